@@ -92,6 +92,12 @@ pub enum ResourceError {
   #[error("Not the custodian of this resource")]
   NotCustodian,
 
+  #[error("Custody transfer proposal not found: {0}")]
+  CustodyProposalNotFound(String),
+
+  #[error("Not the proposed custodian for this transfer")]
+  NotProposedCustodian,
+
   #[error("Serialization error: {0}")]
   SerializationError(String),
 
@@ -106,6 +112,9 @@ pub enum ResourceError {
 
   #[error("Governance rule violation: {0}")]
   GovernanceViolation(String),
+
+  #[error("Unit not found: {0}")]
+  UnitNotFound(String),
 }
 
 impl From<ResourceError> for WasmError {
@@ -132,9 +141,21 @@ pub enum GovernanceError {
   #[error("Commitment not found: {0}")]
   CommitmentNotFound(String),
 
+  #[error("Economic resource not found: {0}")]
+  ResourceNotFound(String),
+
+  #[error("Process not found: {0}")]
+  ProcessNotFound(String),
+
+  #[error("Process already finished: {0}")]
+  ProcessFinished(String),
+
   #[error("Not authorized for this validation")]
   NotAuthorizedValidator,
 
+  #[error("Not authorized: {0}")]
+  NotAuthorized(String),
+
   #[error("Insufficient capability level: {0}")]
   InsufficientCapability(String),
 
@@ -158,6 +179,9 @@ pub enum GovernanceError {
 
   #[error("Cross-zome call failed: {0}")]
   CrossZomeCallFailed(String),
+
+  #[error("Governance rule violation: {0}")]
+  GovernanceViolation(String),
 }
 
 impl From<GovernanceError> for WasmError {
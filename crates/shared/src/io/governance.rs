@@ -1,4 +1,8 @@
-use crate::types::{BenefitClause, NdoLinkType, VfAction};
+use crate::location::{deserialize_location, Location};
+use crate::rules::{EvaluatedRule, GovernedOperation, RuleEvaluationContext};
+use crate::types::{
+  BenefitClause, NdoLinkType, ResourceValidationConfig, ResourceValidationStatus, VfAction,
+};
 use hdi::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +22,27 @@ pub struct UpdateAgreementInput {
   pub primary_accountable: Vec<AgentPubKey>,
 }
 
+/// Input to `log_economic_event` in `zome_gouvernance/economic_event.rs`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LogEconomicEventInput {
+  pub action: VfAction,
+  pub provider: AgentPubKey,
+  pub receiver: AgentPubKey,
+  pub resource_inventoried_as: ActionHash,
+  pub resource_quantity: f64,
+  pub note: Option<String>,
+  pub commitment_hash: Option<ActionHash>, // Optional link to commitment being fulfilled
+  pub generate_pprs: Option<bool>,         // Whether to auto-generate PPR claims
+  #[serde(default)]
+  pub input_of: Option<ActionHash>, // Open Process the event is part of
+  #[serde(default, deserialize_with = "deserialize_location")]
+  pub to_location: Option<Location>, // Destination of a Move
+  #[serde(default)]
+  pub resource_unit: Option<String>, // Unit symbol of resource_quantity; defaults to the resource's
+  #[serde(default)]
+  pub duration_hours: Option<u64>, // Requested length of a Use, checked against usage_limit rules
+}
+
 /// Input to `validate_contribution` in `zome_gouvernance/contribution.rs`.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ValidateContributionInput {
@@ -49,3 +74,37 @@ pub struct GetNdoHardLinksByTypeInput {
   pub ndo_identity_hash: ActionHash,
   pub link_type: NdoLinkType,
 }
+
+/// Input to `evaluate_governance_rules` in `zome_gouvernance/rule_engine.rs`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EvaluateGovernanceRulesInput {
+  pub operation: GovernedOperation,
+  pub rules: Vec<EvaluatedRule>,
+  pub context: RuleEvaluationContext,
+}
+
+/// Input to `validate_new_resource` in `zome_gouvernance/validation.rs`, called by
+/// `create_economic_resource` in zome_resource.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidateNewResourceInput {
+  pub resource_hash: ActionHash,
+  pub resource_spec_hash: ActionHash, // Version of the ResourceSpecification `validation` is from
+  pub creator: AgentPubKey,
+  pub validation: ResourceValidationConfig, // From the ResourceSpecification
+}
+
+/// Output of `validate_new_resource`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidateNewResourceOutput {
+  pub validation_hash: ActionHash,
+  pub validation_required: bool,
+  pub status: String,
+}
+
+/// Output of `get_resource_validation_outcome` in `zome_gouvernance/validation.rs`, read by
+/// `promote_validated_resource` in zome_resource.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResourceValidationOutcome {
+  pub validation_hash: ActionHash, // Latest version of the ResourceValidation
+  pub status: ResourceValidationStatus,
+}
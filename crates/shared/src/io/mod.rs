@@ -1,2 +1,6 @@
 pub mod governance;
 pub mod lobby;
+pub mod page;
+pub mod resource;
+
+pub use page::{Page, PageCursor, PageRequest};
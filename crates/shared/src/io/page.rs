@@ -0,0 +1,69 @@
+use hdi::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// ─── Pagination ──────────────────────────────────────────────────────────────
+// Listing externs have `_paginated` variants that return one page of the links under
+// their anchor at a time. Links are ordered by timestamp, then by create-link hash, so
+// the order is the same on every call and for every agent; a cursor is the position of
+// the last link of a page, and stays valid when links are added or deleted meanwhile.
+
+pub const DEFAULT_PAGE_LIMIT: u32 = 50;
+pub const MAX_PAGE_LIMIT: u32 = 200;
+
+/// Position of a link in the listing order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PageCursor {
+  pub timestamp: Timestamp,
+  pub link_hash: ActionHash,
+}
+
+/// Input to the paginated listing externs. No cursor starts at the first page.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PageRequest {
+  pub cursor: Option<PageCursor>,
+  pub limit: u32, // 0 means DEFAULT_PAGE_LIMIT; capped at MAX_PAGE_LIMIT
+}
+
+/// One page of a listing. `next_cursor` is None on the last page.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Page<T> {
+  pub items: Vec<T>,
+  pub next_cursor: Option<PageCursor>,
+}
+
+impl PageRequest {
+  pub fn effective_limit(&self) -> usize {
+    match self.limit {
+      0 => DEFAULT_PAGE_LIMIT as usize,
+      limit => limit.min(MAX_PAGE_LIMIT) as usize,
+    }
+  }
+}
+
+#[cfg(feature = "coordinator")]
+impl PageRequest {
+  /// The links of the requested page, in listing order, and the cursor of the next page.
+  /// A page may resolve to fewer items than its links when some targets are unavailable.
+  pub fn select(
+    &self,
+    mut links: Vec<hdk::prelude::Link>,
+  ) -> (Vec<hdk::prelude::Link>, Option<PageCursor>) {
+    links
+      .sort_by(|a, b| (a.timestamp, &a.create_link_hash).cmp(&(b.timestamp, &b.create_link_hash)));
+    if let Some(cursor) = &self.cursor {
+      links.retain(|link| {
+        (link.timestamp, &link.create_link_hash) > (cursor.timestamp, &cursor.link_hash)
+      });
+    }
+    let limit = self.effective_limit();
+    let next_cursor = (links.len() > limit).then(|| {
+      let last = &links[limit - 1];
+      PageCursor {
+        timestamp: last.timestamp,
+        link_hash: last.create_link_hash.clone(),
+      }
+    });
+    links.truncate(limit);
+    (links, next_cursor)
+  }
+}
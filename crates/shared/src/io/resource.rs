@@ -0,0 +1,43 @@
+use crate::location::Location;
+use crate::rules::GovernedOperation;
+use crate::types::VfAction;
+use hdi::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Input to `evaluate_resource_governance` in `zome_resource/governance_check.rs`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EvaluateResourceGovernanceInput {
+  pub resource_hash: ActionHash, // Any version of the EconomicResource
+  pub operation: GovernedOperation,
+  pub quantity: Option<f64>,
+  pub recipient: Option<AgentPubKey>, // Incoming custodian when it is not the calling agent
+  #[serde(default)]
+  pub duration_hours: Option<u64>, // Requested length of a use or reservation
+}
+
+/// Input to `apply_economic_event` in `zome_resource/event_effects.rs`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ApplyEconomicEventInput {
+  pub event_hash: ActionHash,
+  pub resource_hash: ActionHash, // Resource version the event was recorded against
+  pub action: VfAction,
+  pub receiver: AgentPubKey,
+  pub resource_quantity: f64,
+  pub to_location: Option<Location>,
+  pub resource_unit: Option<String>, // Unit symbol of resource_quantity
+}
+
+/// What applying an EconomicEvent did to its resource.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AppliedEventEffect {
+  pub updated_resource_hash: Option<ActionHash>, // New resource version, if one was written
+  pub custody_proposal_hash: Option<ActionHash>, // Handover awaiting the receiver's acceptance
+}
+
+/// Input to `convert_quantity` in `zome_resource/unit.rs`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConvertQuantityInput {
+  pub quantity: f64,
+  pub from_unit: String, // Unit symbols
+  pub to_unit: String,
+}
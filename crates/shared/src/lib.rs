@@ -1,7 +1,13 @@
 // Types and I/O structs are available to all compilation targets
 // (integrity zomes, coordinator zomes, native test crates).
 pub mod io;
+pub mod location;
+pub mod roles;
+pub mod rules;
+pub mod search;
 pub mod types;
+pub mod units;
+pub mod update_chain;
 
 // Re-export types at crate root for ergonomic imports
 pub use types::*;
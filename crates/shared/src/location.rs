@@ -0,0 +1,221 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+// ─── Locations ───────────────────────────────────────────────────────────────
+// Where an EconomicResource currently is, or where a Move takes it. Geo points are
+// indexed under geohash-prefixed path anchors, one per precision level, so that a
+// proximity query only reads the anchors of the cells around its centre.
+
+/// Longest geohash prefix resources are indexed under (cells of about 1.2 x 0.6 km).
+pub const GEOHASH_PRECISION: usize = 6;
+
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const EARTH_RADIUS_KM: f64 = 6371.0;
+const KM_PER_DEGREE: f64 = 111.32;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Location {
+  Named(String), // A place known by name, e.g. "Workshop B"
+  Address(PostalAddress),
+  Geo(GeoPoint),
+  Virtual(String), // URI of a virtual place, e.g. a repository
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PostalAddress {
+  pub street: Option<String>,
+  pub locality: String,
+  pub region: Option<String>,
+  pub postal_code: Option<String>,
+  pub country: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+  pub lat: f64,
+  pub long: f64,
+}
+
+impl Location {
+  pub fn validate(&self) -> Result<(), String> {
+    match self {
+      Location::Named(name) if name.trim().is_empty() => {
+        Err("Location name cannot be empty".to_string())
+      }
+      Location::Address(address)
+        if address.locality.trim().is_empty() || address.country.trim().is_empty() =>
+      {
+        Err("Postal address needs a locality and a country".to_string())
+      }
+      Location::Geo(point) => point.validate(),
+      Location::Virtual(uri) if !uri.contains(':') || uri.contains(char::is_whitespace) => {
+        Err(format!("Invalid virtual location URI: {}", uri))
+      }
+      _ => Ok(()),
+    }
+  }
+
+  /// Anchor key of a non-geo location: places written the same way share an anchor.
+  /// Geo points are indexed by geohash instead.
+  pub fn anchor_key(&self) -> Option<String> {
+    match self {
+      Location::Named(name) => Some(normalize(name)),
+      Location::Address(address) => Some(normalize(&format!(
+        "{} {} {} {} {}",
+        address.street.as_deref().unwrap_or_default(),
+        address.postal_code.as_deref().unwrap_or_default(),
+        address.locality,
+        address.region.as_deref().unwrap_or_default(),
+        address.country
+      ))),
+      Location::Geo(_) => None,
+      Location::Virtual(uri) => Some(uri.trim().to_string()),
+    }
+  }
+}
+
+// Lowercase words joined by single dashes
+fn normalize(text: &str) -> String {
+  text
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty())
+    .map(str::to_lowercase)
+    .collect::<Vec<_>>()
+    .join("-")
+}
+
+impl GeoPoint {
+  pub fn validate(&self) -> Result<(), String> {
+    if !(self.lat.is_finite() && (-90.0..=90.0).contains(&self.lat)) {
+      return Err(format!("Latitude out of range: {}", self.lat));
+    }
+    if !(self.long.is_finite() && (-180.0..=180.0).contains(&self.long)) {
+      return Err(format!("Longitude out of range: {}", self.long));
+    }
+    Ok(())
+  }
+
+  /// Great-circle distance in kilometres (haversine)
+  pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+    let d_lat = (other.lat - self.lat).to_radians();
+    let d_long = (other.long - self.long).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+      + self.lat.to_radians().cos() * other.lat.to_radians().cos() * (d_long / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+  }
+
+  pub fn geohash(&self, precision: usize) -> String {
+    let (mut lat_range, mut long_range) = ((-90.0, 90.0), (-180.0, 180.0));
+    let mut hash = String::with_capacity(precision);
+    let mut even_bit = true; // Bits alternate between longitude and latitude, longitude first
+    for _ in 0..precision {
+      let mut index = 0;
+      for _ in 0..5 {
+        let (range, value): (&mut (f64, f64), f64) = if even_bit {
+          (&mut long_range, self.long)
+        } else {
+          (&mut lat_range, self.lat)
+        };
+        let mid = (range.0 + range.1) / 2.0;
+        index <<= 1;
+        if value >= mid {
+          index |= 1;
+          range.0 = mid;
+        } else {
+          range.1 = mid;
+        }
+        even_bit = !even_bit;
+      }
+      hash.push(GEOHASH_ALPHABET[index] as char);
+    }
+    hash
+  }
+
+  /// Geohash prefixes of every precision up to GEOHASH_PRECISION, shortest first
+  pub fn geohash_prefixes(&self) -> Vec<String> {
+    let hash = self.geohash(GEOHASH_PRECISION);
+    (1..=GEOHASH_PRECISION)
+      .map(|length| hash[..length].to_string())
+      .collect()
+  }
+
+  /// Geohash cells covering every point within `radius_km`: the cell containing this
+  /// point and enough rings of neighbours to reach the radius. The precision is the finest
+  /// whose cells are at least `radius_km` wide and high, so that one ring suffices; a
+  /// radius beyond the coarsest cells widens the ring instead.
+  pub fn geohash_cover(&self, radius_km: f64) -> Vec<String> {
+    // Cells narrow towards the poles, so they are measured at the most poleward latitude
+    // within reach
+    let poleward = (self.lat.abs() + radius_km / KM_PER_DEGREE).min(90.0);
+    let cell_km = |precision: usize| {
+      let (lat_degrees, long_degrees) = geohash_cell_degrees(precision);
+      let height_km = lat_degrees * KM_PER_DEGREE;
+      let width_km = long_degrees * KM_PER_DEGREE * poleward.to_radians().cos();
+      (height_km, width_km)
+    };
+    let precision = (1..=GEOHASH_PRECISION)
+      .rev()
+      .find(|&precision| {
+        let (height_km, width_km) = cell_km(precision);
+        height_km >= radius_km && width_km >= radius_km
+      })
+      .unwrap_or(1);
+    let (lat_degrees, long_degrees) = geohash_cell_degrees(precision);
+    let (height_km, width_km) = cell_km(precision);
+
+    let rings = |cell_km: f64| (radius_km / cell_km).ceil().max(1.0) as i64;
+    let columns = (360.0 / long_degrees) as i64;
+    let lat_rings = rings(height_km);
+    // Across a pole every longitude is within reach
+    let long_rings = if poleward >= 90.0 {
+      columns
+    } else {
+      rings(width_km).min(columns)
+    };
+
+    let mut cells: Vec<String> = Vec::new();
+    for lat_step in -lat_rings..=lat_rings {
+      let lat = self.lat + lat_step as f64 * lat_degrees;
+      if !(-90.0..=90.0).contains(&lat) {
+        continue;
+      }
+      for long_step in -long_rings..=long_rings {
+        let long = (self.long + long_step as f64 * long_degrees + 540.0) % 360.0 - 180.0;
+        let cell = GeoPoint { lat, long }.geohash(precision);
+        if !cells.contains(&cell) {
+          cells.push(cell);
+        }
+      }
+    }
+    cells
+  }
+}
+
+// Height and width of a geohash cell in degrees; longitude takes the extra odd bit
+fn geohash_cell_degrees(precision: usize) -> (f64, f64) {
+  let bits = 5 * precision as i32;
+  let long_bits = (bits + 1) / 2;
+  let lat_bits = bits / 2;
+  (180.0 / 2f64.powi(lat_bits), 360.0 / 2f64.powi(long_bits))
+}
+
+// Entries written before structured locations stored a free-text string
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LocationWire {
+  Legacy(String),
+  Structured(Location),
+}
+
+/// `deserialize_with` helper for `Option<Location>` fields that used to hold a string;
+/// legacy strings become `Location::Named`.
+pub fn deserialize_location<'de, D>(deserializer: D) -> Result<Option<Location>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Ok(
+    Option::<LocationWire>::deserialize(deserializer)?.map(|wire| match wire {
+      LocationWire::Legacy(name) => Location::Named(name),
+      LocationWire::Structured(location) => location,
+    }),
+  )
+}
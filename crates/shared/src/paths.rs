@@ -34,3 +34,208 @@ pub fn tag_anchor(entity_type: &str, tag: &str) -> Path {
 pub fn state_anchor(entity_type: &str, state: &str) -> Path {
   Path::from(format!("{entity_type}_by_state_{state}"))
 }
+
+// ─── Sharded anchors ─────────────────────────────────────────────────────────
+// Global discovery anchors are split into shards so that no single path entry collects
+// every link of an entry type. Entities are spread over HASH_SHARDS shards by the first
+// byte of their action hash (`economic_resources.a`); time-ordered records go into one
+// shard per month (`all_economic_events.2026-10`), registered in a path tree under the
+// unsharded root so that readers can enumerate them. Entries linked before sharding hang
+// off the unsharded root itself, which readers still include.
+
+/// Number of shards of a hash-sharded anchor.
+pub const HASH_SHARDS: u8 = 16;
+
+/// Shard of the `root` anchor an entry is linked from, chosen by its hash.
+pub fn hash_shard<T: HashType>(root: &str, hash: &HoloHash<T>) -> Path {
+  Path::from(format!("{root}.{:x}", hash.get_raw_32()[0] % HASH_SHARDS))
+}
+
+/// Every shard of a hash-sharded anchor.
+pub fn hash_shards(root: &str) -> Vec<Path> {
+  (0..HASH_SHARDS)
+    .map(|shard| Path::from(format!("{root}.{shard:x}")))
+    .collect()
+}
+
+/// Link `target` from its shard of the `root` anchor.
+pub fn create_hash_sharded_link<L>(root: &str, target: ActionHash, link_type: L) -> ExternResult<()>
+where
+  ScopedLinkType: TryFrom<L, Error = WasmError>,
+{
+  create_link(
+    hash_shard(root, &target).path_entry_hash()?,
+    target,
+    link_type,
+    (),
+  )?;
+  Ok(())
+}
+
+/// Links from every shard of a hash-sharded anchor, and from the unsharded root.
+pub fn get_hash_sharded_links<L>(root: &str, link_type: L) -> ExternResult<Vec<Link>>
+where
+  L: TryInto<LinkTypeFilter, Error = WasmError> + Copy,
+{
+  let mut anchors = hash_shards(root);
+  anchors.push(Path::from(root));
+  get_links_from_anchors(anchors, link_type)
+}
+
+/// Shard of the `root` anchor for the month of `timestamp` (UTC).
+pub fn month_shard(root: &str, timestamp: Timestamp) -> Path {
+  let (year, month) = year_month(timestamp);
+  Path::from(format!("{root}.{year:04}-{month:02}"))
+}
+
+/// Link `target` from the month shard of the `root` anchor for `timestamp`, registering
+/// the shard in the path tree of `root` (`tree_link_type`) the first time it is used.
+pub fn create_month_sharded_link<L, T>(
+  root: &str,
+  timestamp: Timestamp,
+  target: ActionHash,
+  link_type: L,
+  tree_link_type: T,
+) -> ExternResult<()>
+where
+  ScopedLinkType: TryFrom<L, Error = WasmError> + TryFrom<T, Error = WasmError>,
+{
+  let shard = month_shard(root, timestamp);
+  shard.clone().typed(tree_link_type)?.ensure()?;
+  create_link(shard.path_entry_hash()?, target, link_type, ())?;
+  Ok(())
+}
+
+/// Links from every month shard of the `root` anchor, and from the unsharded root.
+pub fn get_month_sharded_links<L, T>(
+  root: &str,
+  link_type: L,
+  tree_link_type: T,
+) -> ExternResult<Vec<Link>>
+where
+  L: TryInto<LinkTypeFilter, Error = WasmError> + Copy,
+  ScopedLinkType: TryFrom<T, Error = WasmError>,
+{
+  let root_path = Path::from(root);
+  let mut anchors: Vec<Path> = root_path
+    .clone()
+    .typed(tree_link_type)?
+    .children_paths()?
+    .into_iter()
+    .map(|shard| shard.path)
+    .collect();
+  anchors.push(root_path);
+  get_links_from_anchors(anchors, link_type)
+}
+
+fn get_links_from_anchors<L>(anchors: Vec<Path>, link_type: L) -> ExternResult<Vec<Link>>
+where
+  L: TryInto<LinkTypeFilter, Error = WasmError> + Copy,
+{
+  let mut links = Vec::new();
+  for anchor in anchors {
+    links.extend(get_links(
+      LinkQuery::try_new(anchor.path_entry_hash()?, link_type)?,
+      GetStrategy::default(),
+    )?);
+  }
+  Ok(links)
+}
+
+// UTC calendar year and month (civil-from-days, proleptic Gregorian)
+fn year_month(timestamp: Timestamp) -> (i64, u32) {
+  let days = timestamp.as_micros().div_euclid(86_400_000_000) + 719_468;
+  let era = days.div_euclid(146_097);
+  let day_of_era = days.rem_euclid(146_097);
+  let year_of_era =
+    (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153; // March-based
+  let month = (if month_index < 10 {
+    month_index + 3
+  } else {
+    month_index - 9
+  }) as u32;
+  let year = year_of_era + era * 400 + i64::from(month <= 2);
+  (year, month)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Timestamp `seconds` after the Unix epoch, shifted by `micros`
+  fn at(seconds: i64, micros: i64) -> Timestamp {
+    Timestamp::from_micros(seconds * 1_000_000 + micros)
+  }
+
+  #[test]
+  fn months_change_at_midnight_utc() {
+    // 2026-02-01T00:00:00Z
+    assert_eq!(year_month(at(1_769_904_000, -1)), (2026, 1));
+    assert_eq!(year_month(at(1_769_904_000, 0)), (2026, 2));
+  }
+
+  #[test]
+  fn years_change_at_midnight_utc() {
+    // 2026-01-01T00:00:00Z
+    assert_eq!(year_month(at(1_767_225_600, -1)), (2025, 12));
+    assert_eq!(year_month(at(1_767_225_600, 0)), (2026, 1));
+  }
+
+  #[test]
+  fn leap_days_belong_to_february() {
+    // 2024-02-29T12:00:00Z, 2024-03-01T00:00:00Z, 2000-02-29T00:00:00Z
+    assert_eq!(year_month(at(1_709_208_000, 0)), (2024, 2));
+    assert_eq!(year_month(at(1_709_251_200, -1)), (2024, 2));
+    assert_eq!(year_month(at(1_709_251_200, 0)), (2024, 3));
+    assert_eq!(year_month(at(951_782_400, 0)), (2000, 2));
+  }
+
+  #[test]
+  fn timestamps_before_1970_are_counted_back_from_the_epoch() {
+    assert_eq!(year_month(at(0, 0)), (1970, 1));
+    assert_eq!(year_month(at(0, -1)), (1969, 12));
+    // 1900-03-01T00:00:00Z follows 1900-02-28: 1900 is not a leap year
+    assert_eq!(year_month(at(-2_203_891_200, -1)), (1900, 2));
+    assert_eq!(year_month(at(-2_203_891_200, 0)), (1900, 3));
+    // 1600-02-29T00:00:00Z
+    assert_eq!(year_month(at(-11_670_998_400, 0)), (1600, 2));
+  }
+
+  #[test]
+  fn month_shards_are_named_by_year_and_month() {
+    assert_eq!(
+      month_shard("all_economic_events", at(1_769_904_000, 0)),
+      Path::from("all_economic_events.2026-02")
+    );
+  }
+
+  #[test]
+  fn hashes_spread_evenly_over_the_shards() {
+    let shards = hash_shards("economic_resources");
+    assert_eq!(shards.len(), usize::from(HASH_SHARDS));
+
+    let mut counts = vec![0; shards.len()];
+    for first_byte in 0..=u8::MAX {
+      let mut raw = vec![0; 36];
+      raw[0] = first_byte;
+      let shard = hash_shard("economic_resources", &ActionHash::from_raw_36(raw));
+      let index = shards
+        .iter()
+        .position(|candidate| *candidate == shard)
+        .expect("every hash falls into one of the shards");
+      counts[index] += 1;
+    }
+    assert!(counts.iter().all(|&count| count == 256 / shards.len()));
+  }
+
+  #[test]
+  fn shards_hang_under_their_root() {
+    let root = Path::from("economic_resources");
+    for shard in hash_shards("economic_resources") {
+      let (_, parent) = shard.0.split_last().expect("a shard is below its root");
+      assert_eq!(parent, &root.0[..]);
+    }
+  }
+}
@@ -0,0 +1,78 @@
+// ─── Roles ───────────────────────────────────────────────────────────────────
+// Roles agents hold are named entries of the role registry (zome_person_integrity::
+// RoleDefinition). Each role grants one capability level; an agent's capability is the
+// highest level of its roles. The six MVP roles are the default registry, seeded at init;
+// communities propose and ratify their own roles on top of them.
+
+/// Capability levels, lowest first.
+pub const CAPABILITY_LEVELS: [&str; 4] = ["member", "stewardship", "coordination", "governance"];
+
+/// Position of a capability level in CAPABILITY_LEVELS; None for an unknown level.
+pub fn capability_rank(level: &str) -> Option<usize> {
+  CAPABILITY_LEVELS.iter().position(|known| *known == level)
+}
+
+/// A role of the default registry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DefaultRole {
+  pub role_name: &'static str,
+  pub capability_level: &'static str,
+  pub description: &'static str,
+  pub validation_requirements: Option<&'static str>,
+  pub assignable_by: &'static str, // Lowest capability level that may assign the role
+}
+
+const SPECIALIZED_ROLE_VALIDATION: &str =
+  "Specialized role validation by the governance zome (REQ-GOV-04)";
+
+pub const DEFAULT_ROLES: [DefaultRole; 6] = [
+  DefaultRole {
+    role_name: "Simple Agent",
+    capability_level: "member",
+    description: "Community member who can use resources",
+    validation_requirements: None,
+    assignable_by: "member",
+  },
+  DefaultRole {
+    role_name: "Accountable Agent",
+    capability_level: "coordination",
+    description: "Member accountable for the resources in their custody",
+    validation_requirements: None,
+    assignable_by: "coordination",
+  },
+  DefaultRole {
+    role_name: "Primary Accountable Agent",
+    capability_level: "governance",
+    description: "Member who validates others and ratifies community rules",
+    validation_requirements: None,
+    assignable_by: "governance",
+  },
+  DefaultRole {
+    role_name: "Transport Agent",
+    capability_level: "stewardship",
+    description: "Steward of Transport processes",
+    validation_requirements: Some(SPECIALIZED_ROLE_VALIDATION),
+    assignable_by: "coordination",
+  },
+  DefaultRole {
+    role_name: "Repair Agent",
+    capability_level: "stewardship",
+    description: "Steward of Repair processes",
+    validation_requirements: Some(SPECIALIZED_ROLE_VALIDATION),
+    assignable_by: "coordination",
+  },
+  DefaultRole {
+    role_name: "Storage Agent",
+    capability_level: "stewardship",
+    description: "Steward of Storage processes",
+    validation_requirements: Some(SPECIALIZED_ROLE_VALIDATION),
+    assignable_by: "coordination",
+  },
+];
+
+/// The default role with this name, if any
+pub fn default_role(role_name: &str) -> Option<&'static DefaultRole> {
+  DEFAULT_ROLES
+    .iter()
+    .find(|role| role.role_name == role_name)
+}
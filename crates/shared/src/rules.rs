@@ -0,0 +1,300 @@
+use crate::roles::capability_rank;
+use crate::types::{OperationalState, VfAction};
+use hdi::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// ─── Typed GovernanceRule parameters ─────────────────────────────────────────
+// GovernanceRule (zome_resource_integrity) stores a `rule_type` plus a typed,
+// versioned `rule_data`. Clients submit `rule_data` as JSON for the given
+// `rule_type`; it is parsed into the types below, stored as msgpack, validated by
+// zome_resource_integrity and evaluated by zome_gouvernance.
+
+pub const ACCESS_REQUIREMENT: &str = "access_requirement";
+pub const USAGE_LIMIT: &str = "usage_limit";
+pub const TRANSFER_CONDITIONS: &str = "transfer_conditions";
+
+// Rules name roles of the role registry (`min_role`, `enforced_by`). Here only the shape of a
+// name is checked: integrity resolves each name to a default role or to a ratified
+// RoleDefinition the rule cites, and coordinators resolve names when rules are evaluated.
+fn validate_role(role: &str, field: &str) -> Result<(), String> {
+  if role.trim().is_empty() || role.len() > 50 {
+    return Err(format!("{} must name a role (max 50 characters)", field));
+  }
+  Ok(())
+}
+
+/// `access_requirement`: minimum role for any governed operation on the resource.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccessRequirement {
+  pub min_role: String,
+}
+
+/// `usage_limit`: bounds on a single use or reservation of the resource.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UsageLimit {
+  #[serde(default)]
+  pub max_duration_hours: Option<u64>,
+  #[serde(default)]
+  pub max_quantity: Option<f64>,
+}
+
+/// `transfer_conditions`: what must hold before custody changes hands.
+/// `min_role` applies to the incoming custodian (see `RuleEvaluationContext::recipient_roles`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransferConditions {
+  #[serde(default)]
+  pub min_role: Option<String>,
+  #[serde(default)]
+  pub required_validators: u32,
+}
+
+/// `Custom`: escape hatch for community experiments. Stored and displayed, never enforced.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CustomRule {
+  pub data: String, // JSON document
+}
+
+/// A GovernanceRule's parameters, typed by its `rule_type`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RuleParameters {
+  AccessRequirement(AccessRequirement),
+  UsageLimit(UsageLimit),
+  TransferConditions(TransferConditions),
+  Custom(CustomRule),
+}
+
+impl RuleParameters {
+  /// Parse JSON `rule_data` for `rule_type`. Known rule types must match their schema
+  /// exactly; any other rule type becomes `Custom`.
+  pub fn parse(rule_type: &str, rule_data: &str) -> Result<Self, String> {
+    let parsed = match rule_type {
+      ACCESS_REQUIREMENT => serde_json::from_str(rule_data).map(Self::AccessRequirement),
+      USAGE_LIMIT => serde_json::from_str(rule_data).map(Self::UsageLimit),
+      TRANSFER_CONDITIONS => serde_json::from_str(rule_data).map(Self::TransferConditions),
+      _ => {
+        return Ok(Self::Custom(CustomRule {
+          data: rule_data.to_string(),
+        }))
+      }
+    };
+    parsed.map_err(|e| format!("Malformed {} rule_data: {}", rule_type, e))
+  }
+
+  /// The `rule_type` this variant is stored under, or `None` for `Custom`
+  pub fn known_rule_type(&self) -> Option<&'static str> {
+    match self {
+      Self::AccessRequirement(_) => Some(ACCESS_REQUIREMENT),
+      Self::UsageLimit(_) => Some(USAGE_LIMIT),
+      Self::TransferConditions(_) => Some(TRANSFER_CONDITIONS),
+      Self::Custom(_) => None,
+    }
+  }
+
+  /// Roles the parameters require (`min_role`)
+  pub fn role_names(&self) -> Vec<&str> {
+    match self {
+      Self::AccessRequirement(requirement) => vec![requirement.min_role.as_str()],
+      Self::TransferConditions(conditions) => conditions.min_role.as_deref().into_iter().collect(),
+      Self::UsageLimit(_) | Self::Custom(_) => Vec::new(),
+    }
+  }
+
+  /// Check that the parameters are well-formed and agree with `rule_type`.
+  pub fn validate(&self, rule_type: &str) -> Result<(), String> {
+    match self.known_rule_type() {
+      Some(expected) if expected != rule_type => {
+        return Err(format!(
+          "rule_type '{}' does not match {} rule_data",
+          rule_type, expected
+        ))
+      }
+      None if [ACCESS_REQUIREMENT, USAGE_LIMIT, TRANSFER_CONDITIONS].contains(&rule_type) => {
+        return Err(format!(
+          "rule_type '{}' is reserved and cannot carry Custom rule_data",
+          rule_type
+        ))
+      }
+      _ => {}
+    }
+
+    match self {
+      Self::AccessRequirement(requirement) => validate_role(&requirement.min_role, "min_role"),
+      Self::UsageLimit(limit) => {
+        if limit.max_duration_hours.is_none() && limit.max_quantity.is_none() {
+          return Err("usage_limit must set max_duration_hours or max_quantity".to_string());
+        }
+        if limit.max_duration_hours == Some(0) {
+          return Err("max_duration_hours must be positive".to_string());
+        }
+        match limit.max_quantity {
+          Some(quantity) if !(quantity.is_finite() && quantity > 0.0) => {
+            Err("max_quantity must be a positive number".to_string())
+          }
+          _ => Ok(()),
+        }
+      }
+      Self::TransferConditions(conditions) => match &conditions.min_role {
+        Some(role) => validate_role(role, "min_role"),
+        None => Ok(()),
+      },
+      Self::Custom(custom) => serde_json::from_str::<serde_json::Value>(&custom.data)
+        .map(|_| ())
+        .map_err(|e| format!("Custom rule_data must be valid JSON: {}", e)),
+    }
+  }
+}
+
+/// Versioned envelope stored in GovernanceRule.rule_data. A schema change adds a new
+/// variant; older versions stay decodable.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "version", content = "rule")]
+pub enum GovernanceRuleData {
+  V1(RuleParameters),
+}
+
+impl GovernanceRuleData {
+  /// Parse client-supplied JSON into the current schema version
+  pub fn from_json(rule_type: &str, rule_data: &str) -> Result<Self, String> {
+    RuleParameters::parse(rule_type, rule_data).map(Self::V1)
+  }
+
+  pub fn parameters(&self) -> &RuleParameters {
+    match self {
+      Self::V1(parameters) => parameters,
+    }
+  }
+
+  /// Full validation of a rule as stored on the DHT
+  pub fn validate(&self, rule_type: &str, enforced_by: Option<&str>) -> Result<(), String> {
+    if rule_type.trim().is_empty() {
+      return Err("Governance rule type cannot be empty".to_string());
+    }
+    if let Some(role) = enforced_by {
+      validate_role(role, "enforced_by")?;
+    }
+    self.parameters().validate(rule_type)
+  }
+}
+
+/// Whether holding `held` roles meets a `required` minimum role: holding that role, or a
+/// role of a higher capability level. Roles sharing a level are distinct specialisations
+/// (e.g. Transport and Repair Agent), so they do not stand in for each other. `role_levels`
+/// gives the registry's capability level of each role; unresolved roles only match by name.
+pub fn roles_satisfy(
+  held: &[String],
+  required: &str,
+  role_levels: &BTreeMap<String, String>,
+) -> bool {
+  let rank = |role: &str| {
+    role_levels
+      .get(role)
+      .and_then(|level| capability_rank(level))
+  };
+  held.iter().any(|role| {
+    role == required
+      || matches!(
+        (rank(role), rank(required)),
+        (Some(held_rank), Some(required_rank)) if held_rank > required_rank
+      )
+  })
+}
+
+// ─── Evaluation request / result ─────────────────────────────────────────────
+
+/// The resource operation a rule set is evaluated against.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GovernedOperation {
+  /// Custody moving to the acting agent
+  CustodyTransfer,
+  /// OperationalState change on an EconomicResource
+  StateChange {
+    from: OperationalState,
+    to: OperationalState,
+  },
+  /// Any other edit of an EconomicResource (quantity, unit, location)
+  ResourceUpdate,
+  /// An EconomicEvent being logged against the resource
+  EconomicEvent { action: VfAction },
+}
+
+impl GovernedOperation {
+  /// Custody changing hands, either directly or through a transfer event
+  pub fn is_transfer(&self) -> bool {
+    matches!(
+      self,
+      GovernedOperation::CustodyTransfer
+        | GovernedOperation::EconomicEvent {
+          action: VfAction::Transfer | VfAction::TransferCustody
+        }
+    )
+  }
+
+  /// The resource being put to use or reserved for use
+  pub fn is_usage(&self) -> bool {
+    matches!(
+      self,
+      GovernedOperation::StateChange {
+        to: OperationalState::InUse | OperationalState::Reserved,
+        ..
+      } | GovernedOperation::EconomicEvent {
+        action: VfAction::Use
+      }
+    )
+  }
+}
+
+/// Facts about the acting agent and the operation that rules are checked against.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RuleEvaluationContext {
+  pub agent_roles: Vec<String>,              // Roles of the acting agent
+  pub recipient_roles: Option<Vec<String>>,  // Roles of the incoming custodian, if not the actor
+  pub role_levels: BTreeMap<String, String>, // Registry capability level of each role involved
+  pub quantity: Option<f64>,
+  pub duration_hours: Option<u64>,
+  pub validator_approvals: u32, // Distinct validators who approved the resource
+}
+
+/// A stored GovernanceRule as handed to the evaluator.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EvaluatedRule {
+  pub rule_hash: Option<ActionHash>,
+  pub rule_type: String,
+  pub rule_data: GovernanceRuleData,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RuleViolation {
+  pub rule_hash: Option<ActionHash>,
+  pub rule_type: String,
+  pub reason: String,
+}
+
+/// Structured allow/deny returned by `evaluate_governance_rules`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GovernanceDecision {
+  pub allowed: bool,
+  pub violations: Vec<RuleViolation>,
+}
+
+impl GovernanceDecision {
+  pub fn allow() -> Self {
+    Self {
+      allowed: true,
+      violations: Vec::new(),
+    }
+  }
+
+  /// All violation reasons joined for error messages
+  pub fn reasons(&self) -> String {
+    self
+      .violations
+      .iter()
+      .map(|v| format!("{}: {}", v.rule_type, v.reason))
+      .collect::<Vec<_>>()
+      .join("; ")
+  }
+}
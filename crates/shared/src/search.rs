@@ -0,0 +1,67 @@
+// ─── Name search ─────────────────────────────────────────────────────────────
+// ResourceSpecifications are indexed by the words of their name: the spec is linked
+// under a path anchor for every prefix of every word, from MIN_PREFIX_LEN characters up
+// to MAX_PREFIX_LEN, so that a query word finds the specs with a name word starting with
+// it in one get_links. Words are lowercase runs of letters and digits.
+
+pub const MIN_PREFIX_LEN: usize = 2;
+pub const MAX_PREFIX_LEN: usize = 10;
+
+/// Distinct lowercase words of a text, in order of first appearance.
+pub fn tokenize(text: &str) -> Vec<String> {
+  let mut words: Vec<String> = Vec::new();
+  for word in text
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty())
+    .map(str::to_lowercase)
+  {
+    if !words.contains(&word) {
+      words.push(word);
+    }
+  }
+  words
+}
+
+/// Prefixes a name is indexed under, without duplicates.
+pub fn index_prefixes(name: &str) -> Vec<String> {
+  let mut prefixes: Vec<String> = Vec::new();
+  for word in tokenize(name) {
+    let chars: Vec<char> = word.chars().collect();
+    for length in MIN_PREFIX_LEN..=chars.len().min(MAX_PREFIX_LEN) {
+      let prefix: String = chars[..length].iter().collect();
+      if !prefixes.contains(&prefix) {
+        prefixes.push(prefix);
+      }
+    }
+  }
+  prefixes
+}
+
+/// Index prefix a query word is looked up under; None for words too short to be indexed.
+pub fn query_prefix(word: &str) -> Option<String> {
+  let chars: Vec<char> = word.chars().collect();
+  (chars.len() >= MIN_PREFIX_LEN).then(|| chars[..chars.len().min(MAX_PREFIX_LEN)].iter().collect())
+}
+
+/// Relevance of a name to the query words: 3 for each word the name contains, 1 for each
+/// word a name word starts with, plus 1 when the name starts with the first query word.
+/// 0 when some query word matches no name word, since every word must match.
+pub fn match_score(query: &[String], name: &str) -> u32 {
+  let words = tokenize(name);
+  let mut score = 0;
+  for term in query {
+    if words.contains(term) {
+      score += 3;
+    } else if words.iter().any(|word| word.starts_with(term.as_str())) {
+      score += 1;
+    } else {
+      return 0;
+    }
+  }
+  if let (Some(first), Some(term)) = (words.first(), query.first()) {
+    if first.starts_with(term.as_str()) {
+      score += 1;
+    }
+  }
+  score
+}
@@ -41,6 +41,66 @@ impl std::fmt::Display for LifecycleStage {
   }
 }
 
+/// Process currently acting on a specific EconomicResource instance (REQ-NDO-OS-01).
+/// Cycles frequently as processes begin and end; orthogonal to LifecycleStage
+/// (REQ-NDO-OS-04). Transition graph per ndo_prima_materia.md §5.4 — see
+/// `can_transition_to`.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub enum OperationalState {
+  Available,
+  Reserved,
+  InTransit,
+  InStorage,
+  InMaintenance,
+  InUse,
+  /// Initial state for newly created EconomicResource instances.
+  #[default]
+  PendingValidation,
+}
+
+impl OperationalState {
+  /// Returns true if `next` is a legal successor of `self`.
+  /// A state is always allowed to "transition" to itself so that updates which only
+  /// touch other fields (quantity, location) remain valid.
+  pub fn can_transition_to(&self, next: &OperationalState) -> bool {
+    use OperationalState::*;
+    if self == next {
+      return true;
+    }
+    matches!(
+      (self, next),
+      (PendingValidation, Available)
+        | (Available, Reserved)
+        | (Available, InTransit)
+        | (Available, InStorage)
+        | (Available, InMaintenance)
+        | (Available, InUse)
+        | (Reserved, Available)
+        | (Reserved, InTransit)
+        | (Reserved, InStorage)
+        | (InTransit, Available)
+        | (InStorage, Available)
+        | (InMaintenance, Available)
+        | (InUse, Available)
+    )
+  }
+}
+
+impl std::fmt::Display for OperationalState {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let s = match self {
+      OperationalState::Available => "available",
+      OperationalState::Reserved => "reserved",
+      OperationalState::InTransit => "in_transit",
+      OperationalState::InStorage => "in_storage",
+      OperationalState::InMaintenance => "in_maintenance",
+      OperationalState::InUse => "in_use",
+      OperationalState::PendingValidation => "pending_validation",
+    };
+    write!(f, "{}", s)
+  }
+}
+
 /// Governance / ownership regime of a NondominiumIdentity.
 /// Immutable after creation.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
@@ -88,6 +148,92 @@ pub enum VfAction {
   TransferCustody,
 }
 
+impl VfAction {
+  /// Check that an event with this action may be recorded against a resource currently
+  /// in `state` and held by `custodian`. The custodian must be one of the two parties,
+  /// except for citations; custody changes and uses need a validated resource, and
+  /// uses need one that is not tied up in transit, storage or maintenance.
+  pub fn check_resource(
+    &self,
+    state: &OperationalState,
+    custodian: &AgentPubKey,
+    provider: &AgentPubKey,
+    receiver: &AgentPubKey,
+  ) -> Result<(), String> {
+    use OperationalState::*;
+    if *self != VfAction::Cite && custodian != provider && custodian != receiver {
+      return Err(format!(
+        "{:?} events must involve the resource's custodian",
+        self
+      ));
+    }
+    let allowed = match self {
+      VfAction::Transfer | VfAction::TransferCustody | VfAction::Move => {
+        *state != PendingValidation
+      }
+      VfAction::Use | VfAction::AccessForUse => matches!(state, Available | Reserved | InUse),
+      VfAction::Consume => matches!(state, Available | InUse),
+      _ => true,
+    };
+    if !allowed {
+      return Err(format!(
+        "{:?} events are not allowed while the resource is {}",
+        self, state
+      ));
+    }
+    Ok(())
+  }
+
+  /// The VF 1.0 effect of an event with this action on the resource it is recorded
+  /// against. A resource is held whole by one custodian, so transfers hand it over
+  /// entirely, onhand quantity included, rather than splitting its quantities.
+  pub fn resource_effect(&self, quantity: f64) -> ResourceEffect {
+    let change = match self {
+      VfAction::Produce | VfAction::Raise | VfAction::Separate => quantity,
+      VfAction::Consume | VfAction::Lower | VfAction::Combine => -quantity,
+      _ => 0.0,
+    };
+    ResourceEffect {
+      accounting_change: change,
+      onhand_change: change,
+      transfers_custody: matches!(
+        self,
+        VfAction::Transfer | VfAction::TransferCustody | VfAction::InitialTransfer
+      ),
+      changes_location: *self == VfAction::Move,
+    }
+  }
+}
+
+/// How an EconomicEvent changes its resource; see `VfAction::resource_effect`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ResourceEffect {
+  pub accounting_change: f64,  // Added to the resource's accounting quantity
+  pub onhand_change: f64,      // Added to the resource's onhand quantity
+  pub transfers_custody: bool, // Custody passes to the event's receiver
+  pub changes_location: bool,  // The resource moves to the event's `to_location`
+}
+
+impl ResourceEffect {
+  pub fn changes_quantity(&self) -> bool {
+    self.accounting_change != 0.0 || self.onhand_change != 0.0
+  }
+
+  /// Accounting and onhand quantities after the effect, refusing to take either below zero
+  /// or out of the finite numbers
+  pub fn apply_quantities(&self, accounting: f64, onhand: f64) -> Result<(f64, f64), String> {
+    let accounting = accounting + self.accounting_change;
+    let onhand = onhand + self.onhand_change;
+    if !(accounting.is_finite() && accounting >= 0.0 && onhand.is_finite() && onhand >= 0.0) {
+      return Err(format!(
+        "Event would leave the resource with a negative quantity (accounting {}, onhand {})",
+        accounting, onhand
+      ));
+    }
+    Ok((accounting, onhand))
+  }
+}
+
 // ─── NDO federation enums (governance integrity) ─────────────────────────────
 
 /// Typed relationship between two NDOs in a hard link.
@@ -137,3 +283,219 @@ pub struct BenefitClause {
   pub benefit_type: BenefitType,
   pub note: Option<String>,
 }
+
+// ─── Resource validation ─────────────────────────────────────────────────────
+
+/// How new EconomicResources of a ResourceSpecification are validated (REQ-GOV-02).
+/// Stored on the ResourceSpecification; copied into each ResourceValidation.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ResourceValidationConfig {
+  pub validation_scheme: String, // "k-of-n", "simple_majority", "unanimous" or "simple_approval"
+  pub required_validators: u32,  // Validator panel size (n); must equal n for "k-of-n"
+}
+
+impl Default for ResourceValidationConfig {
+  fn default() -> Self {
+    Self {
+      validation_scheme: "simple_approval".to_string(),
+      required_validators: 1,
+    }
+  }
+}
+
+impl ResourceValidationConfig {
+  pub fn validate(&self) -> Result<(), String> {
+    self.scheme().map(|_| ())
+  }
+
+  pub fn scheme(&self) -> Result<ValidationScheme, String> {
+    ValidationScheme::parse(&self.validation_scheme, self.required_validators)
+  }
+}
+
+/// A parsed validation scheme over a panel of `n` validators.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum ValidationScheme {
+  /// `k` approvals out of `n`; "simple_approval" is 1-of-n
+  KOfN { k: u32, n: u32 },
+  /// More than half of `n`
+  SimpleMajority { n: u32 },
+  /// All `n`, and no rejection
+  Unanimous { n: u32 },
+}
+
+impl ValidationScheme {
+  /// Parse a scheme name; `panel_size` supplies `n` for schemes that do not spell it out.
+  pub fn parse(scheme: &str, panel_size: u32) -> Result<Self, String> {
+    if panel_size == 0 {
+      return Err("required_validators must be at least 1".to_string());
+    }
+    let parsed = match scheme.trim() {
+      "simple_approval" => Self::KOfN {
+        k: 1,
+        n: panel_size,
+      },
+      "simple_majority" => Self::SimpleMajority { n: panel_size },
+      "unanimous" => Self::Unanimous { n: panel_size },
+      other => {
+        let (k, n) = other
+          .split_once("-of-")
+          .and_then(|(k, n)| Some((k.parse::<u32>().ok()?, n.parse::<u32>().ok()?)))
+          .ok_or(format!("Unknown validation scheme '{}'", other))?;
+        if k == 0 || k > n {
+          return Err(format!("Validation scheme '{}' needs 1 <= k <= n", other));
+        }
+        if n != panel_size {
+          return Err(format!(
+            "Validation scheme '{}' does not match required_validators {}",
+            other, panel_size
+          ));
+        }
+        Self::KOfN { k, n }
+      }
+    };
+    Ok(parsed)
+  }
+
+  /// Approvals needed to approve
+  pub fn approvals_needed(&self) -> u32 {
+    match self {
+      Self::KOfN { k, .. } => *k,
+      Self::SimpleMajority { n } => n / 2 + 1,
+      Self::Unanimous { n } => *n,
+    }
+  }
+
+  pub fn panel_size(&self) -> u32 {
+    match self {
+      Self::KOfN { n, .. } | Self::SimpleMajority { n } | Self::Unanimous { n } => *n,
+    }
+  }
+
+  /// Status implied by a tally of the panel's votes: rejected once enough validators have
+  /// rejected that the approval threshold can no longer be reached within the panel (for
+  /// Unanimous, on any rejection). A tally of more votes than the panel has seats is not
+  /// one of the panel's; it stays Pending, see `counted_votes`.
+  pub fn outcome(&self, tally: &ValidationTally) -> ResourceValidationStatus {
+    let needed = self.approvals_needed();
+    if tally.approvals + tally.rejections > self.panel_size() {
+      ResourceValidationStatus::Pending
+    } else if tally.rejections > self.panel_size() - needed {
+      ResourceValidationStatus::Rejected
+    } else if tally.approvals >= needed {
+      ResourceValidationStatus::Approved
+    } else {
+      ResourceValidationStatus::Pending
+    }
+  }
+
+  /// The votes that sit on the panel: the first `panel_size`, in the order they were cast.
+  pub fn counted_votes<T>(&self, mut votes: Vec<T>) -> Vec<T> {
+    votes.truncate(self.panel_size() as usize);
+    votes
+  }
+}
+
+/// ResourceValidation status. Aliases accept the lowercase strings stored before the
+/// status was typed.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub enum ResourceValidationStatus {
+  #[default]
+  #[serde(alias = "pending")]
+  Pending,
+  #[serde(alias = "approved")]
+  Approved,
+  #[serde(alias = "rejected")]
+  Rejected,
+}
+
+/// Distinct validators' votes on an item, recomputed from its ValidationReceipts.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub struct ValidationTally {
+  pub approvals: u32,
+  pub rejections: u32,
+}
+
+// ─── Commitment lifecycle ────────────────────────────────────────────────────
+
+/// Negotiation status stored on a Commitment. Commitments created before the field
+/// existed decode as `Proposed`.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub enum CommitmentStatus {
+  #[default]
+  Proposed,
+  Accepted,
+  Declined,
+  Cancelled,
+}
+
+impl CommitmentStatus {
+  /// Stored transitions: the provider accepts or declines a proposal; the receiver
+  /// cancels one that is still proposed or accepted.
+  pub fn can_transition_to(&self, next: CommitmentStatus) -> bool {
+    matches!(
+      (self, next),
+      (
+        CommitmentStatus::Proposed,
+        CommitmentStatus::Accepted | CommitmentStatus::Declined | CommitmentStatus::Cancelled
+      ) | (CommitmentStatus::Accepted, CommitmentStatus::Cancelled)
+    )
+  }
+}
+
+/// Quantity delivered against a Commitment, summed from the EconomicEvents its Claims
+/// point at.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub struct FulfillmentProgress {
+  pub committed_quantity: Option<f64>, // None: any fulfilling event completes the commitment
+  pub fulfilled_quantity: f64,
+  pub fulfilling_events: u32,
+}
+
+impl FulfillmentProgress {
+  pub fn is_complete(&self) -> bool {
+    match self.committed_quantity {
+      Some(committed) => self.fulfilled_quantity >= committed,
+      None => self.fulfilling_events > 0,
+    }
+  }
+}
+
+/// Effective state of a Commitment as reported to clients: the stored status combined
+/// with fulfillment progress and the due date. Never stored.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum CommitmentState {
+  Proposed,
+  Accepted,
+  Declined,
+  Cancelled,
+  PartiallyFulfilled,
+  Fulfilled,
+  Overdue,
+}
+
+impl CommitmentState {
+  pub fn derive(
+    status: CommitmentStatus,
+    progress: &FulfillmentProgress,
+    due_date: Timestamp,
+    now: Timestamp,
+  ) -> Self {
+    match status {
+      CommitmentStatus::Declined => return CommitmentState::Declined,
+      CommitmentStatus::Cancelled => return CommitmentState::Cancelled,
+      _ => {}
+    }
+    if progress.is_complete() {
+      CommitmentState::Fulfilled
+    } else if now > due_date {
+      CommitmentState::Overdue
+    } else if progress.fulfilling_events > 0 {
+      CommitmentState::PartiallyFulfilled
+    } else if status == CommitmentStatus::Accepted {
+      CommitmentState::Accepted
+    } else {
+      CommitmentState::Proposed
+    }
+  }
+}
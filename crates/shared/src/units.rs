@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+// ─── Units of measure ────────────────────────────────────────────────────────
+// Resource and event quantities are expressed in a Unit (zome_resource_integrity),
+// identified by its symbol. Units of the same Dimension convert through their
+// factor to the dimension's base unit. The registry is seeded with the common
+// units of the OM2 ontology used by ValueFlows; communities may add their own.
+
+/// What a unit measures; only units of the same dimension convert into each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Dimension {
+  Count,
+  Mass,
+  Length,
+  Area,
+  Volume,
+  Time,
+  Energy,
+}
+
+/// A seeded OM2 unit; `factor` is the size of one of it in the dimension's base unit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Om2Unit {
+  pub symbol: &'static str,
+  pub label: &'static str,
+  pub dimension: Dimension,
+  pub factor: f64,
+}
+
+const fn om2(
+  symbol: &'static str,
+  label: &'static str,
+  dimension: Dimension,
+  factor: f64,
+) -> Om2Unit {
+  Om2Unit {
+    symbol,
+    label,
+    dimension,
+    factor,
+  }
+}
+
+/// Common OM2 units. The first unit of each dimension is its base unit.
+pub const OM2_UNITS: [Om2Unit; 20] = [
+  om2("one", "one", Dimension::Count, 1.0),
+  om2("kg", "kilogram", Dimension::Mass, 1.0),
+  om2("g", "gram", Dimension::Mass, 0.001),
+  om2("mg", "milligram", Dimension::Mass, 0.000_001),
+  om2("t", "tonne", Dimension::Mass, 1000.0),
+  om2("m", "metre", Dimension::Length, 1.0),
+  om2("km", "kilometre", Dimension::Length, 1000.0),
+  om2("cm", "centimetre", Dimension::Length, 0.01),
+  om2("mm", "millimetre", Dimension::Length, 0.001),
+  om2("m2", "square metre", Dimension::Area, 1.0),
+  om2("ha", "hectare", Dimension::Area, 10_000.0),
+  om2("m3", "cubic metre", Dimension::Volume, 1.0),
+  om2("l", "litre", Dimension::Volume, 0.001),
+  om2("ml", "millilitre", Dimension::Volume, 0.000_001),
+  om2("s", "second", Dimension::Time, 1.0),
+  om2("min", "minute", Dimension::Time, 60.0),
+  om2("h", "hour", Dimension::Time, 3600.0),
+  om2("d", "day", Dimension::Time, 86_400.0),
+  om2("J", "joule", Dimension::Energy, 1.0),
+  om2("kWh", "kilowatt hour", Dimension::Energy, 3_600_000.0),
+];
+
+/// The seeded OM2 unit with this symbol, if any
+pub fn om2_unit(symbol: &str) -> Option<&'static Om2Unit> {
+  OM2_UNITS.iter().find(|unit| unit.symbol == symbol)
+}
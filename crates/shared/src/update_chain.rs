@@ -0,0 +1,97 @@
+use hdi::prelude::*;
+
+// ─── Update chains ───────────────────────────────────────────────────────────
+// Entries evolve through update_entry chains. The latest version of a chain is found by
+// walking forward from any of its versions with get_details, following at every fork the
+// newest update: the latest action timestamp, then the greatest action hash, so that every
+// agent resolves concurrent updates the same way. Anchors and links therefore point at
+// original action hashes and never need to be moved when an entry is updated.
+
+/// Of the updates made to one version, the one a chain walk follows.
+pub fn newest_update<'a>(
+  updates: impl IntoIterator<Item = (Timestamp, &'a ActionHash)>,
+) -> Option<&'a ActionHash> {
+  updates.into_iter().max().map(|(_, hash)| hash)
+}
+
+/// Latest record of the update chain `action_hash` belongs to; `action_hash` may be any
+/// version. None if it cannot be found or the latest version was deleted.
+#[cfg(feature = "coordinator")]
+pub fn get_latest_record(action_hash: ActionHash) -> ExternResult<Option<Record>> {
+  use hdk::prelude::*;
+
+  let mut current = action_hash;
+  loop {
+    let Some(Details::Record(details)) = get_details(current.clone(), GetOptions::default())?
+    else {
+      return Ok(None);
+    };
+    let next = newest_update(
+      details
+        .updates
+        .iter()
+        .map(|update| (update.action().timestamp(), &update.hashed.hash)),
+    )
+    .cloned();
+    match next {
+      Some(next) => current = next,
+      None if details.deletes.is_empty() => return Ok(Some(details.record)),
+      None => return Ok(None),
+    }
+  }
+}
+
+/// Action hash of the latest version of the chain `action_hash` belongs to.
+#[cfg(feature = "coordinator")]
+pub fn get_latest_action_hash(action_hash: ActionHash) -> ExternResult<Option<ActionHash>> {
+  Ok(get_latest_record(action_hash)?.map(|record| record.action_address().clone()))
+}
+
+/// Original (create) action hash of the chain `action_hash` belongs to.
+#[cfg(feature = "coordinator")]
+pub fn get_original_action_hash(action_hash: ActionHash) -> ExternResult<Option<ActionHash>> {
+  use hdk::prelude::*;
+
+  let mut current = action_hash;
+  loop {
+    let Some(record) = get(current.clone(), GetOptions::default())? else {
+      return Ok(None);
+    };
+    match record.action() {
+      Action::Update(update) => current = update.original_action_address.clone(),
+      _ => return Ok(Some(current)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn hash(byte: u8) -> ActionHash {
+    ActionHash::from_raw_36(vec![byte; 36])
+  }
+
+  #[test]
+  fn the_later_update_wins() {
+    let (earlier, later) = (hash(9), hash(1));
+    let updates = [
+      (Timestamp::from_micros(2), &later),
+      (Timestamp::from_micros(1), &earlier),
+    ];
+    assert_eq!(newest_update(updates), Some(&later));
+  }
+
+  #[test]
+  fn simultaneous_updates_fall_back_to_the_greatest_hash() {
+    let (lesser, greater) = (hash(1), hash(9));
+    let at = Timestamp::from_micros(5);
+    assert_eq!(newest_update([(at, &greater), (at, &lesser)]), Some(&greater));
+    assert_eq!(newest_update([(at, &lesser), (at, &greater)]), Some(&greater));
+  }
+
+  #[test]
+  fn a_version_without_updates_has_no_newest_update() {
+    assert_eq!(newest_update([]), None);
+  }
+}
@@ -1,5 +1,8 @@
 use hdk::prelude::*;
 use zome_lobby_integrity::*;
+use nondominium_shared::update_chain::get_latest_record;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
 use nondominium_shared::io::lobby::{
   AnnounceNdoInput, GroupDescriptorStub, LobbyAgentProfileInput, UpdateNdoAnnouncementInput,
 };
@@ -41,7 +44,7 @@ pub fn upsert_lobby_agent_profile(input: LobbyAgentProfileInput) -> ExternResult
   };
 
   // AgentToLobbyProfile: agent-centric lookup link (agent pubkey -> profile hash).
-  // AllLobbyAgents: global path anchor (lobby.agents path -> profile hash).
+  // AllLobbyAgents: global path anchor, sharded by hash (lobby.agents.{shard} path -> profile hash).
   // Update detection uses AgentToLobbyProfile so per-agent queries work correctly.
   let existing_links = get_links(LinkQuery::try_new(agent.clone(), LinkTypes::AgentToLobbyProfile)?, GetStrategy::default())?;
 
@@ -63,13 +66,7 @@ pub fn upsert_lobby_agent_profile(input: LobbyAgentProfileInput) -> ExternResult
   let action_hash = create_entry(&EntryTypes::LobbyAgentProfile(new_profile))?;
 
   // Global discovery anchor: path -> profile
-  let agents_path = Path::from("lobby.agents");
-  create_link(
-    agents_path.path_entry_hash()?,
-    action_hash.clone(),
-    LinkTypes::AllLobbyAgents,
-    (),
-  )?;
+  create_hash_sharded_link("lobby.agents", action_hash.clone(), LinkTypes::AllLobbyAgents)?;
 
   // Agent-centric lookup: agent pubkey -> profile (used by get_lobby_agent_profile and upsert detection)
   create_link(
@@ -95,8 +92,7 @@ pub fn get_lobby_agent_profile(agent: AgentPubKey) -> ExternResult<Option<LobbyA
     return Ok(None);
   };
 
-  let latest_hash = resolve_update_chain(action_hash)?;
-  let Some(record) = get(latest_hash, GetOptions::default())? else {
+  let Some(record) = get_latest_record(action_hash)? else {
     return Ok(None);
   };
 
@@ -106,16 +102,27 @@ pub fn get_lobby_agent_profile(agent: AgentPubKey) -> ExternResult<Option<LobbyA
 /// Get all registered lobby agent profiles.
 #[hdk_extern]
 pub fn get_all_lobby_agents(_: ()) -> ExternResult<Vec<LobbyAgentProfileRecord>> {
-  let path = Path::from("lobby.agents");
-  let links = get_links(LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllLobbyAgents)?, GetStrategy::default())?;
+  resolve_lobby_agent_links(all_lobby_agent_links()?)
+}
+
+/// One page of `get_all_lobby_agents`, in link order.
+#[hdk_extern]
+pub fn get_all_lobby_agents_paginated(page: PageRequest) -> ExternResult<Page<LobbyAgentProfileRecord>> {
+  let (links, next_cursor) = page.select(all_lobby_agent_links()?);
+  Ok(Page { items: resolve_lobby_agent_links(links)?, next_cursor })
+}
+
+fn all_lobby_agent_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("lobby.agents", LinkTypes::AllLobbyAgents)
+}
 
+fn resolve_lobby_agent_links(links: Vec<Link>) -> ExternResult<Vec<LobbyAgentProfileRecord>> {
   let mut results = Vec::new();
   for link in links {
     let Some(action_hash) = link.target.into_action_hash() else {
       continue;
     };
-    let latest_hash = resolve_update_chain(action_hash.clone())?;
-    let Some(record) = get(latest_hash, GetOptions::default())? else {
+    let Some(record) = get_latest_record(action_hash.clone())? else {
       continue;
     };
     let Ok(Some(entry)) = record.entry().to_app_option::<LobbyAgentProfile>() else {
@@ -152,13 +159,7 @@ pub fn announce_ndo(input: AnnounceNdoInput) -> ExternResult<ActionHash> {
   let action_hash = create_entry(&EntryTypes::NdoAnnouncement(ann))?;
 
   // Global discovery anchor
-  let all_ndos_path = Path::from("lobby.ndos");
-  create_link(
-    all_ndos_path.path_entry_hash()?,
-    action_hash.clone(),
-    LinkTypes::AllNdoAnnouncements,
-    (),
-  )?;
+  create_hash_sharded_link("lobby.ndos", action_hash.clone(), LinkTypes::AllNdoAnnouncements)?;
 
   // Agent-centric discovery
   create_link(
@@ -183,16 +184,27 @@ pub fn announce_ndo(input: AnnounceNdoInput) -> ExternResult<ActionHash> {
 /// Get all NDO announcements in the Lobby DHT (cross-conductor discovery).
 #[hdk_extern]
 pub fn get_all_ndo_announcements(_: ()) -> ExternResult<Vec<NdoAnnouncementRecord>> {
-  let path = Path::from("lobby.ndos");
-  let links = get_links(LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllNdoAnnouncements)?, GetStrategy::default())?;
+  resolve_ndo_announcement_links(all_ndo_announcement_links()?)
+}
+
+/// One page of `get_all_ndo_announcements`, in link order.
+#[hdk_extern]
+pub fn get_all_ndo_announcements_paginated(page: PageRequest) -> ExternResult<Page<NdoAnnouncementRecord>> {
+  let (links, next_cursor) = page.select(all_ndo_announcement_links()?);
+  Ok(Page { items: resolve_ndo_announcement_links(links)?, next_cursor })
+}
 
+fn all_ndo_announcement_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("lobby.ndos", LinkTypes::AllNdoAnnouncements)
+}
+
+fn resolve_ndo_announcement_links(links: Vec<Link>) -> ExternResult<Vec<NdoAnnouncementRecord>> {
   let mut results = Vec::new();
   for link in links {
     let Some(action_hash) = link.target.into_action_hash() else {
       continue;
     };
-    let latest_hash = resolve_update_chain(action_hash.clone())?;
-    let Some(record) = get(latest_hash, GetOptions::default())? else {
+    let Some(record) = get_latest_record(action_hash.clone())? else {
       continue;
     };
     let Ok(Some(entry)) = record.entry().to_app_option::<NdoAnnouncement>() else {
@@ -214,8 +226,7 @@ pub fn get_my_ndo_announcements(_: ()) -> ExternResult<Vec<NdoAnnouncementRecord
     let Some(action_hash) = link.target.into_action_hash() else {
       continue;
     };
-    let latest_hash = resolve_update_chain(action_hash.clone())?;
-    let Some(record) = get(latest_hash, GetOptions::default())? else {
+    let Some(record) = get_latest_record(action_hash.clone())? else {
       continue;
     };
     let Ok(Some(entry)) = record.entry().to_app_option::<NdoAnnouncement>() else {
@@ -240,8 +251,7 @@ pub fn get_ndo_announcements_by_lifecycle(stage: String) -> ExternResult<Vec<Ndo
     let Some(action_hash) = link.target.into_action_hash() else {
       continue;
     };
-    let latest_hash = resolve_update_chain(action_hash.clone())?;
-    let Some(record) = get(latest_hash, GetOptions::default())? else {
+    let Some(record) = get_latest_record(action_hash.clone())? else {
       continue;
     };
     let Ok(Some(entry)) = record.entry().to_app_option::<NdoAnnouncement>() else {
@@ -287,10 +297,10 @@ pub fn update_ndo_announcement(input: UpdateNdoAnnouncementInput) -> ExternResul
 /// Get a single NdoAnnouncement by its action hash (resolves update chain).
 #[hdk_extern]
 pub fn get_ndo_announcement(action_hash: ActionHash) -> ExternResult<Option<NdoAnnouncementRecord>> {
-  let latest_hash = resolve_update_chain(action_hash.clone())?;
-  let Some(record) = get(latest_hash.clone(), GetOptions::default())? else {
+  let Some(record) = get_latest_record(action_hash)? else {
     return Ok(None);
   };
+  let latest_hash = record.action_address().clone();
   let Ok(Some(entry)) = record.entry().to_app_option::<NdoAnnouncement>() else {
     return Ok(None);
   };
@@ -309,27 +319,3 @@ pub fn get_my_groups(_: ()) -> ExternResult<Vec<GroupDescriptorStub>> {
     is_solo: true,
   }])
 }
-
-// ─── Helpers ──────────────────────────────────────────────────────────────────
-
-/// Walk an update chain to return the most recent action hash.
-fn resolve_update_chain(original: ActionHash) -> ExternResult<ActionHash> {
-  let mut current = original;
-  loop {
-    match get_details(current.clone(), GetOptions::default())? {
-      Some(Details::Record(details)) => {
-        if details.updates.is_empty() {
-          return Ok(current);
-        }
-        current = details
-          .updates
-          .into_iter()
-          .max_by_key(|sah| sah.action().timestamp())
-          .ok_or_else(|| wasm_error!(WasmErrorInner::Guest("empty updates".to_string())))?
-          .hashed
-          .hash;
-      }
-      _ => return Ok(current),
-    }
-  }
-}
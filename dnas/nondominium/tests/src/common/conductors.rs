@@ -55,6 +55,49 @@ pub async fn setup_two_agents() -> (SweetConductorBatch, SweetCell, SweetCell) {
     (conductors, cell_alice, cell_bob)
 }
 
+/// Mirrors `zome_person_integrity::DnaProperties`.
+#[derive(Debug, serde::Serialize)]
+struct DnaProperties {
+    progenitor: AgentPubKey,
+}
+
+/// Spin up two conductors with the nondominium DNA installed, on a network whose
+/// progenitor is Alice: role assignments are checked against the assigner's roles.
+///
+/// Returns `(conductors, cell_alice, cell_bob)`.
+pub async fn setup_two_agents_with_progenitor() -> (SweetConductorBatch, SweetCell, SweetCell) {
+    let mut conductors =
+        SweetConductorBatch::from_config_rendezvous(2, SweetConductorConfig::standard()).await;
+    let alice = SweetAgents::one(conductors[0].keystore()).await;
+
+    let properties = holochain_serialized_bytes::encode(&DnaProperties {
+        progenitor: alice.clone(),
+    })
+    .expect("Failed to encode DNA properties");
+    let dna = SweetDnaFile::from_bundle(std::path::Path::new(NONDOMINIUM_DNA_PATH))
+        .await
+        .expect("Failed to load nondominium DNA bundle. Did you run `bun run build:happ`?")
+        .with_network_seed(unique_seed())
+        .await
+        .with_properties(SerializedBytes::from(UnsafeBytes::from(properties)))
+        .await;
+
+    let app_alice = conductors[0]
+        .setup_app_for_agent("nondominium", alice, &[dna.clone()])
+        .await
+        .expect("Failed to install nondominium app for the progenitor");
+    let app_bob = conductors[1]
+        .setup_app("nondominium", &[dna])
+        .await
+        .expect("Failed to install nondominium app");
+
+    conductors.exchange_peer_info().await;
+
+    let (cell_alice,) = app_alice.into_tuple();
+    let (cell_bob,) = app_bob.into_tuple();
+    (conductors, cell_alice, cell_bob)
+}
+
 /// Spin up three conductors, each with the nondominium DNA installed.
 ///
 /// Returns `(conductors, cell_alice, cell_bob, cell_carol)`.
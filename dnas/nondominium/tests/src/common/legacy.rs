@@ -0,0 +1,40 @@
+use holochain::prelude::*;
+use holochain::sweettest::*;
+use holochain::test_utils::host_fn_caller::HostFnCaller;
+
+/// Mirrors `hdi::hash_path::path::Component`: a string component is stored as the
+/// little-endian UTF-32 bytes of its chars.
+#[derive(Debug, serde::Serialize)]
+struct PathComponent(SerializedBytes);
+
+/// Hash of the `Path` anchor `path` (components separated by `.`), as computed by
+/// `Path::from(path).path_entry_hash()` in the zomes.
+pub fn path_entry_hash(path: &str) -> EntryHash {
+    let components: Vec<PathComponent> = path
+        .split('.')
+        .filter(|component| !component.is_empty())
+        .map(|component| {
+            let bytes: Vec<u8> = component
+                .chars()
+                .flat_map(|c| (c as u32).to_le_bytes())
+                .collect();
+            PathComponent(SerializedBytes::from(UnsafeBytes::from(bytes)))
+        })
+        .collect();
+    EntryHash::with_data_sync(&Entry::App(app_entry_bytes(&components)))
+}
+
+/// Msgpack-encode `value` as the bytes of an app entry.
+pub fn app_entry_bytes<T: serde::Serialize + std::fmt::Debug>(value: &T) -> AppEntryBytes {
+    let bytes = holochain_serialized_bytes::encode(value).expect("entry serialization failed");
+    AppEntryBytes(SerializedBytes::from(UnsafeBytes::from(bytes)))
+}
+
+/// Writes straight to `cell`'s source chain, bypassing the coordinator zomes, to
+/// reproduce data written by earlier versions of them.
+pub async fn direct_writer(conductor: &SweetConductor, cell: &SweetCell) -> HostFnCaller {
+    let dna_file = conductor
+        .get_dna_file(cell.cell_id().dna_hash())
+        .expect("the cell has a DNA");
+    HostFnCaller::create(cell.cell_id(), &conductor.raw_handle(), &dna_file).await
+}
@@ -1,3 +1,7 @@
 pub mod conductors;
+pub mod legacy;
+pub mod resources;
 
 pub use conductors::*;
+pub use legacy::*;
+pub use resources::*;
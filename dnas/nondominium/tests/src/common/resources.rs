@@ -0,0 +1,48 @@
+use holochain::prelude::*;
+use holochain::sweettest::*;
+
+/// Mirrors `CastValidationVoteInput` in zome_gouvernance.
+#[derive(Debug, serde::Serialize)]
+struct ValidationVoteInput {
+    resource_hash: ActionHash,
+    approved: bool,
+    notes: Option<String>,
+}
+
+/// `CastValidationVoteOutput` in zome_gouvernance; only the call's success matters here.
+#[derive(Debug, serde::Deserialize)]
+struct ValidationVoteOutput {}
+
+/// Approve a resource pending validation as `validator`. Under the default single-validator
+/// scheme the vote decides its ResourceValidation, and the custodian's cell promotes the
+/// resource to Available; the promoted version is returned as the custodian sees it.
+pub async fn approve_resource(
+    validator_conductor: &SweetConductor,
+    validator: &SweetCell,
+    custodian_conductor: &SweetConductor,
+    custodian: &SweetCell,
+    resource_hash: &ActionHash,
+) -> Record {
+    await_consistency_20_s([custodian, validator]).await.unwrap();
+    let _: ValidationVoteOutput = validator_conductor
+        .call(
+            &validator.zome("zome_gouvernance"),
+            "cast_validation_vote",
+            ValidationVoteInput {
+                resource_hash: resource_hash.clone(),
+                approved: true,
+                notes: None,
+            },
+        )
+        .await;
+    await_consistency_20_s([custodian, validator]).await.unwrap();
+
+    let promoted: Option<Record> = custodian_conductor
+        .call(
+            &custodian.zome("zome_resource"),
+            "get_latest_economic_resource_record",
+            resource_hash.clone(),
+        )
+        .await;
+    promoted.expect("approved resource must still exist")
+}
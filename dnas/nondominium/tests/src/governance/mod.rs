@@ -5,6 +5,12 @@
 //!   - Contribution: validate_contribution, get_ndo_contributions, get_agent_contributions
 //!   - NdoHardLink: create_ndo_hard_link, get_ndo_hard_links, get_ndo_hard_links_by_type
 //!
+//! and bilateral PPR signing (log_economic_event → countersign_participation_receipts),
+//! ValueFlows processes (open_process → input_of → finish_process), and the commitment
+//! lifecycle (propose → accept/decline/cancel → claim → get_commitment_state) with its
+//! agent-centric indexes (get_agent_commitments), and the authorization checks and
+//! resource effects of log_economic_event.
+//!
 //! Prerequisites (runtime — not compile-time):
 //!   bun run build:happ   # builds nondominium.dna
 //!
@@ -12,6 +18,7 @@
 //!   CARGO_TARGET_DIR=target/native-tests cargo test --package nondominium_sweettest --test governance
 
 use holochain::prelude::*;
+use holochain::sweettest::*;
 use serde::{Deserialize, Serialize};
 
 use nondominium_sweettest::common::*;
@@ -20,7 +27,11 @@ use nondominium_shared::io::governance::{
     CreateAgreementInput, CreateNdoHardLinkInput, GetNdoHardLinksByTypeInput,
     UpdateAgreementInput, ValidateContributionInput,
 };
-use nondominium_shared::types::{BeneficiaryRef, BenefitClause, BenefitType, NdoLinkType, VfAction};
+use nondominium_shared::io::resource::AppliedEventEffect;
+use nondominium_shared::types::{
+    BeneficiaryRef, BenefitClause, BenefitType, CommitmentState, CommitmentStatus,
+    FulfillmentProgress, NdoLinkType, VfAction,
+};
 
 // ─── Local output types (partial views for assertion — reference entry types
 //     from integrity zomes which cannot be imported in native test crates) ──────
@@ -78,6 +89,8 @@ struct LogEconomicEventInput {
     pub note: Option<String>,
     pub commitment_hash: Option<ActionHash>,
     pub generate_pprs: Option<bool>,
+    pub to_location: Option<String>,
+    pub resource_unit: Option<String>,
 }
 
 /// Minimal asserted fields from LogEconomicEventOutput.
@@ -86,6 +99,350 @@ struct LogEconomicEventOutput {
     pub event_hash: ActionHash,
 }
 
+/// Mirrors `CryptographicSignature` from the governance integrity zome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CryptographicSignature {
+    pub recipient_signature: Signature,
+    pub counterparty_signature: Signature,
+    pub signed_data_hash: [u8; 32],
+    pub signed_at: Timestamp,
+}
+
+/// Minimal PrivateParticipationClaim fields asserted in tests.
+#[derive(Debug, Serialize, Deserialize)]
+struct ParticipationClaimOutput {
+    pub bilateral_signature: CryptographicSignature,
+    pub counterparty: AgentPubKey,
+}
+
+/// Mirrors `IssueParticipationReceiptsOutput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct IssueParticipationReceiptsOutput {
+    pub provider_claim_hash: ActionHash,
+    pub receiver_claim_hash: ActionHash,
+    pub provider_claim: ParticipationClaimOutput,
+    pub receiver_claim: ParticipationClaimOutput,
+}
+
+/// Mirrors `IssueParticipationReceiptsOutcome`.
+#[derive(Debug, Serialize, Deserialize)]
+enum IssueParticipationReceiptsOutcome {
+    Issued(IssueParticipationReceiptsOutput),
+    AwaitingCounterpartySignature { request_hash: ActionHash },
+}
+
+/// LogEconomicEventOutput including what the event did to its resource.
+#[derive(Debug, Serialize, Deserialize)]
+struct LogEconomicEventWithEffectOutput {
+    pub event_hash: ActionHash,
+    pub resource_effect: Option<AppliedEventEffect>,
+}
+
+/// Minimal EconomicResource fields asserted in tests.
+#[derive(Debug, Serialize, Deserialize)]
+struct EconomicResourceOutput {
+    pub accounting_quantity: f64,
+    pub onhand_quantity: f64,
+    pub custodian: AgentPubKey,
+    pub current_location: Option<Location>,
+}
+
+/// The Location variants used in tests.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Location {
+    Named(String),
+}
+
+/// Minimal UnitRecord fields asserted in tests.
+#[derive(Debug, Serialize, Deserialize)]
+struct UnitRecord {
+    pub action_hash: Option<ActionHash>,
+    pub unit: UnitOfMeasure,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UnitOfMeasure {
+    pub symbol: String,
+    pub label: String,
+    pub dimension: String, // Dimension variant name e.g. "Mass"
+    pub factor: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ConvertQuantityInput {
+    pub quantity: f64,
+    pub from_unit: String,
+    pub to_unit: String,
+}
+
+/// Minimal AcceptCustodyTransferOutput fields asserted in tests.
+#[derive(Debug, Serialize, Deserialize)]
+struct AcceptCustodyTransferOutput {
+    pub event_hash: ActionHash,
+}
+
+/// LogEconomicEventOutput including the generated PPRs.
+#[derive(Debug, Serialize, Deserialize)]
+struct LogEconomicEventWithPprsOutput {
+    pub event_hash: ActionHash,
+    pub ppr_claims: Option<IssueParticipationReceiptsOutcome>,
+}
+
+/// Mirrors `PerformanceMetrics` from the governance integrity zome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PerformanceMetrics {
+    pub timeliness: f64,
+    pub quality: f64,
+    pub reliability: f64,
+    pub communication: f64,
+    pub overall_satisfaction: f64,
+    pub notes: Option<String>,
+}
+
+/// Mirrors `IssueParticipationReceiptsInput` (claim types by variant name).
+#[derive(Debug, Serialize, Deserialize)]
+struct IssueParticipationReceiptsInput {
+    pub fulfills: ActionHash,
+    pub fulfilled_by: ActionHash,
+    pub provider: AgentPubKey,
+    pub receiver: AgentPubKey,
+    pub claim_types: Vec<String>,
+    pub provider_metrics: PerformanceMetrics,
+    pub receiver_metrics: PerformanceMetrics,
+    pub resource_hash: Option<ActionHash>,
+    pub notes: Option<String>,
+}
+
+/// Minimal PprSignatureRequestRecord fields asserted in tests.
+#[derive(Debug, Serialize, Deserialize)]
+struct PprSignatureRequestRecord {
+    pub request_hash: ActionHash,
+}
+
+/// Mirrors `ValidateParticipationClaimSignatureInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct ValidateParticipationClaimSignatureInput {
+    pub signature: CryptographicSignature,
+    pub owner: AgentPubKey,
+    pub counterparty: AgentPubKey,
+}
+
+/// Mirrors `GovernanceRuleInput` from zome_resource (always empty here).
+#[derive(Debug, Serialize, Deserialize)]
+struct GovernanceRuleInput {
+    pub rule_type: String,
+    pub rule_data: String,
+    pub enforced_by: Option<String>,
+}
+
+/// Mirrors `ResourceSpecificationInput` from zome_resource; `validation` is left to its default.
+#[derive(Debug, Serialize, Deserialize)]
+struct ResourceSpecificationInput {
+    pub name: String,
+    pub description: String,
+    pub category: String,
+    pub image_url: Option<String>,
+    pub tags: Vec<String>,
+    pub governance_rules: Vec<GovernanceRuleInput>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateResourceSpecificationOutput {
+    pub spec_hash: ActionHash,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EconomicResourceInput {
+    pub spec_hash: ActionHash,
+    pub quantity: f64,
+    pub unit: String,
+    pub current_location: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateEconomicResourceOutput {
+    pub resource_hash: ActionHash,
+}
+
+/// Mirrors `OpenProcessInput` from zome_gouvernance/process.rs.
+#[derive(Debug, Serialize, Deserialize)]
+struct OpenProcessInput {
+    pub name: String,
+    pub based_on: Option<ActionHash>,
+    pub note: Option<String>,
+    pub planned_start: Option<Timestamp>,
+    pub planned_end: Option<Timestamp>,
+}
+
+/// Minimal Process fields asserted in tests.
+#[derive(Debug, Serialize, Deserialize)]
+struct ProcessOutput {
+    pub name: String,
+    pub finished: bool,
+    pub created_by: AgentPubKey,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProcessRecord {
+    pub action_hash: ActionHash,
+    pub entry: ProcessOutput,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProcessInputs {
+    pub commitments: Vec<ActionHash>,
+    pub economic_events: Vec<ActionHash>,
+    pub contributions: Vec<ActionHash>,
+}
+
+/// Mirrors `ProposeCommitmentInput` from zome_gouvernance/commitment.rs.
+#[derive(Debug, Serialize, Deserialize)]
+struct ProposeCommitmentInput {
+    pub action: VfAction,
+    pub resource_hash: Option<ActionHash>,
+    pub resource_spec_hash: Option<ActionHash>,
+    pub provider: AgentPubKey,
+    pub due_date: Timestamp,
+    pub note: Option<String>,
+    pub input_of: Option<ActionHash>,
+    pub resource_quantity: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProposeCommitmentOutput {
+    pub commitment_hash: ActionHash,
+}
+
+/// Minimal Commitment fields asserted in tests.
+#[derive(Debug, Serialize, Deserialize)]
+struct CommitmentOutput {
+    pub status: CommitmentStatus,
+}
+
+/// Mirrors `UpdateCommitmentStatusOutput` from zome_gouvernance/commitment.rs.
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateCommitmentStatusOutput {
+    pub commitment_hash: ActionHash,
+    pub commitment: CommitmentOutput,
+    pub ppr_claims: Option<IssueParticipationReceiptsOutcome>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ClaimCommitmentInput {
+    pub commitment_hash: ActionHash,
+    pub fulfilled_by: ActionHash,
+    pub fulfillment_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ClaimCommitmentOutput {
+    pub claim_hash: ActionHash,
+    pub state: CommitmentState,
+    pub progress: FulfillmentProgress,
+}
+
+/// Mirrors `GetAgentCommitmentsInput` from zome_gouvernance/commitment.rs.
+#[derive(Debug, Serialize, Deserialize)]
+struct GetAgentCommitmentsInput {
+    pub agent: AgentPubKey,
+    pub side: String, // CommitmentSide variant name: "Provider" | "Receiver"
+    pub due_from: Option<Timestamp>,
+    pub due_until: Option<Timestamp>,
+    pub statuses: Option<Vec<CommitmentStatus>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CommitmentListing {
+    pub commitment_hash: ActionHash,
+    pub commitment: CommitmentOutput,
+}
+
+/// Mirrors the `Commitment` entry from zome_gouvernance_integrity, for writing one
+/// directly to a source chain.
+#[derive(Debug, Serialize, Deserialize)]
+struct CommitmentEntry {
+    pub action: VfAction,
+    pub provider: AgentPubKey,
+    pub receiver: AgentPubKey,
+    pub resource_inventoried_as: Option<ActionHash>,
+    pub resource_conforms_to: Option<ActionHash>,
+    pub input_of: Option<ActionHash>,
+    pub due_date: Timestamp,
+    pub note: Option<String>,
+    pub committed_at: Timestamp,
+    pub resource_quantity: Option<f64>,
+    pub status: CommitmentStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CommitmentStateOutput {
+    pub commitment: CommitmentOutput,
+    pub state: CommitmentState,
+    pub progress: FulfillmentProgress,
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Create an EconomicResource held by `cell`'s agent, made Available by `validator`'s
+/// approval; without a validator it stays PendingValidation.
+async fn create_resource(
+    conductor: &SweetConductor,
+    cell: &SweetCell,
+    validator: Option<(&SweetConductor, &SweetCell)>,
+) -> ActionHash {
+    let spec: CreateResourceSpecificationOutput = conductor
+        .call(
+            &cell.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Workshop drill".to_string(),
+                description: "Cordless drill".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+            },
+        )
+        .await;
+    let created: CreateEconomicResourceOutput = conductor
+        .call(
+            &cell.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 1.0,
+                unit: "unit".to_string(),
+                current_location: None,
+            },
+        )
+        .await;
+    if let Some((validator_conductor, validator)) = validator {
+        approve_resource(
+            validator_conductor,
+            validator,
+            conductor,
+            cell,
+            &created.resource_hash,
+        )
+        .await;
+    }
+    created.resource_hash
+}
+
+async fn latest_resource(
+    conductor: &SweetConductor,
+    cell: &SweetCell,
+    resource: &ActionHash,
+) -> EconomicResourceOutput {
+    conductor
+        .call(
+            &cell.zome("zome_resource"),
+            "get_latest_economic_resource",
+            resource.clone(),
+        )
+        .await
+}
+
 // ─── Tests ────────────────────────────────────────────────────────────────────
 
 /// Create an Agreement (v1), then update it to v2, then verify get_current_agreement
@@ -220,13 +577,18 @@ async fn validate_and_get_contributions() {
 /// get_ndo_hard_links and get_ndo_hard_links_by_type both find it.
 #[tokio::test(flavor = "multi_thread")]
 async fn create_and_get_ndo_hard_link() {
-    let (conductors, cell_alice, _cell_bob) = setup_two_agents().await;
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
 
     let alice_key = cell_alice.agent_pubkey().clone();
     let from_ndo = ActionHash::from_raw_36(vec![3u8; 36]);
     let to_ndo = ActionHash::from_raw_36(vec![4u8; 36]);
     let to_dna = DnaHash::from_raw_36(vec![5u8; 36]);
-    let stub_resource = ActionHash::from_raw_36(vec![6u8; 36]);
+    let resource = create_resource(
+        &conductors[0],
+        &cell_alice,
+        Some((&conductors[1], &cell_bob)),
+    )
+    .await;
 
     // Create a real EconomicEvent to use as fulfillment_hash
     let event_output: LogEconomicEventOutput = conductors[0]
@@ -237,11 +599,13 @@ async fn create_and_get_ndo_hard_link() {
                 action: "Use".to_string(),
                 provider: alice_key.clone(),
                 receiver: alice_key.clone(),
-                resource_inventoried_as: stub_resource,
+                resource_inventoried_as: resource,
                 resource_quantity: 1.0,
                 note: Some("link fulfillment event".to_string()),
                 commitment_hash: None,
                 generate_pprs: Some(false),
+                to_location: None,
+                resource_unit: None,
             },
         )
         .await;
@@ -305,3 +669,1092 @@ async fn create_and_get_ndo_hard_link() {
 
     assert_eq!(derived_links.len(), 0, "DerivedFrom filter should return 0 links");
 }
+
+/// Log a Transfer between Alice and Bob with PPR generation: Bob is online, so both claims
+/// must come back committed with two real signatures that verify over the shared hash.
+#[tokio::test(flavor = "multi_thread")]
+async fn participation_receipts_carry_both_signatures() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let alice_key = cell_alice.agent_pubkey().clone();
+    let bob_key = cell_bob.agent_pubkey().clone();
+    let resource = create_resource(
+        &conductors[0],
+        &cell_alice,
+        Some((&conductors[1], &cell_bob)),
+    )
+    .await;
+
+    // Bob's cell must be initialised so his countersigning cap grant exists
+    let _: Vec<PprSignatureRequestRecord> = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_pending_ppr_signature_requests",
+            (),
+        )
+        .await;
+
+    let event_output: LogEconomicEventWithPprsOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            LogEconomicEventInput {
+                action: "Transfer".to_string(),
+                provider: alice_key.clone(),
+                receiver: bob_key.clone(),
+                resource_inventoried_as: resource,
+                resource_quantity: 1.0,
+                note: Some("bilateral PPR test".to_string()),
+                commitment_hash: None,
+                generate_pprs: Some(true),
+                to_location: None,
+                resource_unit: None,
+            },
+        )
+        .await;
+
+    let receipts = match event_output.ppr_claims {
+        Some(IssueParticipationReceiptsOutcome::Issued(receipts)) => receipts,
+        other => panic!("expected both receipts to be issued, got {:?}", other),
+    };
+
+    let provider_sig = receipts.provider_claim.bilateral_signature.clone();
+    let receiver_sig = receipts.receiver_claim.bilateral_signature.clone();
+    assert_ne!(provider_sig.counterparty_signature, Signature([0u8; 64]));
+    assert_eq!(provider_sig.signed_data_hash, receiver_sig.signed_data_hash);
+    assert_eq!(provider_sig.recipient_signature, receiver_sig.counterparty_signature);
+    assert_eq!(provider_sig.counterparty_signature, receiver_sig.recipient_signature);
+
+    for (signature, owner, counterparty) in [
+        (provider_sig, alice_key.clone(), bob_key.clone()),
+        (receiver_sig, bob_key.clone(), alice_key.clone()),
+    ] {
+        let valid: bool = conductors[0]
+            .call(
+                &cell_alice.zome("zome_gouvernance"),
+                "validate_participation_claim_signature",
+                ValidateParticipationClaimSignatureInput {
+                    signature,
+                    owner,
+                    counterparty,
+                },
+            )
+            .await;
+        assert!(valid, "both signatures on each claim must verify");
+    }
+
+    // Nothing should have been left in Bob's inbox
+    let pending: Vec<PprSignatureRequestRecord> = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_pending_ppr_signature_requests",
+            (),
+        )
+        .await;
+    assert!(pending.is_empty());
+}
+
+/// Receipts whose fulfilling EconomicEvent the counterparty cannot see are not countersigned
+/// unattended: they go to the counterparty's inbox, to be signed and then finalized. The
+/// metrics and notes are only shared with the counterparty, and self-dealing is refused.
+#[tokio::test(flavor = "multi_thread")]
+async fn participation_receipts_without_an_event_wait_in_the_inbox() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let alice_key = cell_alice.agent_pubkey().clone();
+    let bob_key = cell_bob.agent_pubkey().clone();
+    let resource = create_resource(&conductors[0], &cell_alice, None).await;
+
+    // Bob's cell must be initialised so his countersigning cap grant exists
+    let _: Vec<PprSignatureRequestRecord> = conductors[1]
+        .call(&cell_bob.zome("zome_gouvernance"), "get_pending_ppr_signature_requests", ())
+        .await;
+
+    let metrics = PerformanceMetrics {
+        timeliness: 1.0,
+        quality: 1.0,
+        reliability: 1.0,
+        communication: 1.0,
+        overall_satisfaction: 1.0,
+        notes: None,
+    };
+    let terms = |receiver: &AgentPubKey| IssueParticipationReceiptsInput {
+        fulfills: resource.clone(),
+        fulfilled_by: resource.clone(),
+        provider: alice_key.clone(),
+        receiver: receiver.clone(),
+        claim_types: vec![
+            "CustodyTransfer".to_string(),
+            "CustodyAcceptance".to_string(),
+        ],
+        provider_metrics: metrics.clone(),
+        receiver_metrics: PerformanceMetrics {
+            quality: 0.5,
+            ..metrics.clone()
+        },
+        resource_hash: Some(resource.clone()),
+        notes: Some("handed over late".to_string()),
+    };
+
+    // A receipt pair needs two distinct parties
+    let self_dealing: Result<IssueParticipationReceiptsOutcome, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "issue_participation_receipts",
+            terms(&alice_key),
+        )
+        .await;
+    assert!(self_dealing.is_err(), "provider and receiver must differ");
+
+    // The resource is no EconomicEvent, so Bob cannot check what he would sign
+    let outcome: IssueParticipationReceiptsOutcome = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "issue_participation_receipts",
+            terms(&bob_key),
+        )
+        .await;
+    let request_hash = match outcome {
+        IssueParticipationReceiptsOutcome::AwaitingCounterpartySignature { request_hash } => {
+            request_hash
+        }
+        other => panic!("expected a signature request, got {:?}", other),
+    };
+
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+
+    let pending: Vec<PprSignatureRequestRecord> = conductors[1]
+        .call(&cell_bob.zome("zome_gouvernance"), "get_pending_ppr_signature_requests", ())
+        .await;
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].request_hash, request_hash);
+
+    // The public request leaves the metrics and notes out; Bob gets them from Alice
+    let shared: IssueParticipationReceiptsInput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_ppr_signature_request_terms",
+            request_hash.clone(),
+        )
+        .await;
+    assert_eq!(shared.receiver_metrics.quality, 0.5);
+    assert_eq!(shared.notes, Some("handed over late".to_string()));
+
+    let _response: ActionHash = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "sign_ppr_signature_request",
+            request_hash.clone(),
+        )
+        .await;
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+
+    let receipts: IssueParticipationReceiptsOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "finalize_participation_receipts",
+            request_hash,
+        )
+        .await;
+    assert_eq!(
+        receipts.provider_claim.bilateral_signature.signed_data_hash,
+        receipts.receiver_claim.bilateral_signature.signed_data_hash
+    );
+}
+
+/// Open a Process, attach a Commitment to it, then finish it: only the creator may
+/// finish, and a finished Process accepts no further inputs.
+#[tokio::test(flavor = "multi_thread")]
+async fn process_collects_inputs_until_finished() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let alice_key = cell_alice.agent_pubkey().clone();
+    let bob_key = cell_bob.agent_pubkey().clone();
+
+    let process: ProcessRecord = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "open_process",
+            OpenProcessInput {
+                name: "Repair drill".to_string(),
+                based_on: None,
+                note: None,
+                planned_start: None,
+                planned_end: None,
+            },
+        )
+        .await;
+    assert!(!process.entry.finished);
+    assert_eq!(process.entry.created_by, alice_key);
+
+    let commitment = |input_of: ActionHash| ProposeCommitmentInput {
+        action: VfAction::Work,
+        resource_hash: None,
+        resource_spec_hash: None,
+        provider: bob_key.clone(),
+        due_date: Timestamp::now(),
+        note: Some("Replace the motor brushes".to_string()),
+        input_of: Some(input_of),
+        resource_quantity: None,
+    };
+
+    let proposed: ProposeCommitmentOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "propose_commitment",
+            commitment(process.action_hash.clone()),
+        )
+        .await;
+
+    let inputs: ProcessInputs = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "get_process_inputs",
+            process.action_hash.clone(),
+        )
+        .await;
+    assert_eq!(inputs.commitments, vec![proposed.commitment_hash]);
+    assert!(inputs.economic_events.is_empty());
+    assert!(inputs.contributions.is_empty());
+
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+
+    // Bob did not open the process and cannot finish it
+    let bob_finish: Result<ProcessRecord, _> = conductors[1]
+        .call_fallible(
+            &cell_bob.zome("zome_gouvernance"),
+            "finish_process",
+            process.action_hash.clone(),
+        )
+        .await;
+    assert!(bob_finish.is_err(), "only the creator may finish a Process");
+
+    let finished: ProcessRecord = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "finish_process",
+            process.action_hash.clone(),
+        )
+        .await;
+    assert!(finished.entry.finished);
+    assert_eq!(finished.action_hash, process.action_hash);
+
+    let latest: Option<ProcessRecord> = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "get_process",
+            process.action_hash.clone(),
+        )
+        .await;
+    assert!(latest.expect("process should exist").entry.finished);
+
+    let late: Result<ProposeCommitmentOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "propose_commitment",
+            commitment(process.action_hash.clone()),
+        )
+        .await;
+    assert!(late.is_err(), "a finished Process accepts no new commitments");
+
+    let again: Result<ProcessRecord, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "finish_process",
+            process.action_hash,
+        )
+        .await;
+    assert!(again.is_err(), "a Process can only be finished once");
+}
+
+/// Alice asks Bob for three hours of work. Only Bob may accept (which issues the
+/// maintenance acceptance PPRs), and the commitment moves through PartiallyFulfilled to
+/// Fulfilled as he claims events; a declined commitment can no longer be cancelled.
+#[tokio::test(flavor = "multi_thread")]
+async fn commitment_lifecycle_tracks_fulfillment() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let alice_key = cell_alice.agent_pubkey().clone();
+    let bob_key = cell_bob.agent_pubkey().clone();
+    let resource = create_resource(
+        &conductors[0],
+        &cell_alice,
+        Some((&conductors[1], &cell_bob)),
+    )
+    .await;
+    let tomorrow = Timestamp::from_micros(Timestamp::now().as_micros() + 86_400_000_000);
+
+    let proposal = |quantity: Option<f64>| ProposeCommitmentInput {
+        action: VfAction::Work,
+        resource_hash: Some(resource.clone()),
+        resource_spec_hash: None,
+        provider: bob_key.clone(),
+        due_date: tomorrow,
+        note: None,
+        input_of: None,
+        resource_quantity: quantity,
+    };
+
+    let proposed: ProposeCommitmentOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "propose_commitment",
+            proposal(Some(3.0)),
+        )
+        .await;
+    let commitment_hash = proposed.commitment_hash;
+
+    let alice_accept: Result<UpdateCommitmentStatusOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "accept_commitment",
+            commitment_hash.clone(),
+        )
+        .await;
+    assert!(alice_accept.is_err(), "only the provider may accept");
+
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+
+    let accepted: UpdateCommitmentStatusOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "accept_commitment",
+            commitment_hash.clone(),
+        )
+        .await;
+    assert_eq!(accepted.commitment.status, CommitmentStatus::Accepted);
+    assert!(
+        accepted.ppr_claims.is_some(),
+        "accepting a work commitment issues acceptance PPRs"
+    );
+
+    let log_work = |quantity: f64| LogEconomicEventInput {
+        action: "Work".to_string(),
+        provider: bob_key.clone(),
+        receiver: alice_key.clone(),
+        resource_inventoried_as: resource.clone(),
+        resource_quantity: quantity,
+        note: None,
+        commitment_hash: None,
+        generate_pprs: Some(false),
+        to_location: None,
+        resource_unit: None,
+    };
+    let claim = |event_hash: ActionHash| ClaimCommitmentInput {
+        commitment_hash: commitment_hash.clone(),
+        fulfilled_by: event_hash,
+        fulfillment_note: None,
+    };
+
+    let first_event: LogEconomicEventOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "log_economic_event",
+            log_work(1.0),
+        )
+        .await;
+    let first_claim: ClaimCommitmentOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "claim_commitment",
+            claim(first_event.event_hash.clone()),
+        )
+        .await;
+    assert_eq!(first_claim.state, CommitmentState::PartiallyFulfilled);
+    assert_eq!(first_claim.progress.fulfilled_quantity, 1.0);
+
+    let duplicate: Result<ClaimCommitmentOutput, _> = conductors[1]
+        .call_fallible(
+            &cell_bob.zome("zome_gouvernance"),
+            "claim_commitment",
+            claim(first_event.event_hash),
+        )
+        .await;
+    assert!(duplicate.is_err(), "an event counts once towards a commitment");
+
+    let second_event: LogEconomicEventOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "log_economic_event",
+            log_work(2.0),
+        )
+        .await;
+    let second_claim: ClaimCommitmentOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "claim_commitment",
+            claim(second_event.event_hash),
+        )
+        .await;
+    assert_eq!(second_claim.state, CommitmentState::Fulfilled);
+
+    let state: CommitmentStateOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_commitment_state",
+            commitment_hash,
+        )
+        .await;
+    assert_eq!(state.state, CommitmentState::Fulfilled);
+    assert_eq!(state.commitment.status, CommitmentStatus::Accepted);
+    assert_eq!(state.progress.fulfilled_quantity, 3.0);
+    assert_eq!(state.progress.fulfilling_events, 2);
+
+    // A declined commitment is final
+    let declined_proposal: ProposeCommitmentOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "propose_commitment",
+            proposal(None),
+        )
+        .await;
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+    let declined: UpdateCommitmentStatusOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "decline_commitment",
+            declined_proposal.commitment_hash.clone(),
+        )
+        .await;
+    assert_eq!(declined.commitment.status, CommitmentStatus::Declined);
+
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+    let cancel: Result<UpdateCommitmentStatusOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "cancel_commitment",
+            declined_proposal.commitment_hash,
+        )
+        .await;
+    assert!(cancel.is_err(), "a declined commitment cannot be cancelled");
+}
+
+/// When Alice cancels and Bob accepts the same version of a commitment, its update chain
+/// forks; both cells follow the newest update and resolve the same latest version.
+#[tokio::test(flavor = "multi_thread")]
+async fn forked_commitment_updates_resolve_the_same_in_every_cell() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let proposed: ProposeCommitmentOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "propose_commitment",
+            ProposeCommitmentInput {
+                action: VfAction::Work,
+                resource_hash: None,
+                resource_spec_hash: None,
+                provider: cell_bob.agent_pubkey().clone(),
+                due_date: Timestamp::from_micros(Timestamp::now().as_micros() + 86_400_000_000),
+                note: None,
+                input_of: None,
+                resource_quantity: None,
+            },
+        )
+        .await;
+    let commitment_hash = proposed.commitment_hash;
+    await_consistency(60, [&cell_alice, &cell_bob]).await.unwrap();
+
+    let cancelled: UpdateCommitmentStatusOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "cancel_commitment",
+            commitment_hash.clone(),
+        )
+        .await;
+
+    // Bob accepts the proposed version whether or not he has seen the cancellation,
+    // which the coordinator would refuse once it has
+    let bob_writer = direct_writer(&conductors[1], &cell_bob).await;
+    let original = bob_writer
+        .get(commitment_hash.clone().into(), GetOptions::default())
+        .await
+        .pop()
+        .flatten()
+        .expect("Bob sees the proposed commitment");
+    let mut commitment: CommitmentEntry = match original.entry().as_option() {
+        Some(Entry::App(bytes)) => {
+            holochain_serialized_bytes::decode(bytes.bytes()).expect("a Commitment entry")
+        }
+        _ => panic!("expected a Commitment entry"),
+    };
+    commitment.status = CommitmentStatus::Accepted;
+    let accepted_hash = bob_writer
+        .update_entry(
+            Entry::App(app_entry_bytes(&commitment)),
+            commitment_hash.clone(),
+        )
+        .await;
+    await_consistency(60, [&cell_alice, &cell_bob]).await.unwrap();
+
+    let mut forks = Vec::new();
+    for (hash, status) in [
+        (cancelled.commitment_hash, CommitmentStatus::Cancelled),
+        (accepted_hash, CommitmentStatus::Accepted),
+    ] {
+        let record = bob_writer
+            .get(hash.clone().into(), GetOptions::default())
+            .await
+            .pop()
+            .flatten()
+            .expect("both updates are visible");
+        forks.push((record.action().timestamp(), hash, status));
+    }
+    let expected = forks
+        .into_iter()
+        .max_by_key(|(timestamp, hash, _)| (*timestamp, hash.clone()))
+        .map(|(_, _, status)| status);
+
+    for (conductor, cell) in [(&conductors[0], &cell_alice), (&conductors[1], &cell_bob)] {
+        let state: CommitmentStateOutput = conductor
+            .call(
+                &cell.zome("zome_gouvernance"),
+                "get_commitment_state",
+                commitment_hash.clone(),
+            )
+            .await;
+        assert_eq!(Some(state.commitment.status), expected);
+    }
+}
+
+/// Commitments are listed per agent and side from the provider/receiver links, filtered
+/// by the due date carried in the link tag and by stored status.
+#[tokio::test(flavor = "multi_thread")]
+async fn agent_commitments_filter_by_side_due_date_and_status() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let alice_key = cell_alice.agent_pubkey().clone();
+    let bob_key = cell_bob.agent_pubkey().clone();
+    let day = 86_400_000_000;
+    let now = Timestamp::now().as_micros();
+
+    let mut hashes = Vec::new();
+    for days in [10, 1] {
+        let proposed: ProposeCommitmentOutput = conductors[0]
+            .call(
+                &cell_alice.zome("zome_gouvernance"),
+                "propose_commitment",
+                ProposeCommitmentInput {
+                    action: VfAction::Work,
+                    resource_hash: None,
+                    resource_spec_hash: None,
+                    provider: bob_key.clone(),
+                    due_date: Timestamp::from_micros(now + days * day),
+                    note: None,
+                    input_of: None,
+                    resource_quantity: None,
+                },
+            )
+            .await;
+        hashes.push(proposed.commitment_hash);
+    }
+    let (later, sooner) = (hashes[0].clone(), hashes[1].clone());
+
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+
+    let list = |agent: AgentPubKey, side: &str, due_until: Option<i64>, statuses| {
+        GetAgentCommitmentsInput {
+            agent,
+            side: side.to_string(),
+            due_from: None,
+            due_until: due_until.map(Timestamp::from_micros),
+            statuses,
+        }
+    };
+
+    // What Bob owes, soonest first
+    let owed_by_bob: Vec<CommitmentListing> = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_agent_commitments",
+            list(bob_key.clone(), "Provider", None, None),
+        )
+        .await;
+    let order: Vec<ActionHash> = owed_by_bob.iter().map(|l| l.commitment_hash.clone()).collect();
+    assert_eq!(order, vec![sooner.clone(), later.clone()]);
+
+    // Bob is not a receiver of anything
+    let owed_to_bob: Vec<CommitmentListing> = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_agent_commitments",
+            list(bob_key.clone(), "Receiver", None, None),
+        )
+        .await;
+    assert!(owed_to_bob.is_empty());
+
+    // Due within two days
+    let due_soon: Vec<CommitmentListing> = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "get_agent_commitments",
+            list(alice_key.clone(), "Receiver", Some(now + 2 * day), None),
+        )
+        .await;
+    assert_eq!(due_soon.len(), 1);
+    assert_eq!(due_soon[0].commitment_hash, sooner);
+
+    let _: UpdateCommitmentStatusOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "decline_commitment",
+            sooner,
+        )
+        .await;
+
+    let still_proposed: Vec<CommitmentListing> = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_agent_commitments",
+            list(bob_key, "Provider", None, Some(vec![CommitmentStatus::Proposed])),
+        )
+        .await;
+    assert_eq!(still_proposed.len(), 1);
+    assert_eq!(still_proposed[0].commitment_hash, later);
+    assert_eq!(still_proposed[0].commitment.status, CommitmentStatus::Proposed);
+}
+
+/// A commitment written before the agent indexes existed is only linked from the
+/// `all_commitments` root, and is still listed for its provider and receiver.
+#[tokio::test(flavor = "multi_thread")]
+async fn commitments_without_agent_index_links_are_still_listed() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let alice_key = cell_alice.agent_pubkey().clone();
+    let bob_key = cell_bob.agent_pubkey().clone();
+    let now = Timestamp::now();
+
+    // Commitment is entry type 2 and AllCommitments link type 6 of
+    // zome_gouvernance_integrity, the DNA's third integrity zome
+    let alice_writer = direct_writer(&conductors[0], &cell_alice).await;
+    let commitment_hash = alice_writer
+        .commit_entry(
+            Entry::App(app_entry_bytes(&CommitmentEntry {
+                action: VfAction::Work,
+                provider: bob_key.clone(),
+                receiver: alice_key.clone(),
+                resource_inventoried_as: None,
+                resource_conforms_to: None,
+                input_of: None,
+                due_date: Timestamp::from_micros(now.as_micros() + 86_400_000_000),
+                note: Some("Proposed before the agent indexes".to_string()),
+                committed_at: now,
+                resource_quantity: None,
+                status: CommitmentStatus::Proposed,
+            })),
+            EntryDefLocation::App(AppEntryDefLocation {
+                zome_index: ZomeIndex(2),
+                entry_def_index: EntryDefIndex(2),
+            }),
+            EntryVisibility::Public,
+        )
+        .await;
+    let _ = alice_writer
+        .create_link(
+            path_entry_hash("all_commitments").into(),
+            commitment_hash.clone().into(),
+            ZomeIndex(2),
+            LinkType(6),
+            LinkTag::new(vec![]),
+        )
+        .await;
+    await_consistency(60, [&cell_alice, &cell_bob]).await.unwrap();
+
+    let for_bob: Vec<CommitmentEntry> = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_commitments_for_agent",
+            bob_key.clone(),
+        )
+        .await;
+    assert_eq!(for_bob.len(), 1);
+    assert_eq!(
+        for_bob[0].note.as_deref(),
+        Some("Proposed before the agent indexes")
+    );
+
+    let owed_by_bob: Vec<CommitmentListing> = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "get_agent_commitments",
+            GetAgentCommitmentsInput {
+                agent: bob_key,
+                side: "Provider".to_string(),
+                due_from: None,
+                due_until: None,
+                statuses: None,
+            },
+        )
+        .await;
+    assert_eq!(owed_by_bob.len(), 1);
+    assert_eq!(owed_by_bob[0].commitment_hash, commitment_hash);
+
+    let owed_to_alice: Vec<CommitmentListing> = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "get_agent_commitments",
+            GetAgentCommitmentsInput {
+                agent: alice_key,
+                side: "Receiver".to_string(),
+                due_from: None,
+                due_until: None,
+                statuses: Some(vec![CommitmentStatus::Proposed]),
+            },
+        )
+        .await;
+    assert_eq!(owed_to_alice.len(), 1);
+    assert_eq!(owed_to_alice[0].commitment_hash, commitment_hash);
+}
+
+/// Events must be logged by one of their parties, against an existing resource whose
+/// custodian takes part and whose state admits the action.
+#[tokio::test(flavor = "multi_thread")]
+async fn economic_events_require_parties_and_a_consistent_resource() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let alice_key = cell_alice.agent_pubkey().clone();
+    let bob_key = cell_bob.agent_pubkey().clone();
+    let available = create_resource(
+        &conductors[0],
+        &cell_alice,
+        Some((&conductors[1], &cell_bob)),
+    )
+    .await;
+    let pending = create_resource(&conductors[0], &cell_alice, None).await;
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+
+    let event = |action: &str,
+                 provider: &AgentPubKey,
+                 receiver: &AgentPubKey,
+                 resource: &ActionHash| LogEconomicEventInput {
+        action: action.to_string(),
+        provider: provider.clone(),
+        receiver: receiver.clone(),
+        resource_inventoried_as: resource.clone(),
+        resource_quantity: 1.0,
+        note: None,
+        commitment_hash: None,
+        generate_pprs: Some(false),
+        to_location: None,
+        resource_unit: None,
+    };
+
+    // Bob cannot attribute Alice's use of her own drill
+    let third_party: Result<LogEconomicEventOutput, _> = conductors[1]
+        .call_fallible(
+            &cell_bob.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Use", &alice_key, &alice_key, &available),
+        )
+        .await;
+    assert!(third_party.is_err(), "the caller must be provider or receiver");
+
+    let missing: Result<LogEconomicEventOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Use", &alice_key, &bob_key, &ActionHash::from_raw_36(vec![8u8; 36])),
+        )
+        .await;
+    assert!(missing.is_err(), "the resource must exist");
+
+    // Bob is not Alice's drill's custodian, so he cannot lend it to himself
+    let not_custodian: Result<LogEconomicEventOutput, _> = conductors[1]
+        .call_fallible(
+            &cell_bob.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Use", &bob_key, &bob_key, &available),
+        )
+        .await;
+    assert!(not_custodian.is_err(), "the custodian must be a party");
+
+    let unvalidated: Result<LogEconomicEventOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Transfer", &alice_key, &bob_key, &pending),
+        )
+        .await;
+    assert!(unvalidated.is_err(), "resources pending validation cannot be transferred");
+
+    // Bob may record receiving the drill from its custodian
+    let _: LogEconomicEventOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Use", &alice_key, &bob_key, &available),
+        )
+        .await;
+}
+
+/// Logged events change their resource: Produce and Consume adjust its quantity but never
+/// below zero, Move relocates it, and Transfer hands it over once the receiver accepts.
+#[tokio::test(flavor = "multi_thread")]
+async fn economic_events_apply_their_resource_effects() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+
+    let alice_key = cell_alice.agent_pubkey().clone();
+    let bob_key = cell_bob.agent_pubkey().clone();
+    let resource = create_resource(
+        &conductors[0],
+        &cell_alice,
+        Some((&conductors[1], &cell_bob)),
+    )
+    .await;
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+
+    let event = |action: &str, receiver: &AgentPubKey, quantity: f64, to: Option<&str>| {
+        LogEconomicEventInput {
+            action: action.to_string(),
+            provider: alice_key.clone(),
+            receiver: receiver.clone(),
+            resource_inventoried_as: resource.clone(),
+            resource_quantity: quantity,
+            note: None,
+            commitment_hash: None,
+            generate_pprs: Some(false),
+            to_location: to.map(str::to_string),
+            resource_unit: None,
+        }
+    };
+
+    let produced: LogEconomicEventWithEffectOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Produce", &alice_key, 2.0, None),
+        )
+        .await;
+    assert!(produced.resource_effect.unwrap().updated_resource_hash.is_some());
+    let produced_resource = latest_resource(&conductors[0], &cell_alice, &resource).await;
+    assert_eq!(produced_resource.accounting_quantity, 3.0);
+    assert_eq!(produced_resource.onhand_quantity, 3.0);
+
+    let overdrawn: Result<LogEconomicEventWithEffectOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Consume", &alice_key, 5.0, None),
+        )
+        .await;
+    assert!(overdrawn.is_err(), "a resource cannot be consumed below zero");
+
+    // A negative Consume would raise the stock, and NaN would slip past the zero checks
+    for quantity in [-5.0, f64::NAN] {
+        let invalid: Result<LogEconomicEventWithEffectOutput, _> = conductors[0]
+            .call_fallible(
+                &cell_alice.zome("zome_gouvernance"),
+                "log_economic_event",
+                event("Consume", &alice_key, quantity, None),
+            )
+            .await;
+        assert!(invalid.is_err(), "event quantities must be positive numbers");
+    }
+
+    // Bob takes part as receiver, but only the custodian may change the resource
+    let not_custodian: Result<LogEconomicEventWithEffectOutput, _> = conductors[1]
+        .call_fallible(
+            &cell_bob.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Consume", &bob_key, 1.0, None),
+        )
+        .await;
+    assert!(not_custodian.is_err(), "only the custodian applies resource effects");
+
+    let nowhere: Result<LogEconomicEventWithEffectOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Move", &alice_key, 3.0, None),
+        )
+        .await;
+    assert!(nowhere.is_err(), "a Move needs a destination");
+
+    let _: LogEconomicEventWithEffectOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Move", &alice_key, 3.0, Some("Workshop B")),
+        )
+        .await;
+    let _: LogEconomicEventWithEffectOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Consume", &alice_key, 3.0, None),
+        )
+        .await;
+    let moved = latest_resource(&conductors[0], &cell_alice, &resource).await;
+    // Free-text destinations are recorded as named places
+    assert_eq!(moved.current_location, Some(Location::Named("Workshop B".to_string())));
+    assert_eq!(moved.accounting_quantity, 0.0, "consuming everything leaves an empty resource");
+    assert_eq!(moved.onhand_quantity, 0.0);
+
+    // Use has no effect on the resource
+    let used: LogEconomicEventWithEffectOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Use", &alice_key, 1.0, None),
+        )
+        .await;
+    assert!(used.resource_effect.is_none());
+
+    let transfer: LogEconomicEventWithEffectOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            event("Transfer", &bob_key, 1.0, None),
+        )
+        .await;
+    let proposal_hash = transfer
+        .resource_effect
+        .and_then(|effect| effect.custody_proposal_hash)
+        .expect("a Transfer proposes custody to the receiver");
+    let pending = latest_resource(&conductors[0], &cell_alice, &resource).await;
+    assert_eq!(pending.custodian, alice_key, "custody moves on acceptance");
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+
+    let accepted: AcceptCustodyTransferOutput = conductors[1]
+        .call(
+            &cell_bob.zome("zome_resource"),
+            "accept_custody_transfer",
+            proposal_hash,
+        )
+        .await;
+    assert_eq!(accepted.event_hash, transfer.event_hash, "no second transfer event");
+    await_consistency_20_s([&cell_alice, &cell_bob]).await.unwrap();
+    assert_eq!(latest_resource(&conductors[0], &cell_alice, &resource).await.custodian, bob_key);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn economic_events_convert_quantities_into_the_resource_unit() {
+    let (conductors, cell_alice, cell_bob) = setup_two_agents().await;
+    let alice_key = cell_alice.agent_pubkey().clone();
+
+    // The OM2 units are built in, without any entry on the chain
+    let units: Vec<UnitRecord> = conductors[0]
+        .call(&cell_alice.zome("zome_resource"), "get_all_units", ())
+        .await;
+    let kg = units.iter().find(|record| record.unit.symbol == "kg").expect("kg is built in");
+    assert!(kg.action_hash.is_none());
+    assert_eq!(kg.unit.dimension, "Mass");
+    assert_eq!(kg.unit.factor, 1.0);
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Pooled clay".to_string(),
+                description: "Shared pottery clay".to_string(),
+                category: "Materials".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+            },
+        )
+        .await;
+    let created: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 2.0,
+                unit: "kg".to_string(),
+                current_location: None,
+            },
+        )
+        .await;
+    let resource = created.resource_hash;
+    approve_resource(
+        &conductors[1],
+        &cell_bob,
+        &conductors[0],
+        &cell_alice,
+        &resource,
+    )
+    .await;
+
+    let consume = |quantity: f64, unit: &str| LogEconomicEventInput {
+        action: "Consume".to_string(),
+        provider: alice_key.clone(),
+        receiver: alice_key.clone(),
+        resource_inventoried_as: resource.clone(),
+        resource_quantity: quantity,
+        note: None,
+        commitment_hash: None,
+        generate_pprs: Some(false),
+        to_location: None,
+        resource_unit: Some(unit.to_string()),
+    };
+
+    let _: LogEconomicEventWithEffectOutput = conductors[0]
+        .call(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            consume(500.0, "g"),
+        )
+        .await;
+    let consumed = latest_resource(&conductors[0], &cell_alice, &resource).await;
+    assert_eq!(consumed.accounting_quantity, 1.5, "500 g is taken off in kg");
+    assert_eq!(consumed.onhand_quantity, 1.5);
+
+    let incompatible: Result<LogEconomicEventWithEffectOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            consume(1.0, "l"),
+        )
+        .await;
+    assert!(incompatible.is_err(), "litres cannot be taken off a mass");
+
+    let overdrawn: Result<LogEconomicEventWithEffectOutput, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_gouvernance"),
+            "log_economic_event",
+            consume(2000.0, "g"),
+        )
+        .await;
+    assert!(overdrawn.is_err(), "2000 g is more than the 1.5 kg left");
+
+    // Community units convert like built-in ones
+    let _: UnitRecord = conductors[0]
+        .call(
+            &cell_alice.zome("zome_resource"),
+            "create_unit",
+            UnitOfMeasure {
+                symbol: "bag".to_string(),
+                label: "25 kg bag".to_string(),
+                dimension: "Mass".to_string(),
+                factor: 25.0,
+            },
+        )
+        .await;
+    let in_bags: f64 = conductors[0]
+        .call(
+            &cell_alice.zome("zome_resource"),
+            "convert_quantity",
+            ConvertQuantityInput {
+                quantity: 50.0,
+                from_unit: "kg".to_string(),
+                to_unit: "bag".to_string(),
+            },
+        )
+        .await;
+    assert_eq!(in_bags, 2.0);
+
+    let redefined: Result<UnitRecord, _> = conductors[0]
+        .call_fallible(
+            &cell_alice.zome("zome_resource"),
+            "create_unit",
+            UnitOfMeasure {
+                symbol: "kg".to_string(),
+                label: "kilogram".to_string(),
+                dimension: "Mass".to_string(),
+                factor: 2.0,
+            },
+        )
+        .await;
+    assert!(redefined.is_err(), "built-in units cannot be redefined");
+}
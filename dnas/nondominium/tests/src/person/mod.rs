@@ -2,7 +2,12 @@
 //!
 //! Covers the hREA bridge: creating a Person entry triggers a cross-DNA
 //! `create_rea_agent` call into the hREA DNA, and the resulting ActionHash
-//! is stored in `Person.hrea_agent_hash`.
+//! is stored in `Person.hrea_agent_hash`. Also covers the role registry:
+//! default roles, and community roles proposed, ratified and assigned; and
+//! role promotion requests, from the request to the approver's inbox; who
+//! may assign which role on networks with and without a progenitor; and the suspension,
+//! revocation and expiry of roles; and agent types, from collectives bridged
+//! to hREA as organisations to bots answerable to a consenting Accountable Agent.
 //!
 //! Prerequisites (runtime — not compile-time):
 //!   bun run build:happ   # builds both nondominium.dna and hrea.dna
@@ -11,6 +16,8 @@
 //!   CARGO_TARGET_DIR=target/native-tests cargo test --test person
 
 use holochain::prelude::*;
+use holochain::sweettest::*;
+use holochain::test_utils::host_fn_caller::HostFnCaller;
 use serde::{Deserialize, Serialize};
 
 use nondominium_sweettest::common::*;
@@ -26,6 +33,25 @@ struct PersonInput {
     pub name: String,
     pub avatar_url: Option<String>,
     pub bio: Option<String>,
+    pub agent_type: Option<AgentEntityType>,
+}
+
+/// Mirrors `zome_person_integrity::AgentEntityType`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum AgentEntityType {
+    Individual,
+    Collective(String),
+    Project(ActionHash),
+    Network(ActionHash),
+    Bot {
+        capabilities: Vec<String>,
+        operator: AgentPubKey,
+        #[serde(default)]
+        operator_role: Option<ActionHash>,
+        #[serde(default)]
+        operator_consent: Option<ActionHash>,
+    },
+    ExternalOrganisation(String),
 }
 
 /// Mirrors `zome_person_integrity::Person`.
@@ -52,6 +78,175 @@ struct ReaAgent {
     pub note: Option<String>,
 }
 
+/// Mirrors `zome_person_coordinator::PersonRoleInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersonRoleInput {
+    pub agent_pubkey: AgentPubKey,
+    pub role_name: String,
+    pub description: Option<String>,
+    pub expires_at: Option<Timestamp>,
+}
+
+/// Mirrors `zome_person_integrity::PersonRole`.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersonRole {
+    pub role_name: String,
+    pub assigned_to: AgentPubKey,
+    pub assigned_by: AgentPubKey,
+    #[serde(default)]
+    pub role_definition: Option<ActionHash>,
+    #[serde(default)]
+    pub assigner_role: Option<ActionHash>,
+    #[serde(default)]
+    pub expires_at: Option<Timestamp>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetPersonRolesOutput {
+    pub roles: Vec<PersonRole>,
+}
+
+/// Mirrors `zome_person_coordinator::RoleRevocationInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RoleRevocationInput {
+    pub person_role: ActionHash,
+    pub reason: String,
+}
+
+/// Mirrors `zome_person_integrity::RoleRevocationKind`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum RoleRevocationKind {
+    Revoke,
+    Suspend,
+    Reinstate,
+}
+
+/// Mirrors `zome_person_integrity::RoleRevocation`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RoleRevocation {
+    pub kind: RoleRevocationKind,
+    pub revoked_by: AgentPubKey,
+}
+
+/// Mirrors `zome_person_coordinator::RoleStatus`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum RoleStatus {
+    Active,
+    Expired,
+    Suspended,
+    Revoked,
+}
+
+/// Mirrors `zome_person_coordinator::RoleDefinitionInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RoleDefinitionInput {
+    pub role_name: String,
+    pub capability_level: String,
+    pub description: Option<String>,
+    pub validation_requirements: Option<String>,
+    pub assignable_by: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum RoleDefinitionStatus {
+    Proposed,
+    Ratified,
+}
+
+/// Mirrors `zome_person_integrity::RoleDefinition`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RoleDefinition {
+    pub role_name: String,
+    pub capability_level: String,
+    pub assignable_by: String,
+    pub status: RoleDefinitionStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RoleDefinitionRecord {
+    pub action_hash: ActionHash,
+    pub definition: RoleDefinition,
+}
+
+/// Mirrors `zome_resource_coordinator::GovernanceRuleInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct GovernanceRuleInput {
+    pub rule_type: String,
+    pub rule_data: String,
+    pub enforced_by: Option<String>,
+}
+
+/// Mirrors `zome_person_coordinator::PrivatePersonDataInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct PrivatePersonDataInput {
+    pub legal_name: String,
+    pub email: String,
+    pub phone: Option<String>,
+    pub address: Option<String>,
+    pub emergency_contact: Option<String>,
+    pub time_zone: Option<String>,
+    pub location: Option<String>,
+}
+
+/// Mirrors `zome_person_coordinator::GrantPrivateDataAccessInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct GrantPrivateDataAccessInput {
+    pub agent_to_grant: AgentPubKey,
+    pub fields_allowed: Vec<String>,
+    pub context: String,
+    pub expires_in_days: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GrantPrivateDataAccessOutput {
+    pub grant_hash: ActionHash,
+}
+
+/// Mirrors `zome_person_coordinator::RolePromotionRequestInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RolePromotionRequestInput {
+    pub target_role: String,
+    pub justification: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum PromotionRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Withdrawn,
+}
+
+/// Mirrors `zome_person_integrity::RolePromotionRequest`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RolePromotionRequest {
+    pub requesting_agent: AgentPubKey,
+    pub target_role: String,
+    pub status: PromotionRequestStatus,
+    pub reviewed_by: Option<AgentPubKey>,
+    pub review_notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PromotionRequestRecord {
+    pub action_hash: ActionHash,
+    pub request: RolePromotionRequest,
+}
+
+/// Mirrors `zome_person_coordinator::ApprovePromotionInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct ApprovePromotionInput {
+    pub request_hash: ActionHash,
+    pub approval_notes: Option<String>,
+}
+
+/// Mirrors `zome_person_coordinator::RejectPromotionInput`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RejectPromotionInput {
+    pub request_hash: ActionHash,
+    pub reason: String,
+}
+
 // ---------------------------------------------------------------------------
 // Decode helper
 // ---------------------------------------------------------------------------
@@ -89,6 +284,7 @@ async fn person_create_populates_hrea_agent_hash() {
         name: "Alice".to_string(),
         avatar_url: None,
         bio: None,
+        agent_type: None,
     };
 
     let record: Record = conductors[0]
@@ -122,6 +318,7 @@ async fn get_hrea_agents_returns_matching_rea_agent() {
         name: "Bob".to_string(),
         avatar_url: Some(avatar.clone()),
         bio: None,
+        agent_type: None,
     };
 
     let person_record: Record = conductors[0]
@@ -168,3 +365,1106 @@ async fn get_hrea_agents_returns_matching_rea_agent() {
         "ReaAgent.image should match Person.avatar_url"
     );
 }
+
+/// Community roles join the registry once ratified by a governance-level agent;
+/// they can then be assigned and grant the capability level of their definition.
+#[tokio::test(flavor = "multi_thread")]
+async fn community_roles_are_proposed_ratified_and_assigned() {
+    let (conductors, alice, bob) = setup_two_agents_with_progenitor().await;
+    let person = |name: &str| PersonInput {
+        name: name.to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: None,
+    };
+    let _: Record = conductors[0]
+        .call(&alice.zome("zome_person"), "create_person", person("Alice"))
+        .await;
+    let _: Record = conductors[1]
+        .call(&bob.zome("zome_person"), "create_person", person("Bob"))
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let defaults: Vec<RoleDefinitionRecord> = conductors[0]
+        .call(&alice.zome("zome_person"), "get_all_role_definitions", ())
+        .await;
+    for name in ["Simple Agent", "Primary Accountable Agent", "Storage Agent"] {
+        assert!(
+            defaults
+                .iter()
+                .any(|record| record.definition.role_name == name),
+            "default role {} is seeded at init",
+            name
+        );
+    }
+
+    let proposal: RoleDefinitionRecord = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "propose_role_definition",
+            RoleDefinitionInput {
+                role_name: "Laser Operator".to_string(),
+                capability_level: "stewardship".to_string(),
+                description: Some("Runs the laser cutter".to_string()),
+                validation_requirements: None,
+                assignable_by: "coordination".to_string(),
+            },
+        )
+        .await;
+    assert_eq!(proposal.definition.status, RoleDefinitionStatus::Proposed);
+
+    let laser_operator = |agent_pubkey: AgentPubKey| PersonRoleInput {
+        agent_pubkey,
+        role_name: "Laser Operator".to_string(),
+        description: None,
+        expires_at: None,
+    };
+    let unratified: Result<Record, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            laser_operator(bob.agent_pubkey().clone()),
+        )
+        .await;
+    assert!(unratified.is_err(), "proposed roles cannot be assigned");
+
+    let by_member: Result<RoleDefinitionRecord, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_person"),
+            "ratify_role_definition",
+            proposal.action_hash.clone(),
+        )
+        .await;
+    assert!(
+        by_member.is_err(),
+        "only governance-level agents ratify roles"
+    );
+
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            PersonRoleInput {
+                agent_pubkey: alice.agent_pubkey().clone(),
+                role_name: "Primary Accountable Agent".to_string(),
+                description: None,
+                expires_at: None,
+            },
+        )
+        .await;
+    let ratified: RoleDefinitionRecord = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "ratify_role_definition",
+            proposal.action_hash.clone(),
+        )
+        .await;
+    assert_eq!(ratified.definition.status, RoleDefinitionStatus::Ratified);
+
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            laser_operator(bob.agent_pubkey().clone()),
+        )
+        .await;
+    let roles: GetPersonRolesOutput = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_person_roles",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(roles.roles.len(), 1);
+    assert_eq!(roles.roles[0].role_name, "Laser Operator");
+    assert_eq!(roles.roles[0].assigned_by, *alice.agent_pubkey());
+    assert!(roles.roles[0].role_definition.is_some());
+
+    let level: String = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_person_capability_level",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(level, "stewardship");
+
+    // Governance rules may require registry roles
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_governance_rule",
+            GovernanceRuleInput {
+                rule_type: "access_requirement".to_string(),
+                rule_data: r#"{"min_role": "Laser Operator"}"#.to_string(),
+                enforced_by: None,
+            },
+        )
+        .await;
+
+    let duplicate: Result<RoleDefinitionRecord, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_person"),
+            "propose_role_definition",
+            RoleDefinitionInput {
+                role_name: "Laser Operator".to_string(),
+                capability_level: "governance".to_string(),
+                description: None,
+                validation_requirements: None,
+                assignable_by: "member".to_string(),
+            },
+        )
+        .await;
+    assert!(
+        duplicate.is_err(),
+        "ratified role names cannot be proposed again"
+    );
+}
+
+/// Promotion requests are stored entries: they reach the inbox of agents who may
+/// assign the role (never the requester's), and leave it once reviewed or withdrawn.
+#[tokio::test(flavor = "multi_thread")]
+async fn promotion_requests_reach_the_approver_inbox() {
+    let (conductors, alice, bob) = setup_two_agents_with_progenitor().await;
+    let person = |name: &str| PersonInput {
+        name: name.to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: None,
+    };
+    let _: Record = conductors[0]
+        .call(&alice.zome("zome_person"), "create_person", person("Alice"))
+        .await;
+    let _: Record = conductors[1]
+        .call(&bob.zome("zome_person"), "create_person", person("Bob"))
+        .await;
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            PersonRoleInput {
+                agent_pubkey: alice.agent_pubkey().clone(),
+                role_name: "Primary Accountable Agent".to_string(),
+                description: None,
+                expires_at: None,
+            },
+        )
+        .await;
+
+    // Bob's request is checked against his own private data
+    let _: Record = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "store_private_person_data",
+            PrivatePersonDataInput {
+                legal_name: "Bob Builder".to_string(),
+                email: "bob@example.com".to_string(),
+                phone: Some("+1-555-0101".to_string()),
+                address: None,
+                emergency_contact: None,
+                time_zone: None,
+                location: None,
+            },
+        )
+        .await;
+    let _: GrantPrivateDataAccessOutput = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "grant_private_data_access",
+            GrantPrivateDataAccessInput {
+                agent_to_grant: bob.agent_pubkey().clone(),
+                fields_allowed: vec!["email".to_string(), "phone".to_string()],
+                context: "role_promotion".to_string(),
+                expires_in_days: Some(7),
+            },
+        )
+        .await;
+
+    let request = || RolePromotionRequestInput {
+        target_role: "Accountable Agent".to_string(),
+        justification: "Keeps the tool library running".to_string(),
+    };
+    let request_hash: ActionHash = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "request_role_promotion",
+            request(),
+        )
+        .await;
+    let duplicate: Result<ActionHash, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_person"),
+            "request_role_promotion",
+            request(),
+        )
+        .await;
+    assert!(duplicate.is_err(), "one pending request per role");
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let inbox: Vec<PromotionRequestRecord> = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_pending_promotion_requests",
+            (),
+        )
+        .await;
+    assert_eq!(inbox.len(), 1);
+    assert_eq!(inbox[0].action_hash, request_hash);
+    assert_eq!(inbox[0].request.requesting_agent, *bob.agent_pubkey());
+    assert_eq!(inbox[0].request.status, PromotionRequestStatus::Pending);
+
+    let own: Vec<PromotionRequestRecord> = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_pending_promotion_requests",
+            (),
+        )
+        .await;
+    assert!(own.is_empty(), "agents do not review their own requests");
+
+    let _: PromotionRequestRecord = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "reject_role_promotion",
+            RejectPromotionInput {
+                request_hash: request_hash.clone(),
+                reason: "Needs a first validated resource".to_string(),
+            },
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let inbox: Vec<PromotionRequestRecord> = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_pending_promotion_requests",
+            (),
+        )
+        .await;
+    assert!(inbox.is_empty(), "reviewed requests leave the inbox");
+    let mine: Vec<PromotionRequestRecord> = conductors[1]
+        .call(&bob.zome("zome_person"), "get_my_promotion_requests", ())
+        .await;
+    assert_eq!(mine.len(), 1);
+    assert_eq!(mine[0].request.status, PromotionRequestStatus::Rejected);
+    assert_eq!(
+        mine[0].request.reviewed_by.as_ref(),
+        Some(alice.agent_pubkey())
+    );
+    assert_eq!(
+        mine[0].request.review_notes.as_deref(),
+        Some("Needs a first validated resource")
+    );
+
+    // A rejected request can be made again, and withdrawn by its requester
+    let retry_hash: ActionHash = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "request_role_promotion",
+            request(),
+        )
+        .await;
+    let by_other: Result<PromotionRequestRecord, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_person"),
+            "withdraw_role_promotion",
+            retry_hash.clone(),
+        )
+        .await;
+    assert!(by_other.is_err(), "only the requester withdraws a request");
+    let withdrawn: PromotionRequestRecord = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "withdraw_role_promotion",
+            retry_hash,
+        )
+        .await;
+    assert_eq!(withdrawn.request.status, PromotionRequestStatus::Withdrawn);
+}
+
+/// Approval follows the same rule as the inbox: a stewardship level agent approves a
+/// community role `assignable_by` stewardship, and the role is assigned.
+#[tokio::test(flavor = "multi_thread")]
+async fn stewardship_assignable_roles_are_approved_by_stewards() {
+    let (conductors, alice, bob) = setup_two_agents_with_progenitor().await;
+    let person = |name: &str| PersonInput {
+        name: name.to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: None,
+    };
+    let _: Record = conductors[0]
+        .call(&alice.zome("zome_person"), "create_person", person("Alice"))
+        .await;
+    let _: Record = conductors[1]
+        .call(&bob.zome("zome_person"), "create_person", person("Bob"))
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let role = |agent_pubkey: &AgentPubKey, role_name: &str| PersonRoleInput {
+        agent_pubkey: agent_pubkey.clone(),
+        role_name: role_name.to_string(),
+        description: None,
+        expires_at: None,
+    };
+    let governance: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            role(alice.agent_pubkey(), "Primary Accountable Agent"),
+        )
+        .await;
+    let proposal: RoleDefinitionRecord = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "propose_role_definition",
+            RoleDefinitionInput {
+                role_name: "Tool Librarian".to_string(),
+                capability_level: "stewardship".to_string(),
+                description: Some("Lends out the shared tools".to_string()),
+                validation_requirements: None,
+                assignable_by: "stewardship".to_string(),
+            },
+        )
+        .await;
+    let _: RoleDefinitionRecord = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "ratify_role_definition",
+            proposal.action_hash,
+        )
+        .await;
+
+    // Alice keeps only a stewardship level role
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            role(alice.agent_pubkey(), "Tool Librarian"),
+        )
+        .await;
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "revoke_person_role",
+            RoleRevocationInput {
+                person_role: governance.action_address().clone(),
+                reason: "Stepping down".to_string(),
+            },
+        )
+        .await;
+    let level: String = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_person_capability_level",
+            alice.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(level, "stewardship");
+
+    // Bob's private data is checked on request and again on approval
+    let private_data = |legal_name: &str, email: &str| PrivatePersonDataInput {
+        legal_name: legal_name.to_string(),
+        email: email.to_string(),
+        phone: Some("+1-555-0101".to_string()),
+        address: None,
+        emergency_contact: None,
+        time_zone: Some("Europe/Paris".to_string()),
+        location: Some("Lyon".to_string()),
+    };
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "store_private_person_data",
+            private_data("Alice Smith", "alice@example.com"),
+        )
+        .await;
+    let _: Record = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "store_private_person_data",
+            private_data("Bob Builder", "bob@example.com"),
+        )
+        .await;
+    for agent_to_grant in [bob.agent_pubkey(), alice.agent_pubkey()] {
+        let _: GrantPrivateDataAccessOutput = conductors[1]
+            .call(
+                &bob.zome("zome_person"),
+                "grant_private_data_access",
+                GrantPrivateDataAccessInput {
+                    agent_to_grant: agent_to_grant.clone(),
+                    fields_allowed: vec![
+                        "email".to_string(),
+                        "phone".to_string(),
+                        "location".to_string(),
+                        "time_zone".to_string(),
+                    ],
+                    context: "role_promotion".to_string(),
+                    expires_in_days: Some(7),
+                },
+            )
+            .await;
+    }
+
+    let request_hash: ActionHash = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "request_role_promotion",
+            RolePromotionRequestInput {
+                target_role: "Tool Librarian".to_string(),
+                justification: "Runs the Saturday tool library".to_string(),
+            },
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let inbox: Vec<PromotionRequestRecord> = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_pending_promotion_requests",
+            (),
+        )
+        .await;
+    assert_eq!(inbox.len(), 1);
+    assert_eq!(inbox[0].action_hash, request_hash);
+
+    let assigned: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "approve_role_promotion",
+            ApprovePromotionInput {
+                request_hash,
+                approval_notes: Some("Welcome aboard".to_string()),
+            },
+        )
+        .await;
+    let assigned: PersonRole = decode_record_entry(&assigned);
+    assert_eq!(assigned.role_name, "Tool Librarian");
+    assert_eq!(assigned.assigned_to, *bob.agent_pubkey());
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let level: String = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_person_capability_level",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(level, "stewardship");
+    let mine: Vec<PromotionRequestRecord> = conductors[1]
+        .call(&bob.zome("zome_person"), "get_my_promotion_requests", ())
+        .await;
+    assert_eq!(mine[0].request.status, PromotionRequestStatus::Approved);
+}
+
+/// On a network with a progenitor, integrity accepts a role assignment only from the
+/// progenitor or from an agent holding a role at the `assignable_by` level of the
+/// assigned role; nobody else can promote themselves.
+#[tokio::test(flavor = "multi_thread")]
+async fn role_assignments_are_authorized_by_the_assigners_roles() {
+    let (conductors, alice, bob) = setup_two_agents_with_progenitor().await;
+    let person = |name: &str| PersonInput {
+        name: name.to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: None,
+    };
+    let _: Record = conductors[0]
+        .call(&alice.zome("zome_person"), "create_person", person("Alice"))
+        .await;
+    let _: Record = conductors[1]
+        .call(&bob.zome("zome_person"), "create_person", person("Bob"))
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let role = |agent_pubkey: &AgentPubKey, role_name: &str| PersonRoleInput {
+        agent_pubkey: agent_pubkey.clone(),
+        role_name: role_name.to_string(),
+        description: None,
+        expires_at: None,
+    };
+    let self_promotion: Result<Record, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_person"),
+            "assign_person_role",
+            role(bob.agent_pubkey(), "Primary Accountable Agent"),
+        )
+        .await;
+    assert!(self_promotion.is_err(), "agents cannot promote themselves");
+    let without_authority: Result<Record, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_person"),
+            "assign_person_role",
+            role(alice.agent_pubkey(), "Accountable Agent"),
+        )
+        .await;
+    assert!(
+        without_authority.is_err(),
+        "Accountable Agent is assigned by coordination level agents"
+    );
+    let _: Record = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "assign_person_role",
+            role(bob.agent_pubkey(), "Simple Agent"),
+        )
+        .await;
+
+    // The progenitor needs no role of its own
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            role(bob.agent_pubkey(), "Accountable Agent"),
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let record: Record = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "assign_person_role",
+            role(alice.agent_pubkey(), "Accountable Agent"),
+        )
+        .await;
+    let assigned: PersonRole = decode_record_entry(&record);
+    assert!(
+        assigned.assigner_role.is_some(),
+        "the assignment names Bob's own Accountable Agent role"
+    );
+    let above_authority: Result<Record, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_person"),
+            "assign_person_role",
+            role(alice.agent_pubkey(), "Primary Accountable Agent"),
+        )
+        .await;
+    assert!(
+        above_authority.is_err(),
+        "coordination level agents cannot assign governance roles"
+    );
+}
+
+/// The default manifest has no progenitor, so role authority fails closed: agents may
+/// take member level roles themselves, and nothing else.
+#[tokio::test(flavor = "multi_thread")]
+async fn roles_fail_closed_without_a_progenitor() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+    let person = |name: &str| PersonInput {
+        name: name.to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: None,
+    };
+    let _: Record = conductors[0]
+        .call(&alice.zome("zome_person"), "create_person", person("Alice"))
+        .await;
+    let _: Record = conductors[1]
+        .call(&bob.zome("zome_person"), "create_person", person("Bob"))
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let role = |agent_pubkey: &AgentPubKey, role_name: &str| PersonRoleInput {
+        agent_pubkey: agent_pubkey.clone(),
+        role_name: role_name.to_string(),
+        description: None,
+        expires_at: None,
+    };
+    let record: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            role(alice.agent_pubkey(), "Simple Agent"),
+        )
+        .await;
+    for role_name in ["Accountable Agent", "Primary Accountable Agent"] {
+        let self_promotion: Result<Record, _> = conductors[0]
+            .call_fallible(
+                &alice.zome("zome_person"),
+                "assign_person_role",
+                role(alice.agent_pubkey(), role_name),
+            )
+            .await;
+        assert!(
+            self_promotion.is_err(),
+            "{} cannot be taken without a progenitor",
+            role_name
+        );
+    }
+    let for_other: Result<Record, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            role(bob.agent_pubkey(), "Simple Agent"),
+        )
+        .await;
+    assert!(
+        for_other.is_err(),
+        "nobody assigns roles to others without a progenitor"
+    );
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let suspension: Result<Record, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_person"),
+            "suspend_person_role",
+            RoleRevocationInput {
+                person_role: record.action_address().clone(),
+                reason: "Not Bob's call".to_string(),
+            },
+        )
+        .await;
+    assert!(
+        suspension.is_err(),
+        "only holders end their roles without a progenitor"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn suspended_revoked_and_expired_roles_grant_no_capability() {
+    let (conductors, alice, bob) = setup_two_agents_with_progenitor().await;
+    let person = |name: &str| PersonInput {
+        name: name.to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: None,
+    };
+    let _: Record = conductors[0]
+        .call(&alice.zome("zome_person"), "create_person", person("Alice"))
+        .await;
+    let _: Record = conductors[1]
+        .call(&bob.zome("zome_person"), "create_person", person("Bob"))
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let record: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            PersonRoleInput {
+                agent_pubkey: bob.agent_pubkey().clone(),
+                role_name: "Accountable Agent".to_string(),
+                description: None,
+                expires_at: None,
+            },
+        )
+        .await;
+    let role_hash = record.action_address().clone();
+    let revocation = |reason: &str| RoleRevocationInput {
+        person_role: role_hash.clone(),
+        reason: reason.to_string(),
+    };
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let level: String = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_person_capability_level",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(level, "coordination");
+
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "suspend_person_role",
+            revocation("Under review"),
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let status: RoleStatus = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_person_role_status",
+            role_hash.clone(),
+        )
+        .await;
+    assert_eq!(status, RoleStatus::Suspended);
+    let level: String = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_person_capability_level",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(level, "member", "a suspended role grants no capability");
+    let holds_role: bool = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "has_person_role_capability",
+            (bob.agent_pubkey().clone(), "Accountable Agent".to_string()),
+        )
+        .await;
+    assert!(!holds_role);
+    let self_reinstated: Result<Record, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_person"),
+            "reinstate_person_role",
+            revocation("Done"),
+        )
+        .await;
+    assert!(
+        self_reinstated.is_err(),
+        "agents cannot lift their own suspension"
+    );
+
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "reinstate_person_role",
+            revocation("Review passed"),
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+    let level: String = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_person_capability_level",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(level, "coordination");
+
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "revoke_person_role",
+            revocation("Left the community"),
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let status: RoleStatus = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_person_role_status",
+            role_hash.clone(),
+        )
+        .await;
+    assert_eq!(status, RoleStatus::Revoked);
+    let reinstated: Result<Record, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_person"),
+            "reinstate_person_role",
+            revocation("Came back"),
+        )
+        .await;
+    assert!(reinstated.is_err(), "revocation is final");
+    let history: Vec<RoleRevocation> = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_role_revocations",
+            role_hash.clone(),
+        )
+        .await;
+    assert!(history
+        .iter()
+        .all(|r| &r.revoked_by == alice.agent_pubkey()));
+    let kinds: Vec<RoleRevocationKind> = history.into_iter().map(|r| r.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            RoleRevocationKind::Suspend,
+            RoleRevocationKind::Reinstate,
+            RoleRevocationKind::Revoke
+        ]
+    );
+    let all_roles: GetPersonRolesOutput = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_person_roles",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(
+        all_roles.roles.len(),
+        1,
+        "revoked roles stay in the history"
+    );
+    let active_roles: GetPersonRolesOutput = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_active_person_roles",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    assert!(active_roles.roles.is_empty());
+
+    // A time-boxed role expires on its own
+    let term_end = Timestamp::from_micros(Timestamp::now().as_micros() + 2_000_000);
+    let record: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            PersonRoleInput {
+                agent_pubkey: bob.agent_pubkey().clone(),
+                role_name: "Repair Agent".to_string(),
+                description: None,
+                expires_at: Some(term_end),
+            },
+        )
+        .await;
+    tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+    let status: RoleStatus = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_person_role_status",
+            record.action_address().clone(),
+        )
+        .await;
+    assert_eq!(status, RoleStatus::Expired);
+}
+
+/// A revocation only counts for the role it names and only when linked by its revoker:
+/// Bob's valid revocation of his own role, linked from Alice's role by writing the link
+/// straight to his chain, neither passes validation nor ends Alice's role.
+#[tokio::test(flavor = "multi_thread")]
+async fn revocations_cannot_be_linked_from_another_role() {
+    let (conductors, alice, bob) = setup_two_agents_with_progenitor().await;
+    let person = |name: &str| PersonInput {
+        name: name.to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: None,
+    };
+    let _: Record = conductors[0]
+        .call(&alice.zome("zome_person"), "create_person", person("Alice"))
+        .await;
+    let _: Record = conductors[1]
+        .call(&bob.zome("zome_person"), "create_person", person("Bob"))
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let assign = |agent: &AgentPubKey| PersonRoleInput {
+        agent_pubkey: agent.clone(),
+        role_name: "Accountable Agent".to_string(),
+        description: None,
+        expires_at: None,
+    };
+    let alice_role: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            assign(alice.agent_pubkey()),
+        )
+        .await;
+    let bob_role: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            assign(bob.agent_pubkey()),
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let revocation: Record = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "revoke_person_role",
+            RoleRevocationInput {
+                person_role: bob_role.action_address().clone(),
+                reason: "Stepping down".to_string(),
+            },
+        )
+        .await;
+
+    // RoleRevocations is link type 9 of zome_person_integrity, the DNA's first integrity zome
+    let dna_file = conductors[1]
+        .get_dna_file(bob.cell_id().dna_hash())
+        .expect("bob's cell has a DNA");
+    let bob_host =
+        HostFnCaller::create(bob.cell_id(), &conductors[1].raw_handle(), &dna_file).await;
+    let _ = bob_host
+        .create_link(
+            alice_role.action_address().clone().into(),
+            revocation.action_address().clone().into(),
+            ZomeIndex(0),
+            LinkType(9),
+            LinkTag::new(vec![]),
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let status: RoleStatus = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_person_role_status",
+            alice_role.action_address().clone(),
+        )
+        .await;
+    assert_eq!(status, RoleStatus::Active, "a forged revocation link is ignored");
+    let history: Vec<RoleRevocation> = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_role_revocations",
+            alice_role.action_address().clone(),
+        )
+        .await;
+    assert!(history.is_empty());
+    let level: String = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_person_capability_level",
+            alice.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(level, "coordination");
+}
+
+/// Non-individual agents are bridged to hREA as organisations or classified persons:
+///   create_person(Collective) → ReaAgent.agent_type == "Organization"
+///   → ReaAgent.classified_as == ["collective"]
+#[tokio::test(flavor = "multi_thread")]
+async fn collectives_are_bridged_to_hrea_as_organizations() {
+    let (conductors, nd_alice, _hrea_alice, _nd_bob, _hrea_bob) =
+        setup_dual_dna_two_agents().await;
+
+    let input = PersonInput {
+        name: "Fab Lab Collective".to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: Some(AgentEntityType::Collective("Fab Lab".to_string())),
+    };
+    let person_record: Record = conductors[0]
+        .call(&nd_alice.zome("zome_person"), "create_person", input)
+        .await;
+    let person: PersonOutput = decode_record_entry(&person_record);
+    let hrea_hash = person
+        .hrea_agent_hash
+        .expect("hrea_agent_hash must be set to test cross-DNA read");
+
+    let agents: Vec<Option<Record>> = conductors[0]
+        .call(
+            &nd_alice.zome("zome_person"),
+            "get_hrea_agents",
+            vec![hrea_hash],
+        )
+        .await;
+    let agent_record = agents
+        .into_iter()
+        .next()
+        .flatten()
+        .expect("agent record should not be None");
+    let rea_agent: ReaAgent = decode_record_entry(&agent_record);
+    assert_eq!(rea_agent.agent_type, "Organization");
+    assert_eq!(
+        rea_agent.classified_as,
+        Some(vec!["collective".to_string()])
+    );
+
+    let agent_type: AgentEntityType = conductors[0]
+        .call(
+            &nd_alice.zome("zome_person"),
+            "get_agent_entity_type",
+            nd_alice.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(
+        agent_type,
+        AgentEntityType::Collective("Fab Lab".to_string())
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bots_need_an_accountable_operator() {
+    let (conductors, alice, bob) = setup_two_agents_with_progenitor().await;
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "create_person",
+            PersonInput {
+                name: "Alice".to_string(),
+                avatar_url: None,
+                bio: None,
+                agent_type: None,
+            },
+        )
+        .await;
+    let scanner = || PersonInput {
+        name: "Inventory Scanner".to_string(),
+        avatar_url: None,
+        bio: None,
+        agent_type: Some(AgentEntityType::Bot {
+            capabilities: vec!["inventory_scan".to_string()],
+            operator: alice.agent_pubkey().clone(),
+            operator_role: None,
+            operator_consent: None,
+        }),
+    };
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let unaccountable_operator: Result<Record, _> = conductors[1]
+        .call_fallible(&bob.zome("zome_person"), "create_person", scanner())
+        .await;
+    assert!(
+        unaccountable_operator.is_err(),
+        "a bot's operator must be an Accountable Agent"
+    );
+
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "assign_person_role",
+            PersonRoleInput {
+                agent_pubkey: alice.agent_pubkey().clone(),
+                role_name: "Accountable Agent".to_string(),
+                description: None,
+                expires_at: None,
+            },
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let without_consent: Result<Record, _> = conductors[1]
+        .call_fallible(&bob.zome("zome_person"), "create_person", scanner())
+        .await;
+    assert!(
+        without_consent.is_err(),
+        "the operator must consent to operate the bot"
+    );
+
+    let consent_hash: ActionHash = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "consent_to_operate_bot",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let _: Record = conductors[1]
+        .call(&bob.zome("zome_person"), "create_person", scanner())
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let bot_type: AgentEntityType = conductors[0]
+        .call(
+            &alice.zome("zome_person"),
+            "get_agent_entity_type",
+            bob.agent_pubkey().clone(),
+        )
+        .await;
+    match bot_type {
+        AgentEntityType::Bot {
+            operator,
+            operator_role,
+            operator_consent,
+            ..
+        } => {
+            assert_eq!(&operator, alice.agent_pubkey());
+            assert!(operator_role.is_some(), "the operator's role is recorded");
+            assert_eq!(operator_consent, Some(consent_hash));
+        }
+        other => panic!("expected a bot, got {:?}", other),
+    }
+    let alice_type: AgentEntityType = conductors[1]
+        .call(
+            &bob.zome("zome_person"),
+            "get_agent_entity_type",
+            alice.agent_pubkey().clone(),
+        )
+        .await;
+    assert_eq!(alice_type, AgentEntityType::Individual);
+}
@@ -2,7 +2,9 @@
 //!
 //! Covers `get_all_resource_specifications` — specifically that the new
 //! `action_hashes` field is returned in parallel with `specifications` and
-//! that both vectors have the same length and order.
+//! that both vectors have the same length and order — and the EconomicResource
+//! OperationalState transition graph (REQ-NDO-OS-06), custody transfer, and
+//! governance rule enforcement on resource state changes.
 //!
 //! Prerequisites (runtime — not compile-time):
 //!   bun run build:happ   # builds nondominium.dna
@@ -11,8 +13,10 @@
 //!   CARGO_TARGET_DIR=target/native-tests cargo test --test resource
 
 use holochain::prelude::*;
+use holochain::sweettest::*;
 use serde::{Deserialize, Serialize};
 
+use nondominium_shared::io::{Page, PageRequest};
 use nondominium_sweettest::common::*;
 
 // ---------------------------------------------------------------------------
@@ -35,6 +39,13 @@ struct ResourceSpecificationInput {
     pub image_url: Option<String>,
     pub tags: Vec<String>,
     pub governance_rules: Vec<GovernanceRuleInput>,
+    pub validation: Option<ResourceValidationConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ResourceValidationConfig {
+    pub validation_scheme: String,
+    pub required_validators: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -60,6 +71,133 @@ struct GetAllResourceSpecificationsOutput {
     pub action_hashes: Vec<ActionHash>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum OperationalState {
+    Available,
+    Reserved,
+    InTransit,
+    InStorage,
+    InMaintenance,
+    InUse,
+    PendingValidation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum LifecycleStage {
+    Ideation,
+    Specification,
+    Development,
+    Prototype,
+    Stable,
+    Distributed,
+    Active,
+    Hibernating,
+    Deprecated,
+    EndOfLife,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Location {
+    Named(String),
+    Geo(GeoPoint),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct GeoPoint {
+    pub lat: f64,
+    pub long: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EconomicResourceInput {
+    pub spec_hash: ActionHash,
+    pub quantity: f64,
+    pub unit: String,
+    pub current_location: Option<Location>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateEconomicResourceInput {
+    pub original_action_hash: ActionHash,
+    pub previous_action_hash: ActionHash,
+    pub updated_resource: EconomicResourceInput,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ResourcesNearInput {
+    pub lat: f64,
+    pub long: f64,
+    pub radius_km: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NearbyResource {
+    pub record: Record,
+    pub distance_km: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EconomicResource {
+    pub accounting_quantity: f64,
+    pub onhand_quantity: f64,
+    pub unit: String,
+    pub custodian: AgentPubKey,
+    pub current_location: Option<Location>,
+    pub state: OperationalState,
+    pub lifecycle_stage: LifecycleStage,
+    pub validated_by: Option<ActionHash>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EconomicResourceProfileOutput {
+    pub resource: Option<EconomicResource>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateEconomicResourceOutput {
+    pub resource_hash: ActionHash,
+    pub resource: EconomicResource,
+    pub validation_hash: ActionHash,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateResourceStateInput {
+    pub resource_hash: ActionHash,
+    pub new_state: OperationalState,
+    pub duration_hours: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProposeCustodyTransferInput {
+    pub resource_hash: ActionHash,
+    pub new_custodian: AgentPubKey,
+    pub note: Option<String>,
+    pub request_contact_info: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CustodyTransferProposal {
+    pub resource_hash: ActionHash,
+    pub current_custodian: AgentPubKey,
+    pub proposed_custodian: AgentPubKey,
+    pub note: Option<String>,
+    pub proposed_at: Timestamp,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CustodyTransferProposalRecord {
+    pub proposal_hash: ActionHash,
+    pub proposal: CustodyTransferProposal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AcceptCustodyTransferOutput {
+    pub updated_resource_hash: ActionHash,
+    pub updated_resource: EconomicResource,
+    pub acceptance_hash: ActionHash,
+    pub event_hash: ActionHash,
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -77,6 +215,7 @@ async fn get_all_resource_specifications_returns_parallel_hashes() {
         image_url: None,
         tags: vec!["transport".to_string()],
         governance_rules: vec![],
+        validation: None,
     };
 
     let spec2 = ResourceSpecificationInput {
@@ -86,6 +225,7 @@ async fn get_all_resource_specifications_returns_parallel_hashes() {
         image_url: None,
         tags: vec!["safety".to_string()],
         governance_rules: vec![],
+        validation: None,
     };
 
     // Create both specs — discard the output, we only care about get_all below
@@ -135,3 +275,1544 @@ async fn get_all_resource_specifications_returns_parallel_hashes() {
         );
     }
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ResourceSpecificationListing {
+    pub action_hash: ActionHash,
+    pub specification: ResourceSpecification,
+}
+
+/// Paginated listings walk the anchor links in a stable order: consecutive pages
+/// neither repeat nor skip specifications, and the last page has no cursor.
+#[tokio::test(flavor = "multi_thread")]
+async fn resource_specifications_are_listed_page_by_page() {
+    let (conductors, alice, _bob) = setup_two_agents().await;
+
+    let mut created = Vec::new();
+    for name in ["Spade", "Rake", "Hoe", "Trowel", "Shears"] {
+        let spec: CreateResourceSpecificationOutput = conductors[0]
+            .call(
+                &alice.zome("zome_resource"),
+                "create_resource_specification",
+                ResourceSpecificationInput {
+                    name: name.to_string(),
+                    description: format!("Garden {}", name.to_lowercase()),
+                    category: "Garden".to_string(),
+                    image_url: None,
+                    tags: vec![],
+                    governance_rules: vec![],
+                    validation: None,
+                },
+            )
+            .await;
+        created.push(spec.spec_hash);
+    }
+
+    let mut listed = Vec::new();
+    let mut request = PageRequest { cursor: None, limit: 2 };
+    loop {
+        let page: Page<ResourceSpecificationListing> = conductors[0]
+            .call(
+                &alice.zome("zome_resource"),
+                "get_all_resource_specifications_paginated",
+                request.clone(),
+            )
+            .await;
+        assert!(page.items.len() <= 2);
+        listed.extend(page.items.into_iter().map(|item| item.action_hash));
+        match page.next_cursor {
+            Some(cursor) => request.cursor = Some(cursor),
+            None => break,
+        }
+    }
+    assert_eq!(listed, created, "pages follow creation order without gaps or repeats");
+
+    let everything: Page<ResourceSpecificationListing> = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "get_all_resource_specifications_paginated",
+            PageRequest::default(),
+        )
+        .await;
+    assert_eq!(everything.items.len(), 5, "a zero limit uses the default page size");
+    assert!(everything.next_cursor.is_none());
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SearchResourceSpecificationsInput {
+    pub text: Option<String>,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ResourceSpecificationMatch {
+    pub action_hash: ActionHash,
+    pub specification: ResourceSpecification,
+    pub score: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateResourceSpecificationInput {
+    pub original_action_hash: ActionHash,
+    pub previous_action_hash: ActionHash,
+    pub updated_specification: ResourceSpecificationInput,
+}
+
+fn spec_input(name: &str, category: &str, tags: &[&str]) -> ResourceSpecificationInput {
+    ResourceSpecificationInput {
+        name: name.to_string(),
+        description: format!("{} for the shared workshop", name),
+        category: category.to_string(),
+        image_url: None,
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        governance_rules: vec![],
+        validation: None,
+    }
+}
+
+/// Search intersects its criteria and ranks by name relevance; name words are
+/// found by prefix, and an update moves the spec to the anchors of its new name.
+#[tokio::test(flavor = "multi_thread")]
+async fn resource_specifications_are_searched_by_facets_and_name() {
+    let (conductors, alice, _bob) = setup_two_agents().await;
+
+    let mut specs = Vec::new();
+    for (name, category, tags) in [
+        ("Cordless Drill", "Tools", &["power", "portable"][..]),
+        ("Drill Press", "Tools", &["power"][..]),
+        ("Hand Drill", "Tools", &[][..]),
+        ("Drip Irrigation Kit", "Garden", &[][..]),
+    ] {
+        let spec: CreateResourceSpecificationOutput = conductors[0]
+            .call(
+                &alice.zome("zome_resource"),
+                "create_resource_specification",
+                spec_input(name, category, tags),
+            )
+            .await;
+        specs.push(spec.spec_hash);
+    }
+
+    let search = |input: SearchResourceSpecificationsInput| {
+        let conductor = &conductors[0];
+        let zome = alice.zome("zome_resource");
+        async move {
+            let found: Vec<ResourceSpecificationMatch> = conductor
+                .call(&zome, "search_resource_specifications", input)
+                .await;
+            found
+                .into_iter()
+                .map(|found| found.specification.name)
+                .collect::<Vec<_>>()
+        }
+    };
+    let text = |text: &str| Some(text.to_string());
+
+    let drill = search(SearchResourceSpecificationsInput {
+        text: text("drill"),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(drill, ["Drill Press", "Cordless Drill", "Hand Drill"]);
+
+    let dri = search(SearchResourceSpecificationsInput {
+        text: text("DRI"),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(
+        dri,
+        ["Drill Press", "Drip Irrigation Kit", "Cordless Drill", "Hand Drill"],
+        "names starting with the word rank first"
+    );
+
+    let powered = search(SearchResourceSpecificationsInput {
+        text: text("drill"),
+        category: Some("Tools".to_string()),
+        tags: vec!["power".to_string(), "portable".to_string()],
+        is_active: Some(true),
+    })
+    .await;
+    assert_eq!(powered, ["Cordless Drill"]);
+
+    let garden = search(SearchResourceSpecificationsInput {
+        text: text("dri"),
+        category: Some("Garden".to_string()),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(garden, ["Drip Irrigation Kit"]);
+
+    let inactive = search(SearchResourceSpecificationsInput {
+        is_active: Some(false),
+        ..Default::default()
+    })
+    .await;
+    assert!(inactive.is_empty());
+
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "update_resource_specification",
+            UpdateResourceSpecificationInput {
+                original_action_hash: specs[2].clone(),
+                previous_action_hash: specs[2].clone(),
+                updated_specification: spec_input("Hand Auger", "Tools", &[]),
+            },
+        )
+        .await;
+
+    let drill = search(SearchResourceSpecificationsInput {
+        text: text("drill"),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(drill, ["Drill Press", "Cordless Drill"]);
+
+    let found: Vec<ResourceSpecificationMatch> = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "search_resource_specifications",
+            SearchResourceSpecificationsInput {
+                text: text("auger"),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].action_hash, specs[2], "results carry the original hash");
+    assert_eq!(found[0].specification.name, "Hand Auger");
+}
+
+#[test]
+fn names_are_indexed_by_word_prefix() {
+    use nondominium_shared::search::{index_prefixes, match_score, tokenize};
+
+    assert_eq!(tokenize("Drill-press, drill!"), ["drill", "press"]);
+    assert_eq!(index_prefixes("Big Hammer"), ["bi", "big", "ha", "ham", "hamm", "hamme", "hammer"]);
+    assert_eq!(index_prefixes("Electrocardiograph")[8], "electrocar", "prefixes are capped");
+
+    let query = tokenize("drill");
+    assert_eq!(match_score(&query, "Drill Press"), 4);
+    assert_eq!(match_score(&query, "Cordless Drill"), 3);
+    assert_eq!(match_score(&tokenize("dri"), "Cordless Drill"), 1);
+    assert_eq!(match_score(&tokenize("drill saw"), "Cordless Drill"), 0, "every word must match");
+}
+
+/// OperationalState changes follow the transition graph: a freshly created
+/// resource cannot jump straight into use, must pass through Available, and
+/// is discoverable under the anchor of its current state (REQ-NDO-OS-06).
+#[tokio::test(flavor = "multi_thread")]
+async fn economic_resource_operational_state_transitions() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Drill Press".to_string(),
+                description: "Bench-mounted drill press".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+                validation: None,
+            },
+        )
+        .await;
+
+    let created: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 1.0,
+                unit: "unit".to_string(),
+                current_location: None,
+            },
+        )
+        .await;
+    assert_eq!(created.resource.state, OperationalState::PendingValidation);
+    assert_eq!(created.resource.lifecycle_stage, LifecycleStage::Prototype);
+
+    // PendingValidation → InUse skips validation and must be rejected
+    let skip_result: Result<Record, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "update_resource_state",
+            UpdateResourceStateInput {
+                resource_hash: created.resource_hash.clone(),
+                new_state: OperationalState::InUse,
+                duration_hours: None,
+            },
+        )
+        .await;
+    assert!(
+        skip_result.is_err(),
+        "PendingValidation → InUse must be rejected (REQ-NDO-OS-06)"
+    );
+
+    // The custodian cannot declare their own resource validated
+    let self_promotion: Result<Record, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "update_resource_state",
+            UpdateResourceStateInput {
+                resource_hash: created.resource_hash.clone(),
+                new_state: OperationalState::Available,
+                duration_hours: None,
+            },
+        )
+        .await;
+    assert!(
+        self_promotion.is_err(),
+        "PendingValidation → Available must go through an approved validation"
+    );
+
+    // An approved validation is the only exit from PendingValidation
+    let available = approve_resource(
+        &conductors[1],
+        &bob,
+        &conductors[0],
+        &alice,
+        &created.resource_hash,
+    )
+    .await;
+    let resource: EconomicResource = available
+        .entry()
+        .to_app_option()
+        .unwrap()
+        .expect("promoted resource entry");
+    assert_eq!(resource.state, OperationalState::Available);
+    assert!(
+        resource.validated_by.is_some(),
+        "the promotion cites the approved ResourceValidation"
+    );
+
+    let available: Vec<Record> = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "get_resources_by_operational_state",
+            OperationalState::Available,
+        )
+        .await;
+    assert_eq!(available.len(), 1, "resource must be listed under Available");
+
+    let pending: Vec<Record> = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "get_resources_by_operational_state",
+            OperationalState::PendingValidation,
+        )
+        .await;
+    assert!(
+        pending.is_empty(),
+        "PendingValidation anchor link must be moved on transition"
+    );
+}
+
+/// Custody only changes hands once the incoming custodian accepts: a proposal
+/// leaves the resource untouched, a version has one open proposal at a time, only the
+/// proposed custodian can accept, and acceptance moves custody, with the onhand quantity,
+/// and logs a TransferCustody event.
+#[tokio::test(flavor = "multi_thread")]
+async fn economic_resource_custody_transfer_requires_acceptance() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Ladder".to_string(),
+                description: "Aluminium step ladder".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+                validation: None,
+            },
+        )
+        .await;
+
+    let created: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash.clone(),
+                quantity: 1.0,
+                unit: "unit".to_string(),
+                current_location: None,
+            },
+        )
+        .await;
+    assert_eq!(created.resource.accounting_quantity, 1.0);
+    assert_eq!(created.resource.onhand_quantity, 1.0);
+
+    // A resource pending validation cannot be offered
+    let pending_proposal: Result<CustodyTransferProposalRecord, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "propose_custody_transfer",
+            ProposeCustodyTransferInput {
+                resource_hash: created.resource_hash.clone(),
+                new_custodian: bob.agent_pubkey().clone(),
+                note: None,
+                request_contact_info: Some(false),
+            },
+        )
+        .await;
+    assert!(
+        pending_proposal.is_err(),
+        "a PendingValidation resource must not be offered for transfer"
+    );
+
+    let available = approve_resource(
+        &conductors[1],
+        &bob,
+        &conductors[0],
+        &alice,
+        &created.resource_hash,
+    )
+    .await;
+
+    let proposal: CustodyTransferProposalRecord = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "propose_custody_transfer",
+            ProposeCustodyTransferInput {
+                resource_hash: available.action_address().clone(),
+                new_custodian: bob.agent_pubkey().clone(),
+                note: Some("Lending for the weekend".to_string()),
+                request_contact_info: Some(false),
+            },
+        )
+        .await;
+
+    // The proposer cannot accept on the recipient's behalf
+    let self_accept: Result<AcceptCustodyTransferOutput, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "accept_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    assert!(self_accept.is_err(), "only the proposed custodian may accept");
+
+    // A version is offered to one agent at a time, so it can be accepted only once
+    let second_proposal: Result<CustodyTransferProposalRecord, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "propose_custody_transfer",
+            ProposeCustodyTransferInput {
+                resource_hash: available.action_address().clone(),
+                new_custodian: bob.agent_pubkey().clone(),
+                note: None,
+                request_contact_info: Some(false),
+            },
+        )
+        .await;
+    assert!(second_proposal.is_err(), "an offered version cannot be offered again");
+
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+
+    let incoming: Vec<CustodyTransferProposalRecord> = conductors[1]
+        .call(&bob.zome("zome_resource"), "get_incoming_custody_proposals", ())
+        .await;
+    assert_eq!(incoming.len(), 1, "bob must see the pending proposal");
+
+    let accepted: AcceptCustodyTransferOutput = conductors[1]
+        .call(
+            &bob.zome("zome_resource"),
+            "accept_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    assert_eq!(&accepted.updated_resource.custodian, bob.agent_pubkey());
+    assert_eq!(accepted.updated_resource.state, OperationalState::Available);
+    assert_eq!(accepted.updated_resource.onhand_quantity, 1.0);
+
+    let profile: EconomicResourceProfileOutput = conductors[1]
+        .call(
+            &bob.zome("zome_resource"),
+            "get_economic_resource_profile",
+            created.resource_hash.clone(),
+        )
+        .await;
+    let profile = profile.resource.expect("the transferred resource has a profile");
+    assert_eq!(&profile.custodian, bob.agent_pubkey());
+    assert_eq!(profile.accounting_quantity, 1.0);
+    assert_eq!(profile.onhand_quantity, 1.0);
+
+    let incoming_after: Vec<CustodyTransferProposalRecord> = conductors[1]
+        .call(&bob.zome("zome_resource"), "get_incoming_custody_proposals", ())
+        .await;
+    assert!(incoming_after.is_empty(), "accepted proposal is no longer pending");
+
+    // The previous custodian can no longer write from the version they handed over
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+    let stale_update: Result<Record, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "update_economic_resource",
+            UpdateEconomicResourceInput {
+                original_action_hash: created.resource_hash.clone(),
+                previous_action_hash: available.action_address().clone(),
+                updated_resource: EconomicResourceInput {
+                    spec_hash: spec.spec_hash.clone(),
+                    quantity: 1.0,
+                    unit: "unit".to_string(),
+                    current_location: None,
+                },
+            },
+        )
+        .await;
+    assert!(stale_update.is_err(), "a superseded version cannot be updated");
+}
+
+/// Number of proposals pending in bob's incoming and alice's outgoing queries
+async fn pending_proposals(
+    conductors: &SweetConductorBatch,
+    alice: &SweetCell,
+    bob: &SweetCell,
+) -> (usize, usize) {
+    let incoming: Vec<CustodyTransferProposalRecord> = conductors[1]
+        .call(&bob.zome("zome_resource"), "get_incoming_custody_proposals", ())
+        .await;
+    let outgoing: Vec<CustodyTransferProposalRecord> = conductors[0]
+        .call(&alice.zome("zome_resource"), "get_outgoing_custody_proposals", ())
+        .await;
+    (incoming.len(), outgoing.len())
+}
+
+/// A declined or cancelled proposal leaves the pending queries, and the version it offered
+/// can be updated again; only the recipient may decline and only the proposer may cancel.
+#[tokio::test(flavor = "multi_thread")]
+async fn custody_proposals_can_be_declined_or_cancelled() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Wheelbarrow".to_string(),
+                description: "Single-wheel garden barrow".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+                validation: None,
+            },
+        )
+        .await;
+    let resource_input = |quantity: f64| EconomicResourceInput {
+        spec_hash: spec.spec_hash.clone(),
+        quantity,
+        unit: "unit".to_string(),
+        current_location: None,
+    };
+    let created: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            resource_input(1.0),
+        )
+        .await;
+    let available = approve_resource(
+        &conductors[1],
+        &bob,
+        &conductors[0],
+        &alice,
+        &created.resource_hash,
+    )
+    .await;
+
+    let propose = |version: &ActionHash| ProposeCustodyTransferInput {
+        resource_hash: version.clone(),
+        new_custodian: bob.agent_pubkey().clone(),
+        note: None,
+        request_contact_info: Some(false),
+    };
+    let update = |version: &ActionHash| UpdateEconomicResourceInput {
+        original_action_hash: created.resource_hash.clone(),
+        previous_action_hash: version.clone(),
+        updated_resource: resource_input(1.0),
+    };
+
+    // Declined by the recipient
+    let proposal: CustodyTransferProposalRecord = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "propose_custody_transfer",
+            propose(available.action_address()),
+        )
+        .await;
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+    assert_eq!(pending_proposals(&conductors, &alice, &bob).await, (1, 1));
+
+    let proposer_declines: Result<ActionHash, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "decline_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    assert!(proposer_declines.is_err(), "only the proposed custodian may decline");
+
+    let _: ActionHash = conductors[1]
+        .call(
+            &bob.zome("zome_resource"),
+            "decline_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+    assert_eq!(
+        pending_proposals(&conductors, &alice, &bob).await,
+        (0, 0),
+        "a declined proposal is no longer pending"
+    );
+
+    let accept_declined: Result<AcceptCustodyTransferOutput, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_resource"),
+            "accept_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    assert!(accept_declined.is_err(), "a declined proposal cannot be accepted");
+
+    let recounted: Record = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "update_economic_resource",
+            update(available.action_address()),
+        )
+        .await;
+
+    // Cancelled by the proposer
+    let proposal: CustodyTransferProposalRecord = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "propose_custody_transfer",
+            propose(recounted.action_address()),
+        )
+        .await;
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+    assert_eq!(pending_proposals(&conductors, &alice, &bob).await, (1, 1));
+
+    let recipient_cancels: Result<ActionHash, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_resource"),
+            "cancel_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    assert!(recipient_cancels.is_err(), "only the proposer may cancel");
+
+    let _: ActionHash = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "cancel_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+    assert_eq!(
+        pending_proposals(&conductors, &alice, &bob).await,
+        (0, 0),
+        "a cancelled proposal is no longer pending"
+    );
+
+    let accept_cancelled: Result<AcceptCustodyTransferOutput, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_resource"),
+            "accept_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    assert!(accept_cancelled.is_err(), "a cancelled proposal cannot be accepted");
+
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "update_economic_resource",
+            update(recounted.action_address()),
+        )
+        .await;
+}
+
+/// Rules attached to a ResourceSpecification are evaluated before state changes:
+/// a usage_limit blocks putting too large a quantity in use, and transfer_conditions
+/// block an incoming custodian without the required role.
+#[tokio::test(flavor = "multi_thread")]
+async fn economic_resource_state_changes_respect_governance_rules() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Generator".to_string(),
+                description: "Shared 5kW generator".to_string(),
+                category: "Equipment".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![
+                    GovernanceRuleInput {
+                        rule_type: "usage_limit".to_string(),
+                        rule_data: r#"{"max_quantity": 0.5}"#.to_string(),
+                        enforced_by: None,
+                    },
+                    GovernanceRuleInput {
+                        rule_type: "transfer_conditions".to_string(),
+                        rule_data: r#"{"min_role": "Accountable Agent"}"#.to_string(),
+                        enforced_by: None,
+                    },
+                ],
+                validation: None,
+            },
+        )
+        .await;
+
+    let created: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 1.0,
+                unit: "unit".to_string(),
+                current_location: None,
+            },
+        )
+        .await;
+
+    let available = approve_resource(
+        &conductors[1],
+        &bob,
+        &conductors[0],
+        &alice,
+        &created.resource_hash,
+    )
+    .await;
+
+    let in_use: Result<Record, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "update_resource_state",
+            UpdateResourceStateInput {
+                resource_hash: available.action_address().clone(),
+                new_state: OperationalState::InUse,
+                duration_hours: None,
+            },
+        )
+        .await;
+    assert!(in_use.is_err(), "usage_limit must block using more than 0.5 units");
+
+    let proposal: CustodyTransferProposalRecord = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "propose_custody_transfer",
+            ProposeCustodyTransferInput {
+                resource_hash: available.action_address().clone(),
+                new_custodian: bob.agent_pubkey().clone(),
+                note: None,
+                request_contact_info: Some(false),
+            },
+        )
+        .await;
+
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+
+    let accepted: Result<AcceptCustodyTransferOutput, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_resource"),
+            "accept_custody_transfer",
+            proposal.proposal_hash.clone(),
+        )
+        .await;
+    assert!(
+        accepted.is_err(),
+        "transfer_conditions must block an incoming custodian without Accountable Agent"
+    );
+
+    let incoming: Vec<CustodyTransferProposalRecord> = conductors[1]
+        .call(&bob.zome("zome_resource"), "get_incoming_custody_proposals", ())
+        .await;
+    assert_eq!(incoming.len(), 1, "the refused acceptance leaves the proposal pending");
+}
+
+/// A usage_limit with max_duration_hours bounds how long a resource may be reserved:
+/// an over-long request and a request without a duration are refused.
+#[tokio::test(flavor = "multi_thread")]
+async fn usage_limit_bounds_the_requested_duration() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Cargo Bike".to_string(),
+                description: "Shared cargo bike".to_string(),
+                category: "Transport".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![GovernanceRuleInput {
+                    rule_type: "usage_limit".to_string(),
+                    rule_data: r#"{"max_duration_hours": 4}"#.to_string(),
+                    enforced_by: None,
+                }],
+                validation: None,
+            },
+        )
+        .await;
+
+    let created: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 1.0,
+                unit: "unit".to_string(),
+                current_location: None,
+            },
+        )
+        .await;
+
+    let available = approve_resource(
+        &conductors[1],
+        &bob,
+        &conductors[0],
+        &alice,
+        &created.resource_hash,
+    )
+    .await;
+
+    let reserve = |duration_hours: Option<u64>| UpdateResourceStateInput {
+        resource_hash: available.action_address().clone(),
+        new_state: OperationalState::Reserved,
+        duration_hours,
+    };
+
+    let too_long: Result<Record, _> = conductors[0]
+        .call_fallible(&alice.zome("zome_resource"), "update_resource_state", reserve(Some(8)))
+        .await;
+    assert!(too_long.is_err(), "usage_limit must refuse an 8h reservation over a 4h limit");
+
+    let unbounded: Result<Record, _> = conductors[0]
+        .call_fallible(&alice.zome("zome_resource"), "update_resource_state", reserve(None))
+        .await;
+    assert!(unbounded.is_err(), "usage_limit must refuse a reservation without a duration");
+
+    let reserved: Record = conductors[0]
+        .call(&alice.zome("zome_resource"), "update_resource_state", reserve(Some(2)))
+        .await;
+    let resource: EconomicResource = reserved
+        .entry()
+        .to_app_option()
+        .unwrap()
+        .expect("reserved resource entry");
+    assert_eq!(resource.state, OperationalState::Reserved);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn malformed_governance_rules_are_rejected() {
+    let (conductors, alice, _bob) = setup_two_agents().await;
+
+    let malformed = [
+        ("usage_limit", r#"{"max_quantity": -1.0}"#, None),
+        ("usage_limit", r#"{}"#, None),
+        ("access_requirement", r#"{"min_role": "Benevolent Dictator"}"#, None),
+        ("transfer_conditions", r#"{"required_validators": "two"}"#, None),
+        (
+            "access_requirement",
+            r#"{"min_role": "Simple Agent"}"#,
+            Some("Unknown Role".to_string()),
+        ),
+        ("community_experiment", "not json", None),
+    ];
+    for (rule_type, rule_data, enforced_by) in malformed {
+        let result: Result<Record, _> = conductors[0]
+            .call_fallible(
+                &alice.zome("zome_resource"),
+                "create_governance_rule",
+                GovernanceRuleInput {
+                    rule_type: rule_type.to_string(),
+                    rule_data: rule_data.to_string(),
+                    enforced_by,
+                },
+            )
+            .await;
+        assert!(result.is_err(), "{} rule {} must be rejected", rule_type, rule_data);
+    }
+
+    // Custom rules are accepted as long as they carry JSON
+    let _custom: Record = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_governance_rule",
+            GovernanceRuleInput {
+                rule_type: "community_experiment".to_string(),
+                rule_data: r#"{"quiet_hours": [22, 7]}"#.to_string(),
+                enforced_by: Some("Accountable Agent".to_string()),
+            },
+        )
+        .await;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateValidationReceiptInput {
+    pub validated_item: ActionHash,
+    pub validation_type: String,
+    pub approved: bool,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateValidationReceiptOutput {
+    pub receipt_hash: ActionHash,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum ResourceValidationStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ResourceValidation {
+    pub resource: ActionHash,
+    pub validation_scheme: String,
+    pub required_validators: u32,
+    pub status: ResourceValidationStatus,
+    pub receipts: Vec<ActionHash>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct ValidationTally {
+    pub approvals: u32,
+    pub rejections: u32,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn economic_resource_is_promoted_once_validated() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Drill press".to_string(),
+                description: "Bench drill press".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+                validation: Some(ResourceValidationConfig {
+                    validation_scheme: "simple_approval".to_string(),
+                    required_validators: 1,
+                }),
+            },
+        )
+        .await;
+
+    let created: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 1.0,
+                unit: "unit".to_string(),
+                current_location: None,
+            },
+        )
+        .await;
+    assert_eq!(created.resource.state, OperationalState::PendingValidation);
+
+    let pending: Option<ResourceValidation> = conductors[0]
+        .call(
+            &alice.zome("zome_gouvernance"),
+            "check_validation_status",
+            created.resource_hash.clone(),
+        )
+        .await;
+    let pending = pending.expect("create_economic_resource must open a ResourceValidation");
+    assert_eq!(pending.validation_scheme, "simple_approval");
+    assert_eq!(pending.status, ResourceValidationStatus::Pending);
+
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+
+    // A raw resource_approval receipt is a vote, under the same rules
+    let approval_receipt = || CreateValidationReceiptInput {
+        validated_item: created.resource_hash.clone(),
+        validation_type: "resource_approval".to_string(),
+        approved: true,
+        notes: None,
+    };
+    let own_receipt: Result<CreateValidationReceiptOutput, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_gouvernance"),
+            "create_validation_receipt",
+            approval_receipt(),
+        )
+        .await;
+    assert!(own_receipt.is_err(), "the creator cannot approve their own resource");
+
+    let _vote: CastValidationVoteOutput = conductors[1]
+        .call(
+            &bob.zome("zome_gouvernance"),
+            "cast_validation_vote",
+            CastValidationVoteInput {
+                resource_hash: created.resource_hash.clone(),
+                approved: true,
+                notes: None,
+            },
+        )
+        .await;
+
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+
+    let validation: Option<ResourceValidation> = conductors[0]
+        .call(
+            &alice.zome("zome_gouvernance"),
+            "check_validation_status",
+            created.resource_hash.clone(),
+        )
+        .await;
+    assert_eq!(validation.unwrap().status, ResourceValidationStatus::Approved);
+
+    let second_receipt: Result<CreateValidationReceiptOutput, _> = conductors[1]
+        .call_fallible(
+            &bob.zome("zome_gouvernance"),
+            "create_validation_receipt",
+            approval_receipt(),
+        )
+        .await;
+    assert!(second_receipt.is_err(), "a validator votes once, on a pending validation");
+
+    let tally: ValidationTally = conductors[0]
+        .call(
+            &alice.zome("zome_gouvernance"),
+            "get_validation_tally",
+            created.resource_hash.clone(),
+        )
+        .await;
+    assert_eq!(tally, ValidationTally { approvals: 1, rejections: 0 });
+
+    let resource: EconomicResource = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "get_latest_economic_resource",
+            created.resource_hash.clone(),
+        )
+        .await;
+    assert_eq!(resource.state, OperationalState::Available);
+    assert_eq!(resource.lifecycle_stage, LifecycleStage::Active);
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CastValidationVoteInput {
+    pub resource_hash: ActionHash,
+    pub approved: bool,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CastValidationVoteOutput {
+    pub receipt_hash: ActionHash,
+    pub status: ResourceValidationStatus,
+    pub tally: ValidationTally,
+}
+
+async fn create_resource_with_scheme(
+    conductor: &SweetConductor,
+    cell: &SweetCell,
+    scheme: &str,
+    panel_size: u32,
+) -> CreateEconomicResourceOutput {
+    let spec: CreateResourceSpecificationOutput = conductor
+        .call(
+            &cell.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: format!("Tool validated by {}", scheme),
+                description: "Shared workshop tool".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+                validation: Some(ResourceValidationConfig {
+                    validation_scheme: scheme.to_string(),
+                    required_validators: panel_size,
+                }),
+            },
+        )
+        .await;
+
+    conductor
+        .call(
+            &cell.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 1.0,
+                unit: "unit".to_string(),
+                current_location: None,
+            },
+        )
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validation_votes_follow_the_scheme() {
+    let (conductors, alice, bob, carol) = setup_three_agents().await;
+
+    let tool = create_resource_with_scheme(&conductors[0], &alice, "2-of-3", 3).await;
+    let vetoed = create_resource_with_scheme(&conductors[0], &alice, "unanimous", 2).await;
+    await_consistency_20_s([&alice, &bob, &carol]).await.unwrap();
+
+    let vote = |approved: bool| CastValidationVoteInput {
+        resource_hash: tool.resource_hash.clone(),
+        approved,
+        notes: None,
+    };
+
+    // Creators cannot vote on their own resource
+    let own_vote: Result<CastValidationVoteOutput, _> = conductors[0]
+        .call_fallible(&alice.zome("zome_gouvernance"), "cast_validation_vote", vote(true))
+        .await;
+    assert!(own_vote.is_err());
+
+    let first: CastValidationVoteOutput = conductors[1]
+        .call(&bob.zome("zome_gouvernance"), "cast_validation_vote", vote(true))
+        .await;
+    assert_eq!(first.status, ResourceValidationStatus::Pending);
+    assert_eq!(first.tally, ValidationTally { approvals: 1, rejections: 0 });
+
+    let duplicate: Result<CastValidationVoteOutput, _> = conductors[1]
+        .call_fallible(&bob.zome("zome_gouvernance"), "cast_validation_vote", vote(true))
+        .await;
+    assert!(duplicate.is_err(), "a validator can only vote once");
+
+    await_consistency_20_s([&alice, &bob, &carol]).await.unwrap();
+
+    let second: CastValidationVoteOutput = conductors[2]
+        .call(&carol.zome("zome_gouvernance"), "cast_validation_vote", vote(true))
+        .await;
+    assert_eq!(second.status, ResourceValidationStatus::Approved);
+    assert_eq!(second.tally, ValidationTally { approvals: 2, rejections: 0 });
+
+    // The recorded outcome cites the votes it was decided on
+    let decided: Option<ResourceValidation> = conductors[2]
+        .call(
+            &carol.zome("zome_gouvernance"),
+            "check_validation_status",
+            tool.resource_hash.clone(),
+        )
+        .await;
+    let decided = decided.expect("the tool has a ResourceValidation");
+    assert!(decided.receipts.contains(&first.receipt_hash));
+    assert!(decided.receipts.contains(&second.receipt_hash));
+    assert_eq!(decided.receipts.len(), 2);
+
+    // A single rejection decides a unanimous validation
+    let veto: CastValidationVoteOutput = conductors[1]
+        .call(
+            &bob.zome("zome_gouvernance"),
+            "cast_validation_vote",
+            CastValidationVoteInput {
+                resource_hash: vetoed.resource_hash.clone(),
+                approved: false,
+                notes: Some("Missing safety guard".to_string()),
+            },
+        )
+        .await;
+    assert_eq!(veto.status, ResourceValidationStatus::Rejected);
+
+    await_consistency_20_s([&alice, &bob, &carol]).await.unwrap();
+
+    let approved: EconomicResource = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "get_latest_economic_resource",
+            tool.resource_hash.clone(),
+        )
+        .await;
+    assert_eq!(approved.state, OperationalState::Available);
+    assert_eq!(approved.lifecycle_stage, LifecycleStage::Active);
+
+    let rejected: EconomicResource = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "get_latest_economic_resource",
+            vetoed.resource_hash.clone(),
+        )
+        .await;
+    assert_eq!(rejected.state, OperationalState::PendingValidation);
+}
+
+#[test]
+fn validation_outcomes_only_count_the_panel() {
+    use nondominium_shared::types::{ResourceValidationStatus, ValidationScheme, ValidationTally};
+
+    let tally = |approvals, rejections| ValidationTally { approvals, rejections };
+    let unanimous = ValidationScheme::parse("unanimous", 2).unwrap();
+    let two_of_three = ValidationScheme::parse("2-of-3", 3).unwrap();
+
+    assert_eq!(unanimous.outcome(&tally(1, 1)), ResourceValidationStatus::Rejected);
+    assert_eq!(unanimous.outcome(&tally(2, 0)), ResourceValidationStatus::Approved);
+    assert_eq!(
+        unanimous.outcome(&tally(2, 1)),
+        ResourceValidationStatus::Pending,
+        "more votes than seats are not the panel's"
+    );
+    assert_eq!(two_of_three.outcome(&tally(1, 2)), ResourceValidationStatus::Rejected);
+    assert_eq!(two_of_three.counted_votes(vec![1, 2, 3, 4]), vec![1, 2, 3]);
+}
+
+/// Resources are indexed under their location: named places match however they are
+/// capitalised, geo points are found by distance, closest first, and an update that
+/// moves a resource moves it in both indexes.
+#[tokio::test(flavor = "multi_thread")]
+async fn resources_are_found_by_location() {
+    let (conductors, alice, _bob) = setup_two_agents().await;
+    let brussels = GeoPoint { lat: 50.8503, long: 4.3517 };
+    let ghent = GeoPoint { lat: 51.0543, long: 3.7174 }; // about 50 km from Brussels
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Cordless drill".to_string(),
+                description: "18V cordless drill".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+                validation: None,
+            },
+        )
+        .await;
+    let mut drills = Vec::new();
+    for location in [
+        Location::Geo(brussels),
+        Location::Geo(ghent),
+        Location::Named("Workshop B".to_string()),
+    ] {
+        let created: CreateEconomicResourceOutput = conductors[0]
+            .call(
+                &alice.zome("zome_resource"),
+                "create_economic_resource",
+                EconomicResourceInput {
+                    spec_hash: spec.spec_hash.clone(),
+                    quantity: 1.0,
+                    unit: "one".to_string(),
+                    current_location: Some(location),
+                },
+            )
+            .await;
+        drills.push(created.resource_hash);
+    }
+
+    let near = |radius_km: f64| ResourcesNearInput {
+        lat: brussels.lat + 0.01,
+        long: brussels.long,
+        radius_km,
+    };
+    let close: Vec<NearbyResource> = conductors[0]
+        .call(&alice.zome("zome_resource"), "get_resources_near", near(10.0))
+        .await;
+    assert_eq!(close.len(), 1, "only the Brussels drill is within 10 km");
+    assert_eq!(close[0].record.action_address(), &drills[0]);
+    assert!(close[0].distance_km < 2.0);
+
+    let wider: Vec<NearbyResource> = conductors[0]
+        .call(&alice.zome("zome_resource"), "get_resources_near", near(80.0))
+        .await;
+    let found: Vec<&ActionHash> = wider.iter().map(|n| n.record.action_address()).collect();
+    assert_eq!(found, vec![&drills[0], &drills[1]], "closest first, named places excluded");
+
+    let at_workshop: Vec<Record> = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "get_resources_at_location",
+            Location::Named("workshop  b".to_string()),
+        )
+        .await;
+    assert_eq!(at_workshop.len(), 1);
+
+    // Bring the workshop drill to Brussels
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "update_economic_resource",
+            UpdateEconomicResourceInput {
+                original_action_hash: drills[2].clone(),
+                previous_action_hash: drills[2].clone(),
+                updated_resource: EconomicResourceInput {
+                    spec_hash: spec.spec_hash.clone(),
+                    quantity: 1.0,
+                    unit: "one".to_string(),
+                    current_location: Some(Location::Geo(brussels)),
+                },
+            },
+        )
+        .await;
+    let at_workshop: Vec<Record> = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "get_resources_at_location",
+            Location::Named("Workshop B".to_string()),
+        )
+        .await;
+    assert!(at_workshop.is_empty(), "the location link moves with the resource");
+    let close: Vec<NearbyResource> = conductors[0]
+        .call(&alice.zome("zome_resource"), "get_resources_near", near(10.0))
+        .await;
+    assert_eq!(close.len(), 2);
+
+    let invalid: Result<CreateEconomicResourceOutput, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 1.0,
+                unit: "one".to_string(),
+                current_location: Some(Location::Geo(GeoPoint { lat: 91.0, long: 0.0 })),
+            },
+        )
+        .await;
+    assert!(invalid.is_err(), "latitudes beyond the poles are rejected");
+}
+
+#[test]
+fn geohash_cover_reaches_radii_wider_than_the_coarsest_cells() {
+    use nondominium_shared::location::GeoPoint;
+
+    let brussels = GeoPoint { lat: 50.8503, long: 4.3517 };
+    let new_york = GeoPoint { lat: 40.7128, long: -74.0060 }; // about 5900 km away
+    assert!(brussels.distance_km(&new_york) < 6000.0);
+    assert!(brussels
+        .geohash_cover(6000.0)
+        .contains(&new_york.geohash(1)));
+
+    // Past the pole every longitude is within reach
+    let svalbard = GeoPoint { lat: 78.2232, long: 15.6267 };
+    let alert = GeoPoint { lat: 82.5018, long: -62.3481 };
+    assert!(svalbard.distance_km(&alert) < 2000.0);
+    let cover = svalbard.geohash_cover(2000.0);
+    let precision = cover[0].len();
+    assert!(cover.contains(&alert.geohash(precision)));
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetAllEconomicResourcesOutput {
+    pub resources: Vec<EconomicResource>,
+}
+
+/// A superseded version cannot be updated again, every agent resolves the same latest
+/// version, and anchors keep pointing at the original hash.
+#[tokio::test(flavor = "multi_thread")]
+async fn superseded_versions_resolve_deterministically() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Sawhorse".to_string(),
+                description: "Folding sawhorse".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+                validation: None,
+            },
+        )
+        .await;
+    let resource_input = |quantity: f64| EconomicResourceInput {
+        spec_hash: spec.spec_hash.clone(),
+        quantity,
+        unit: "one".to_string(),
+        current_location: None,
+    };
+    let created: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            resource_input(1.0),
+        )
+        .await;
+    let original = created.resource_hash;
+    let update_input = |previous_action_hash: &ActionHash, quantity: f64| {
+        UpdateEconomicResourceInput {
+            original_action_hash: original.clone(),
+            previous_action_hash: previous_action_hash.clone(),
+            updated_resource: resource_input(quantity),
+        }
+    };
+
+    let recount: Record = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "update_economic_resource",
+            update_input(&original, 2.0),
+        )
+        .await;
+    let fork: Result<Record, _> = conductors[0]
+        .call_fallible(
+            &alice.zome("zome_resource"),
+            "update_economic_resource",
+            update_input(&original, 3.0),
+        )
+        .await;
+    assert!(fork.is_err(), "a superseded version cannot be updated again");
+    let _: Record = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "update_economic_resource",
+            update_input(recount.action_address(), 4.0),
+        )
+        .await;
+
+    await_consistency_20_s([&alice, &bob]).await.unwrap();
+    for (conductor, cell) in [(&conductors[0], &alice), (&conductors[1], &bob)] {
+        let latest: EconomicResource = conductor
+            .call(
+                &cell.zome("zome_resource"),
+                "get_latest_economic_resource",
+                original.clone(),
+            )
+            .await;
+        assert_eq!(latest.onhand_quantity, 4.0, "every agent resolves the same version");
+        assert_eq!(latest.accounting_quantity, 1.0, "a recount leaves accounting alone");
+    }
+
+    let all: GetAllEconomicResourcesOutput = conductors[1]
+        .call(&bob.zome("zome_resource"), "get_all_economic_resources", ())
+        .await;
+    assert_eq!(all.resources.len(), 1, "the resource stays listed once");
+    assert_eq!(all.resources[0].onhand_quantity, 4.0);
+
+    let pending: Vec<Record> = conductors[1]
+        .call(
+            &bob.zome("zome_resource"),
+            "get_resources_by_operational_state",
+            OperationalState::PendingValidation,
+        )
+        .await;
+    assert_eq!(pending.len(), 1);
+}
+
+/// Resources created before the `economic_resources` anchor was sharded are linked from
+/// the unsharded root, and are still listed, whole and page by page, with the sharded ones.
+#[tokio::test(flavor = "multi_thread")]
+async fn resources_linked_from_the_unsharded_root_are_still_listed() {
+    let (conductors, alice, bob) = setup_two_agents().await;
+
+    let spec: CreateResourceSpecificationOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_resource_specification",
+            ResourceSpecificationInput {
+                name: "Wheelbarrow".to_string(),
+                description: "Single-wheel barrow".to_string(),
+                category: "Tools".to_string(),
+                image_url: None,
+                tags: vec![],
+                governance_rules: vec![],
+                validation: None,
+            },
+        )
+        .await;
+    let _: CreateEconomicResourceOutput = conductors[0]
+        .call(
+            &alice.zome("zome_resource"),
+            "create_economic_resource",
+            EconomicResourceInput {
+                spec_hash: spec.spec_hash,
+                quantity: 1.0,
+                unit: "sharded".to_string(),
+                current_location: None,
+            },
+        )
+        .await;
+
+    // EconomicResource is entry type 1 and AllEconomicResources link type 1 of
+    // zome_resource_integrity, the DNA's second integrity zome
+    let alice_writer = direct_writer(&conductors[0], &alice).await;
+    let legacy_hash = alice_writer
+        .commit_entry(
+            Entry::App(app_entry_bytes(&EconomicResource {
+                accounting_quantity: 1.0,
+                onhand_quantity: 1.0,
+                unit: "legacy".to_string(),
+                custodian: alice.agent_pubkey().clone(),
+                current_location: None,
+                state: OperationalState::PendingValidation,
+                lifecycle_stage: LifecycleStage::Active,
+                validated_by: None,
+            })),
+            EntryDefLocation::App(AppEntryDefLocation {
+                zome_index: ZomeIndex(1),
+                entry_def_index: EntryDefIndex(1),
+            }),
+            EntryVisibility::Public,
+        )
+        .await;
+    let _ = alice_writer
+        .create_link(
+            path_entry_hash("economic_resources").into(),
+            legacy_hash.into(),
+            ZomeIndex(1),
+            LinkType(1),
+            LinkTag::new(vec![]),
+        )
+        .await;
+    await_consistency(60, [&alice, &bob]).await.unwrap();
+
+    let units = |resources: &[EconomicResource]| {
+        let mut units: Vec<String> = resources.iter().map(|r| r.unit.clone()).collect();
+        units.sort();
+        units
+    };
+    let all: GetAllEconomicResourcesOutput = conductors[1]
+        .call(&bob.zome("zome_resource"), "get_all_economic_resources", ())
+        .await;
+    assert_eq!(units(&all.resources), vec!["legacy", "sharded"]);
+
+    let mut listed = Vec::new();
+    let mut request = PageRequest { cursor: None, limit: 1 };
+    loop {
+        let page: Page<EconomicResource> = conductors[1]
+            .call(
+                &bob.zome("zome_resource"),
+                "get_all_economic_resources_paginated",
+                request.clone(),
+            )
+            .await;
+        assert!(page.items.len() <= 1);
+        listed.extend(page.items);
+        match page.next_cursor {
+            Some(cursor) => request.cursor = Some(cursor),
+            None => break,
+        }
+    }
+    assert_eq!(units(&listed), vec!["legacy", "sharded"]);
+}
@@ -2,6 +2,7 @@ use hdk::prelude::*;
 use zome_gouvernance_integrity::*;
 use nondominium_shared::external_local_call;
 use nondominium_shared::io::governance::{CreateAgreementInput, UpdateAgreementInput};
+use nondominium_shared::update_chain::get_latest_record;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AgreementRecord {
@@ -124,10 +125,10 @@ pub fn get_current_agreement(ndo_identity_hash: ActionHash) -> ExternResult<Opti
   };
 
   // Walk update chain to latest
-  let latest_hash = resolve_agreement_update_chain(action_hash.clone())?;
-  let Some(record) = get(latest_hash.clone(), GetOptions::default())? else {
+  let Some(record) = get_latest_record(action_hash)? else {
     return Ok(None);
   };
+  let latest_hash = record.action_address().clone();
 
   let Ok(Some(entry)) = record.entry().to_app_option::<Agreement>() else {
     return Ok(None);
@@ -147,17 +148,3 @@ pub fn get_agreement(action_hash: ActionHash) -> ExternResult<Option<AgreementRe
   };
   Ok(Some(AgreementRecord { action_hash, entry }))
 }
-
-fn resolve_agreement_update_chain(original: ActionHash) -> ExternResult<ActionHash> {
-  let mut current = original;
-  loop {
-    let links = get_links(LinkQuery::try_new(current.clone(), LinkTypes::AgreementUpdates)?, GetStrategy::default())?;
-    let Some(link) = links.into_iter().max_by_key(|l| l.timestamp) else {
-      return Ok(current);
-    };
-    let Some(next) = link.target.into_action_hash() else {
-      return Ok(current);
-    };
-    current = next;
-  }
-}
@@ -1,10 +1,22 @@
+use crate::ppr::{create_service_commitment_pprs, IssueParticipationReceiptsOutcome};
+use crate::process::ensure_open_process;
 use crate::GovernanceError;
 use hdk::prelude::*;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use nondominium_shared::update_chain::{
+  get_latest_record, get_original_action_hash, newest_update,
+};
 use zome_gouvernance_integrity::*;
 
 // ============================================================================
 // Commitment Management
 // ============================================================================
+//
+// A Commitment is proposed by its receiver, then accepted or declined by its provider;
+// the receiver may cancel it until it is declined. Each status change is an update of
+// the latest version. Fulfillment is never stored: it is summed from the EconomicEvents
+// the commitment's Claims point at.
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ProposeCommitmentInput {
@@ -14,6 +26,10 @@ pub struct ProposeCommitmentInput {
   pub provider: AgentPubKey,
   pub due_date: Timestamp,
   pub note: Option<String>,
+  #[serde(default)]
+  pub input_of: Option<ActionHash>, // Open Process the commitment is planned for
+  #[serde(default)]
+  pub resource_quantity: Option<f64>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -29,32 +45,60 @@ pub fn propose_commitment(input: ProposeCommitmentInput) -> ExternResult<Propose
 
   // TODO: In Phase 2, check that the calling agent has restricted_access capability
 
+  if let Some(process_hash) = &input.input_of {
+    ensure_open_process(process_hash)?;
+  }
+
   let commitment = Commitment {
     action: input.action,
     provider: input.provider,
     receiver: agent_info.agent_initial_pubkey,
     resource_inventoried_as: input.resource_hash,
     resource_conforms_to: input.resource_spec_hash,
-    input_of: None, // TODO: Add process support in Phase 4
+    input_of: input.input_of.clone(),
     due_date: input.due_date,
     note: input.note,
     committed_at: now,
+    resource_quantity: input.resource_quantity,
+    status: CommitmentStatus::Proposed,
   };
 
   let commitment_hash = create_entry(&EntryTypes::Commitment(commitment.clone()))?;
 
   // Create discovery link
-  let path = Path::from("all_commitments");
-  let anchor_hash = path.path_entry_hash()?;
-  create_link(
-    anchor_hash,
+  create_hash_sharded_link(
+    "all_commitments",
     commitment_hash.clone(),
     LinkTypes::AllCommitments,
-    (),
   )?;
 
-  // TODO: Link commitment to provider and receiver when AgentToCommitment link type is added
-  // For now, just use the basic discovery link
+  if let Some(process_hash) = input.input_of {
+    create_link(
+      process_hash,
+      commitment_hash.clone(),
+      LinkTypes::ProcessToCommitment,
+      (),
+    )?;
+  }
+
+  // Agent-centric indexes: "what I owe" (provider) and "what is owed to me" (receiver)
+  let tag = CommitmentLinkTag {
+    action: commitment.action.clone(),
+    due_date: commitment.due_date,
+  }
+  .to_link_tag()?;
+  create_link(
+    commitment.provider.clone(),
+    commitment_hash.clone(),
+    LinkTypes::ProviderToCommitment,
+    tag.clone(),
+  )?;
+  create_link(
+    commitment.receiver.clone(),
+    commitment_hash.clone(),
+    LinkTypes::ReceiverToCommitment,
+    tag,
+  )?;
 
   Ok(ProposeCommitmentOutput {
     commitment_hash,
@@ -64,51 +108,430 @@ pub fn propose_commitment(input: ProposeCommitmentInput) -> ExternResult<Propose
 
 #[hdk_extern]
 pub fn get_all_commitments(_: ()) -> ExternResult<Vec<Commitment>> {
-  let path = Path::from("all_commitments");
-  let anchor_hash = path.path_entry_hash()?;
+  resolve_commitment_links(all_commitment_links()?)
+}
+
+/// One page of `get_all_commitments`, in link order.
+#[hdk_extern]
+pub fn get_all_commitments_paginated(page: PageRequest) -> ExternResult<Page<Commitment>> {
+  let (links, next_cursor) = page.select(all_commitment_links()?);
+  Ok(Page {
+    items: resolve_commitment_links(links)?,
+    next_cursor,
+  })
+}
+
+fn all_commitment_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("all_commitments", LinkTypes::AllCommitments)
+}
+
+fn resolve_commitment_links(links: Vec<Link>) -> ExternResult<Vec<Commitment>> {
+  let mut commitments = Vec::new();
+
+  for link in links {
+    if let Some(commitment_hash) = link.target.into_action_hash() {
+      if let Some((_, commitment)) = get_latest_commitment(&commitment_hash)? {
+        commitments.push(commitment);
+      }
+    }
+  }
+
+  Ok(commitments)
+}
+
+/// Latest version of a Commitment and its action hash, addressed by the original hash.
+pub(crate) fn get_latest_commitment(
+  commitment_hash: &ActionHash,
+) -> ExternResult<Option<(ActionHash, Commitment)>> {
+  let Some(record) = get_latest_record(commitment_hash.clone())? else {
+    return Ok(None);
+  };
+  let latest_hash = record.action_address().clone();
+  match record.entry().to_app_option::<EntryTypes>().map_err(|_| {
+    wasm_error!(WasmErrorInner::Guest(
+      "Failed to deserialize commitment".into()
+    ))
+  })? {
+    Some(EntryTypes::Commitment(commitment)) => Ok(Some((latest_hash, commitment))),
+    _ => Ok(None),
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateCommitmentStatusOutput {
+  pub commitment_hash: ActionHash, // Action hash of the new version
+  pub commitment: Commitment,
+  pub ppr_claims: Option<IssueParticipationReceiptsOutcome>, // Issued on acceptance of service commitments
+}
+
+/// Provider accepts a proposed Commitment. Service commitments (work, modification,
+/// transport) issue the matching `*CommitmentAccepted` PPR pair.
+#[hdk_extern]
+pub fn accept_commitment(
+  commitment_hash: ActionHash,
+) -> ExternResult<UpdateCommitmentStatusOutput> {
+  let mut output = set_commitment_status(&commitment_hash, CommitmentStatus::Accepted)?;
+
+  if let Some(service_type) = service_type_for(&output.commitment.action) {
+    match create_service_commitment_pprs(
+      commitment_hash.clone(),
+      service_type,
+      output.commitment.provider.clone(),
+      output.commitment.receiver.clone(),
+      output.commitment.resource_inventoried_as.clone(),
+    ) {
+      Ok(pprs) => output.ppr_claims = Some(pprs),
+      Err(e) => error!(
+        "Failed to issue acceptance PPRs for commitment {}: {:?}",
+        commitment_hash, e
+      ),
+    }
+  }
+
+  Ok(output)
+}
+
+/// Provider declines a proposed Commitment.
+#[hdk_extern]
+pub fn decline_commitment(
+  commitment_hash: ActionHash,
+) -> ExternResult<UpdateCommitmentStatusOutput> {
+  set_commitment_status(&commitment_hash, CommitmentStatus::Declined)
+}
+
+/// Receiver withdraws a proposed or accepted Commitment.
+#[hdk_extern]
+pub fn cancel_commitment(
+  commitment_hash: ActionHash,
+) -> ExternResult<UpdateCommitmentStatusOutput> {
+  set_commitment_status(&commitment_hash, CommitmentStatus::Cancelled)
+}
+
+fn set_commitment_status(
+  commitment_hash: &ActionHash,
+  status: CommitmentStatus,
+) -> ExternResult<UpdateCommitmentStatusOutput> {
+  let agent = agent_info()?.agent_initial_pubkey;
+  let (latest_hash, commitment) = get_latest_commitment(commitment_hash)?.ok_or(
+    GovernanceError::CommitmentNotFound(commitment_hash.to_string()),
+  )?;
+
+  let party = match status {
+    CommitmentStatus::Accepted | CommitmentStatus::Declined => &commitment.provider,
+    _ => &commitment.receiver,
+  };
+  if *party != agent {
+    return Err(
+      GovernanceError::NotAuthorized(format!("cannot mark this commitment {:?}", status)).into(),
+    );
+  }
+  if !commitment.status.can_transition_to(status) {
+    return Err(
+      GovernanceError::InvalidInput(format!(
+        "commitment is {:?} and cannot become {:?}",
+        commitment.status, status
+      ))
+      .into(),
+    );
+  }
+
+  let updated = Commitment {
+    status,
+    ..commitment
+  };
+  let updated_hash = update_entry(latest_hash, &EntryTypes::Commitment(updated.clone()))?;
+
+  Ok(UpdateCommitmentStatusOutput {
+    commitment_hash: updated_hash,
+    commitment: updated,
+    ppr_claims: None,
+  })
+}
+
+/// PPR service category for commitments whose acceptance earns a `*CommitmentAccepted` claim
+fn service_type_for(action: &VfAction) -> Option<&'static str> {
+  match action {
+    VfAction::Work | VfAction::Modify => Some("maintenance"),
+    VfAction::Move => Some("transport"),
+    _ => None,
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommitmentStateOutput {
+  pub commitment: Commitment,
+  pub state: CommitmentState,
+  pub progress: FulfillmentProgress,
+}
+
+/// Effective state of a Commitment, with fulfillment summed from its claimed events.
+#[hdk_extern]
+pub fn get_commitment_state(commitment_hash: ActionHash) -> ExternResult<CommitmentStateOutput> {
+  let (_, commitment) = get_latest_commitment(&commitment_hash)?.ok_or(
+    GovernanceError::CommitmentNotFound(commitment_hash.to_string()),
+  )?;
+  let progress = fulfillment_progress(&commitment_hash, &commitment)?;
+  let state = CommitmentState::derive(
+    commitment.status,
+    &progress,
+    commitment.due_date,
+    sys_time()?,
+  );
+  Ok(CommitmentStateOutput {
+    commitment,
+    state,
+    progress,
+  })
+}
+
+/// Sum the quantities of the distinct EconomicEvents validly claimed against a Commitment:
+/// claimed by its provider while it was Accepted, for an event matching the commitment.
+fn fulfillment_progress(
+  commitment_hash: &ActionHash,
+  commitment: &Commitment,
+) -> ExternResult<FulfillmentProgress> {
+  let mut progress = FulfillmentProgress {
+    committed_quantity: commitment.resource_quantity,
+    ..Default::default()
+  };
+  let Some((accepted_at, cancelled_at)) = acceptance_window(commitment_hash)? else {
+    return Ok(progress);
+  };
 
   let links = get_links(
-    LinkQuery::try_new(anchor_hash, LinkTypes::AllCommitments)?,
+    LinkQuery::try_new(commitment_hash.clone(), LinkTypes::CommitmentToClaim)?,
     GetStrategy::default(),
   )?;
-  let mut commitments = Vec::new();
-
+  let mut counted = HashSet::new();
   for link in links {
-    if let Ok(any_dht_hash) = AnyDhtHash::try_from(link.target.clone()) {
-      if let Some(record) = get(any_dht_hash, GetOptions::default())? {
-        if let Ok(Some(EntryTypes::Commitment(commitment))) =
-          record.entry().to_app_option::<EntryTypes>().map_err(|_| {
-            wasm_error!(WasmErrorInner::Guest(
-              "Failed to deserialize commitment".into()
-            ))
-          })
-        {
-          commitments.push(commitment);
+    let Some(claim_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get(claim_hash, GetOptions::default())? else {
+      continue;
+    };
+    let Ok(Some(EntryTypes::Claim(claim))) = record.entry().to_app_option::<EntryTypes>() else {
+      continue;
+    };
+    let claimed_at = record.action().timestamp();
+    if *record.action().author() != commitment.provider
+      || claimed_at < accepted_at
+      || cancelled_at.is_some_and(|cancelled_at| claimed_at >= cancelled_at)
+      || counted.contains(&claim.fulfilled_by)
+    {
+      continue;
+    }
+    let Some(event) = get_economic_event(&claim.fulfilled_by)? else {
+      continue;
+    };
+    if !event_fulfills(&event, commitment) {
+      continue;
+    }
+    counted.insert(claim.fulfilled_by);
+    progress.fulfilled_quantity += event.resource_quantity;
+    progress.fulfilling_events += 1;
+  }
+  Ok(progress)
+}
+
+/// When the Commitment was accepted and, if it was, when it was cancelled, read from its
+/// update chain. None if it was never accepted.
+fn acceptance_window(
+  commitment_hash: &ActionHash,
+) -> ExternResult<Option<(Timestamp, Option<Timestamp>)>> {
+  let Some(mut current) = get_original_action_hash(commitment_hash.clone())? else {
+    return Ok(None);
+  };
+  let mut accepted_at = None;
+  while let Some(Details::Record(details)) = get_details(current, GetOptions::default())? {
+    if let Ok(Some(EntryTypes::Commitment(version))) =
+      details.record.entry().to_app_option::<EntryTypes>()
+    {
+      let at = details.record.action().timestamp();
+      match version.status {
+        CommitmentStatus::Accepted => accepted_at = Some(at),
+        CommitmentStatus::Cancelled => {
+          return Ok(accepted_at.map(|accepted_at| (accepted_at, Some(at))));
         }
+        _ => {}
       }
     }
+    let next = newest_update(
+      details
+        .updates
+        .iter()
+        .map(|update| (update.action().timestamp(), &update.hashed.hash)),
+    );
+    match next {
+      Some(next) => current = next.clone(),
+      None => break,
+    }
   }
+  Ok(accepted_at.map(|accepted_at| (accepted_at, None)))
+}
 
-  Ok(commitments)
+/// The event is the committed action between the commitment's two parties
+fn event_fulfills(event: &EconomicEvent, commitment: &Commitment) -> bool {
+  event.action == commitment.action
+    && event.provider == commitment.provider
+    && event.receiver == commitment.receiver
+}
+
+fn get_economic_event(event_hash: &ActionHash) -> ExternResult<Option<EconomicEvent>> {
+  let Some(record) = get(event_hash.clone(), GetOptions::default())? else {
+    return Ok(None);
+  };
+  match record.entry().to_app_option::<EntryTypes>() {
+    Ok(Some(EntryTypes::EconomicEvent(event))) => Ok(Some(event)),
+    _ => Ok(None),
+  }
 }
 
+/// Commitments where the agent is provider or receiver, at their latest version.
 #[hdk_extern]
 pub fn get_commitments_for_agent(agent: AgentPubKey) -> ExternResult<Vec<Commitment>> {
-  // TODO: Implement agent-specific commitment links when AgentToCommitment link type is added
-  // For now, filter all commitments by agent
-  let all_commitments = get_all_commitments(())?;
+  let mut listings = get_agent_commitments(GetAgentCommitmentsInput {
+    agent: agent.clone(),
+    side: CommitmentSide::Provider,
+    due_from: None,
+    due_until: None,
+    statuses: None,
+  })?;
+  let mut seen: HashSet<ActionHash> = listings.iter().map(|l| l.commitment_hash.clone()).collect();
+  for listing in get_agent_commitments(GetAgentCommitmentsInput {
+    agent,
+    side: CommitmentSide::Receiver,
+    due_from: None,
+    due_until: None,
+    statuses: None,
+  })? {
+    if seen.insert(listing.commitment_hash.clone()) {
+      listings.push(listing);
+    }
+  }
 
-  let agent_commitments: Vec<Commitment> = all_commitments
-    .into_iter()
-    .filter(|commitment| commitment.provider == agent || commitment.receiver == agent)
-    .collect();
+  Ok(listings.into_iter().map(|l| l.commitment).collect())
+}
+
+/// Which side of a Commitment the agent is on.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum CommitmentSide {
+  Provider, // What the agent owes
+  Receiver, // What is owed to the agent
+}
 
-  Ok(agent_commitments)
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetAgentCommitmentsInput {
+  pub agent: AgentPubKey,
+  pub side: CommitmentSide,
+  pub due_from: Option<Timestamp>,  // Inclusive
+  pub due_until: Option<Timestamp>, // Inclusive
+  pub statuses: Option<Vec<CommitmentStatus>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommitmentListing {
+  pub commitment_hash: ActionHash, // Original action hash
+  pub commitment: Commitment,
+}
+
+/// List an agent's commitments from one side, filtered by due-date window (read from the
+/// link tags) and stored status, ordered by due date. Commitments proposed before the
+/// agent indexes existed have no index links and are found on the `all_commitments` root.
+#[hdk_extern]
+pub fn get_agent_commitments(
+  input: GetAgentCommitmentsInput,
+) -> ExternResult<Vec<CommitmentListing>> {
+  let link_type = match input.side {
+    CommitmentSide::Provider => LinkTypes::ProviderToCommitment,
+    CommitmentSide::Receiver => LinkTypes::ReceiverToCommitment,
+  };
+  let links = get_links(
+    LinkQuery::try_new(input.agent.clone(), link_type)?,
+    GetStrategy::default(),
+  )?;
+
+  let mut listings = Vec::new();
+  let mut indexed = HashSet::new();
+  for link in links {
+    let Some(tag) = CommitmentLinkTag::from_link_tag(link.tag) else {
+      continue;
+    };
+    let Some(commitment_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    indexed.insert(commitment_hash.clone());
+    if !due_within(&input, tag.due_date) {
+      continue;
+    }
+    if let Some(listing) = agent_commitment_listing(&input, commitment_hash)? {
+      listings.push(listing);
+    }
+  }
+
+  // Commitments that predate the agent indexes also predate sharding: they hang off the
+  // unsharded root
+  let legacy_links = get_links(
+    LinkQuery::try_new(
+      Path::from("all_commitments").path_entry_hash()?,
+      LinkTypes::AllCommitments,
+    )?,
+    GetStrategy::default(),
+  )?;
+  for link in legacy_links {
+    let Some(commitment_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    if !indexed.insert(commitment_hash.clone()) {
+      continue;
+    }
+    if let Some(listing) = agent_commitment_listing(&input, commitment_hash)? {
+      if due_within(&input, listing.commitment.due_date) {
+        listings.push(listing);
+      }
+    }
+  }
+
+  listings.sort_by_key(|listing| listing.commitment.due_date);
+  Ok(listings)
+}
+
+fn due_within(input: &GetAgentCommitmentsInput, due_date: Timestamp) -> bool {
+  !(input.due_from.is_some_and(|from| due_date < from)
+    || input.due_until.is_some_and(|until| due_date > until))
+}
+
+// The latest version of a commitment, if the agent is on the requested side of it and it
+// has one of the requested statuses
+fn agent_commitment_listing(
+  input: &GetAgentCommitmentsInput,
+  commitment_hash: ActionHash,
+) -> ExternResult<Option<CommitmentListing>> {
+  let Some((_, commitment)) = get_latest_commitment(&commitment_hash)? else {
+    return Ok(None);
+  };
+  let party = match input.side {
+    CommitmentSide::Provider => &commitment.provider,
+    CommitmentSide::Receiver => &commitment.receiver,
+  };
+  if *party != input.agent {
+    return Ok(None);
+  }
+  if let Some(statuses) = &input.statuses {
+    if !statuses.contains(&commitment.status) {
+      return Ok(None);
+    }
+  }
+  Ok(Some(CommitmentListing {
+    commitment_hash,
+    commitment,
+  }))
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ClaimCommitmentInput {
   pub commitment_hash: ActionHash,
+  pub fulfilled_by: ActionHash, // EconomicEvent delivering (part of) the commitment
   pub fulfillment_note: Option<String>,
 }
 
@@ -116,33 +539,64 @@ pub struct ClaimCommitmentInput {
 pub struct ClaimCommitmentOutput {
   pub claim_hash: ActionHash,
   pub claim: Claim,
+  pub state: CommitmentState,
+  pub progress: FulfillmentProgress,
 }
 
 #[hdk_extern]
 pub fn claim_commitment(input: ClaimCommitmentInput) -> ExternResult<ClaimCommitmentOutput> {
-  let _agent_info = agent_info()?;
+  let agent = agent_info()?.agent_initial_pubkey;
   let now = sys_time()?;
 
-  // Get the original commitment
-  let commitment_record = get(input.commitment_hash.clone(), GetOptions::default())?.ok_or(
+  let (_, commitment) = get_latest_commitment(&input.commitment_hash)?.ok_or(
     GovernanceError::CommitmentNotFound(input.commitment_hash.to_string()),
   )?;
 
-  let _commitment = match commitment_record.entry().to_app_option::<EntryTypes>() {
-    Ok(Some(EntryTypes::Commitment(commitment))) => commitment,
-    _ => {
-      return Err(
-        GovernanceError::SerializationError("Invalid commitment entry".to_string()).into(),
-      )
-    }
-  };
+  if commitment.provider != agent {
+    return Err(
+      GovernanceError::NotAuthorized("only the provider can claim a commitment".to_string()).into(),
+    );
+  }
+  if commitment.status != CommitmentStatus::Accepted {
+    return Err(
+      GovernanceError::InvalidInput(format!(
+        "commitment is {:?}; only accepted commitments can be claimed",
+        commitment.status
+      ))
+      .into(),
+    );
+  }
+  let progress = fulfillment_progress(&input.commitment_hash, &commitment)?;
+  if progress.is_complete() {
+    return Err(
+      GovernanceError::InvalidInput("commitment is already fulfilled".to_string()).into(),
+    );
+  }
 
-  // TODO: In Phase 2, verify that the calling agent is the provider of the commitment
-  // TODO: In Phase 2, check that the commitment hasn't already been claimed
+  // The event must be the committed action between the same two parties
+  let event = get_economic_event(&input.fulfilled_by)?.ok_or(
+    GovernanceError::EconomicEventNotFound(input.fulfilled_by.to_string()),
+  )?;
+  if !event_fulfills(&event, &commitment) {
+    return Err(
+      GovernanceError::InvalidInput(
+        "event does not match the commitment's action, provider and receiver".to_string(),
+      )
+      .into(),
+    );
+  }
+  let already_claimed = get_claims_for_commitment(input.commitment_hash.clone())?
+    .iter()
+    .any(|claim| claim.fulfilled_by == input.fulfilled_by);
+  if already_claimed {
+    return Err(
+      GovernanceError::InvalidInput("event already claimed for this commitment".to_string()).into(),
+    );
+  }
 
   let claim = Claim {
     fulfills: input.commitment_hash.clone(),
-    fulfilled_by: input.commitment_hash.clone(), // TODO: Link to actual EconomicEvent
+    fulfilled_by: input.fulfilled_by.clone(),
     claimed_at: now,
     note: input.fulfillment_note,
   };
@@ -150,9 +604,7 @@ pub fn claim_commitment(input: ClaimCommitmentInput) -> ExternResult<ClaimCommit
   let claim_hash = create_entry(&EntryTypes::Claim(claim.clone()))?;
 
   // Create discovery link
-  let path = Path::from("all_claims");
-  let anchor_hash = path.path_entry_hash()?;
-  create_link(anchor_hash, claim_hash.clone(), LinkTypes::AllClaims, ())?;
+  create_hash_sharded_link("all_claims", claim_hash.clone(), LinkTypes::AllClaims)?;
 
   // Link claim to the original commitment
   create_link(
@@ -162,18 +614,41 @@ pub fn claim_commitment(input: ClaimCommitmentInput) -> ExternResult<ClaimCommit
     (),
   )?;
 
-  Ok(ClaimCommitmentOutput { claim_hash, claim })
+  let progress = FulfillmentProgress {
+    fulfilled_quantity: progress.fulfilled_quantity + event.resource_quantity,
+    fulfilling_events: progress.fulfilling_events + 1,
+    ..progress
+  };
+  let state = CommitmentState::derive(commitment.status, &progress, commitment.due_date, now);
+
+  Ok(ClaimCommitmentOutput {
+    claim_hash,
+    claim,
+    state,
+    progress,
+  })
 }
 
 #[hdk_extern]
 pub fn get_all_claims(_: ()) -> ExternResult<Vec<Claim>> {
-  let path = Path::from("all_claims");
-  let anchor_hash = path.path_entry_hash()?;
+  resolve_claim_links(all_claim_links()?)
+}
 
-  let links = get_links(
-    LinkQuery::try_new(anchor_hash, LinkTypes::AllClaims)?,
-    GetStrategy::default(),
-  )?;
+/// One page of `get_all_claims`, in link order.
+#[hdk_extern]
+pub fn get_all_claims_paginated(page: PageRequest) -> ExternResult<Page<Claim>> {
+  let (links, next_cursor) = page.select(all_claim_links()?);
+  Ok(Page {
+    items: resolve_claim_links(links)?,
+    next_cursor,
+  })
+}
+
+fn all_claim_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("all_claims", LinkTypes::AllClaims)
+}
+
+fn resolve_claim_links(links: Vec<Link>) -> ExternResult<Vec<Claim>> {
   let mut claims = Vec::new();
 
   for link in links {
@@ -2,6 +2,7 @@ use hdk::prelude::*;
 use zome_gouvernance_integrity::*;
 use nondominium_shared::external_local_call;
 use nondominium_shared::io::governance::ValidateContributionInput;
+use crate::process::ensure_open_process;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ContributionRecord {
@@ -27,6 +28,10 @@ pub fn validate_contribution(input: ValidateContributionInput) -> ExternResult<A
     )));
   }
 
+  if let Some(process_hash) = &input.input_of {
+    ensure_open_process(process_hash)?;
+  }
+
   let now = sys_time()?;
 
   let contribution = Contribution {
@@ -35,7 +40,7 @@ pub fn validate_contribution(input: ValidateContributionInput) -> ExternResult<A
     work_log_group_dna_hash: input.work_log_group_dna_hash,
     work_log_action_hash: input.work_log_action_hash,
     ndo_identity_hash: input.ndo_identity_hash.clone(),
-    input_of: input.input_of,
+    input_of: input.input_of.clone(),
     note: input.note,
     effort_quantity: input.effort_quantity,
     validated_by: vec![validator.clone()],
@@ -62,6 +67,15 @@ pub fn validate_contribution(input: ValidateContributionInput) -> ExternResult<A
     (),
   )?;
 
+  if let Some(process_hash) = input.input_of {
+    create_link(
+      process_hash,
+      action_hash.clone(),
+      LinkTypes::ProcessToContribution,
+      (),
+    )?;
+  }
+
   Ok(action_hash)
 }
 
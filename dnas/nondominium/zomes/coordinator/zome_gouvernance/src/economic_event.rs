@@ -1,70 +1,206 @@
 use crate::ppr::*;
+use crate::process::ensure_open_process;
+use crate::GovernanceError;
 use hdk::prelude::*;
+use nondominium_shared::call_resource_zome;
+pub use nondominium_shared::io::governance::LogEconomicEventInput;
+use nondominium_shared::io::resource::{
+  AppliedEventEffect, ApplyEconomicEventInput, ConvertQuantityInput,
+  EvaluateResourceGovernanceInput,
+};
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_month_sharded_link, get_month_sharded_links};
+use nondominium_shared::rules::{GovernanceDecision, GovernedOperation};
+use nondominium_shared::types::OperationalState;
+use nondominium_shared::update_chain::get_original_action_hash;
 use zome_gouvernance_integrity::*;
 
+// Subset of zome_resource's EconomicResource used to authorize and apply events
+#[derive(Serialize, Deserialize, Debug, SerializedBytes)]
+struct ResourceSnapshot {
+  pub custodian: AgentPubKey,
+  pub state: OperationalState,
+  pub accounting_quantity: f64,
+  pub onhand_quantity: f64,
+  pub unit: String,
+}
+
 // ============================================================================
 // Economic Event Management
 // ============================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct LogEconomicEventInput {
-  pub action: VfAction,
-  pub provider: AgentPubKey,
-  pub receiver: AgentPubKey,
-  pub resource_inventoried_as: ActionHash,
-  pub resource_quantity: f64,
-  pub note: Option<String>,
-  pub commitment_hash: Option<ActionHash>, // Optional link to commitment being fulfilled
-  pub generate_pprs: Option<bool>,         // Whether to auto-generate PPR claims
-}
-
 #[derive(Serialize, Deserialize, Debug)]
 pub struct LogEconomicEventOutput {
   pub event_hash: ActionHash,
   pub event: EconomicEvent,
-  pub ppr_claims: Option<IssueParticipationReceiptsOutput>, // Generated PPR claims if requested
+  pub ppr_claims: Option<IssueParticipationReceiptsOutcome>, // Generated PPR claims if requested
+  pub resource_effect: Option<AppliedEventEffect>, // Set when the event changed its resource
 }
 
 #[hdk_extern]
 pub fn log_economic_event(input: LogEconomicEventInput) -> ExternResult<LogEconomicEventOutput> {
   let now = sys_time()?;
 
-  // TODO: In Phase 2, add proper authorization checks
-  // TODO: In Phase 2, validate the resource exists and check governance rules
+  // Events are recorded by one of their parties
+  let agent = agent_info()?.agent_initial_pubkey;
+  if agent != input.provider && agent != input.receiver {
+    return Err(
+      GovernanceError::NotAuthorized(
+        "only the provider or receiver can log an economic event".to_string(),
+      )
+      .into(),
+    );
+  }
+
+  if !(input.resource_quantity.is_finite() && input.resource_quantity > 0.0) {
+    return Err(
+      GovernanceError::InvalidInput("resource_quantity must be a positive number".to_string())
+        .into(),
+    );
+  }
+
+  // The resource must exist, and its current state and custodian must admit the action
+  let not_found = || GovernanceError::ResourceNotFound(input.resource_inventoried_as.to_string());
+  let resource_record: Option<Record> = call_resource_zome(
+    "get_latest_economic_resource_record",
+    input.resource_inventoried_as.clone(),
+  )
+  .map_err(|_| not_found())?;
+  let resource_record = resource_record.ok_or_else(not_found)?;
+  let resource: ResourceSnapshot = resource_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| GovernanceError::SerializationError(format!("{:?}", e)))?
+    .ok_or_else(not_found)?;
+  input
+    .action
+    .check_resource(
+      &resource.state,
+      &resource.custodian,
+      &input.provider,
+      &input.receiver,
+    )
+    .map_err(GovernanceError::InvalidInput)?;
+
+  // An event may be measured in another unit of the same dimension as the resource
+  let quantity = match &input.resource_unit {
+    Some(unit) if *unit != resource.unit => call_resource_zome(
+      "convert_quantity",
+      ConvertQuantityInput {
+        quantity: input.resource_quantity,
+        from_unit: unit.clone(),
+        to_unit: resource.unit.clone(),
+      },
+    )?,
+    _ => input.resource_quantity,
+  };
+
+  // Events that change the resource are applied by its custodian, and may not take
+  // its quantity below zero. Custody already held by the receiver needs no handover.
+  let effect = input.action.resource_effect(quantity);
+  let changes_resource = effect.changes_quantity()
+    || effect.changes_location
+    || (effect.transfers_custody && resource.custodian != input.receiver);
+  if changes_resource && agent != resource.custodian {
+    return Err(
+      GovernanceError::NotAuthorized(format!(
+        "only the resource's custodian can log {:?} events",
+        input.action
+      ))
+      .into(),
+    );
+  }
+  effect
+    .apply_quantities(resource.accounting_quantity, resource.onhand_quantity)
+    .map_err(GovernanceError::InvalidInput)?;
+  if effect.changes_location && input.to_location.is_none() {
+    return Err(GovernanceError::InvalidInput("Move events need a to_location".to_string()).into());
+  }
+
+  // Governance rules attached to the resource's specification
+  let decision: GovernanceDecision = call_resource_zome(
+    "evaluate_resource_governance",
+    EvaluateResourceGovernanceInput {
+      resource_hash: input.resource_inventoried_as.clone(),
+      operation: GovernedOperation::EconomicEvent {
+        action: input.action.clone(),
+      },
+      quantity: Some(quantity),
+      recipient: Some(input.receiver.clone()),
+      duration_hours: input.duration_hours,
+    },
+  )?;
+  if !decision.allowed {
+    return Err(GovernanceError::GovernanceViolation(decision.reasons()).into());
+  }
+  if let Some(process_hash) = &input.input_of {
+    ensure_open_process(process_hash)?;
+  }
 
   let event = EconomicEvent {
     action: input.action.clone(),
     provider: input.provider.clone(),
     receiver: input.receiver.clone(),
     resource_inventoried_as: input.resource_inventoried_as.clone(),
-    affects: input.resource_inventoried_as.clone(), // For now, same as inventoried_as
+    affects: resource_record.action_address().clone(),
     resource_quantity: input.resource_quantity,
     event_time: now,
     note: input.note.clone(),
+    to_location: input.to_location.clone(),
+    resource_unit: Some(input.resource_unit.clone().unwrap_or(resource.unit)),
   };
 
   let event_hash = create_entry(&EntryTypes::EconomicEvent(event.clone()))?;
 
-  // Create discovery link
-  let path = Path::from("all_economic_events");
-  let anchor_hash = path.path_entry_hash()?;
-  create_link(
-    anchor_hash,
+  // Create discovery link, in the shard of the month the event occurred
+  create_month_sharded_link(
+    "all_economic_events",
+    event.event_time,
     event_hash.clone(),
     LinkTypes::AllEconomicEvents,
-    (),
+    LinkTypes::EconomicEventMonths,
   )?;
 
-  // Link the event to the resource
+  // Link the event to the resource's original hash, which every version resolves to
+  let resource_original_hash =
+    get_original_action_hash(input.resource_inventoried_as.clone())?.ok_or_else(not_found)?;
   create_link(
-    input.resource_inventoried_as,
+    resource_original_hash,
     event_hash.clone(),
     LinkTypes::ResourceToEvent,
     (),
   )?;
 
-  // Generate PPR claims if requested (default is true for Phase 2)
-  let generate_pprs = input.generate_pprs.unwrap_or(true);
+  if let Some(process_hash) = input.input_of {
+    create_link(
+      process_hash,
+      event_hash.clone(),
+      LinkTypes::ProcessToEvent,
+      (),
+    )?;
+  }
+
+  // Apply the action's effect to the resource version the event was recorded against
+  let resource_effect = if changes_resource {
+    Some(call_resource_zome(
+      "apply_economic_event",
+      ApplyEconomicEventInput {
+        event_hash: event_hash.clone(),
+        resource_hash: event.affects.clone(),
+        action: event.action.clone(),
+        receiver: event.receiver.clone(),
+        resource_quantity: event.resource_quantity,
+        to_location: event.to_location.clone(),
+        resource_unit: event.resource_unit.clone(),
+      },
+    )?)
+  } else {
+    None
+  };
+
+  // Generate PPR claims if requested (default is true for Phase 2); a receipt pair needs
+  // two distinct parties, so self-dealing events get none
+  let generate_pprs = input.generate_pprs.unwrap_or(true) && event.provider != event.receiver;
   let ppr_claims = if generate_pprs {
     // Use commitment hash if provided, otherwise create a placeholder
     let commitment_hash = input.commitment_hash.unwrap_or_else(|| event_hash.clone());
@@ -91,6 +227,7 @@ pub fn log_economic_event(input: LogEconomicEventInput) -> ExternResult<LogEcono
     event_hash,
     event,
     ppr_claims,
+    resource_effect,
   })
 }
 
@@ -105,7 +242,7 @@ pub struct LogInitialTransferInput {
 pub struct LogInitialTransferOutput {
   pub event_hash: ActionHash,
   pub event: EconomicEvent,
-  pub ppr_claims: Option<IssueParticipationReceiptsOutput>, // Generated PPR claims for agent promotion
+  pub ppr_claims: Option<IssueParticipationReceiptsOutcome>, // Generated PPR claims for agent promotion
 }
 
 #[hdk_extern]
@@ -126,6 +263,10 @@ pub fn log_initial_transfer(
     note: Some("First resource transfer by Simple Agent".to_string()),
     commitment_hash: None, // Initial transfers don't typically have commitments
     generate_pprs: Some(true), // Always generate PPRs for initial transfers
+    input_of: None,
+    to_location: None,
+    resource_unit: None,
+    duration_hours: None,
   };
 
   let result = log_economic_event(event_input)?;
@@ -146,13 +287,28 @@ pub fn log_initial_transfer(
 
 #[hdk_extern]
 pub fn get_all_economic_events(_: ()) -> ExternResult<Vec<EconomicEvent>> {
-  let path = Path::from("all_economic_events");
-  let anchor_hash = path.path_entry_hash()?;
+  resolve_economic_event_links(all_economic_event_links()?)
+}
 
-  let links = get_links(
-    LinkQuery::try_new(anchor_hash, LinkTypes::AllEconomicEvents)?,
-    GetStrategy::default(),
-  )?;
+/// One page of `get_all_economic_events`, in link order.
+#[hdk_extern]
+pub fn get_all_economic_events_paginated(page: PageRequest) -> ExternResult<Page<EconomicEvent>> {
+  let (links, next_cursor) = page.select(all_economic_event_links()?);
+  Ok(Page {
+    items: resolve_economic_event_links(links)?,
+    next_cursor,
+  })
+}
+
+fn all_economic_event_links() -> ExternResult<Vec<Link>> {
+  get_month_sharded_links(
+    "all_economic_events",
+    LinkTypes::AllEconomicEvents,
+    LinkTypes::EconomicEventMonths,
+  )
+}
+
+fn resolve_economic_event_links(links: Vec<Link>) -> ExternResult<Vec<EconomicEvent>> {
   let mut events = Vec::new();
 
   for link in links {
@@ -174,10 +330,14 @@ pub fn get_all_economic_events(_: ()) -> ExternResult<Vec<EconomicEvent>> {
   Ok(events)
 }
 
+/// Events of a resource; `resource_hash` may be any version of it.
 #[hdk_extern]
 pub fn get_events_for_resource(resource_hash: ActionHash) -> ExternResult<Vec<EconomicEvent>> {
+  let Some(original_hash) = get_original_action_hash(resource_hash)? else {
+    return Ok(Vec::new());
+  };
   let links = get_links(
-    LinkQuery::try_new(resource_hash, LinkTypes::ResourceToEvent)?,
+    LinkQuery::try_new(original_hash, LinkTypes::ResourceToEvent)?,
     GetStrategy::default(),
   )?;
   let mut events = Vec::new();
@@ -5,7 +5,10 @@ use zome_gouvernance_integrity::*;
 pub mod commitment;
 pub mod economic_event;
 pub mod ppr;
+pub mod ppr_countersigning;
 pub mod private_data_validation;
+pub mod process;
+pub mod rule_engine;
 pub mod validation;
 // NDO federation extensions (issue #100)
 pub mod agreement;
@@ -15,7 +18,10 @@ pub mod hard_link;
 pub use commitment::*;
 pub use economic_event::*;
 pub use ppr::*;
+pub use ppr_countersigning::*;
 pub use private_data_validation::*;
+pub use process::*;
+pub use rule_engine::*;
 pub use validation::*;
 pub use agreement::*;
 pub use contribution::*;
@@ -34,6 +40,7 @@ pub enum Signal {
 
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+  grant_countersigning_capability()?;
   Ok(InitCallbackResult::Pass)
 }
 
@@ -82,7 +89,7 @@ fn generate_promotion_validation_pprs(
   validator_agent: AgentPubKey,
   resource_hash: ActionHash,
   validation_hash: ActionHash,
-) -> ExternResult<IssueParticipationReceiptsOutput> {
+) -> ExternResult<IssueParticipationReceiptsOutcome> {
   // Create appropriate claim types for agent promotion
   let claim_types = vec![
     ParticipationClaimType::ResourceValidation, // Validator gets this
@@ -121,6 +128,10 @@ fn get_entry_for_action(action_hash: &ActionHash) -> ExternResult<Option<EntryTy
       return Ok(None);
     }
   };
+  entry_of_record(&record)
+}
+
+fn entry_of_record(record: &Record) -> ExternResult<Option<EntryTypes>> {
   let entry = match record.entry().as_option() {
     Some(entry) => entry,
     None => {
@@ -1,3 +1,4 @@
+use crate::ppr_countersigning::*;
 use crate::GovernanceError;
 use blake3::Hasher;
 use hdk::ed25519::{sign, verify_signature};
@@ -8,18 +9,8 @@ use zome_gouvernance_integrity::*;
 // PPR Core Data Structures for Input/Output
 // ============================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct IssueParticipationReceiptsInput {
-  pub fulfills: ActionHash,     // Commitment that was fulfilled
-  pub fulfilled_by: ActionHash, // Economic event that fulfilled it
-  pub provider: AgentPubKey,    // Agent who provided/performed the action
-  pub receiver: AgentPubKey,    // Agent who received/benefited from the action
-  pub claim_types: Vec<ParticipationClaimType>, // Types of claims to generate
-  pub provider_metrics: PerformanceMetrics, // Performance metrics for provider
-  pub receiver_metrics: PerformanceMetrics, // Performance metrics for receiver
-  pub resource_hash: Option<ActionHash>, // Optional resource involved
-  pub notes: Option<String>,
-}
+/// The PPR pair's terms, as signed by both parties
+pub type IssueParticipationReceiptsInput = PprTerms;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct IssueParticipationReceiptsOutput {
@@ -29,6 +20,15 @@ pub struct IssueParticipationReceiptsOutput {
   pub receiver_claim: PrivateParticipationClaim,
 }
 
+/// Result of `issue_participation_receipts`: either both claims were committed with
+/// both signatures, or the counterparty was unreachable and a signature request was filed
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum IssueParticipationReceiptsOutcome {
+  Issued(IssueParticipationReceiptsOutput),
+  AwaitingCounterpartySignature { request_hash: ActionHash },
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SignParticipationClaimInput {
   pub data_to_sign: Vec<u8>,     // Data to be signed
@@ -80,13 +80,16 @@ pub struct DeriveReputationSummaryOutput {
 // ============================================================================
 
 /// Issue bi-directional Private Participation Receipts for an economic interaction
-/// This is the main function that generates exactly 2 PPRs per interaction
+/// This is the main function that generates exactly 2 PPRs per interaction.
+///
+/// Both parties sign the same `signed_data_hash` before either claim is committed: the
+/// calling agent signs locally and asks the counterparty to countersign over a remote call.
+/// If the counterparty cannot be reached (or declines), nothing is committed except a
+/// PprSignatureRequest in their inbox; see `ppr_countersigning`.
 #[hdk_extern]
 pub fn issue_participation_receipts(
   input: IssueParticipationReceiptsInput,
-) -> ExternResult<IssueParticipationReceiptsOutput> {
-  let _agent_info = agent_info()?;
-
+) -> ExternResult<IssueParticipationReceiptsOutcome> {
   // Validate that we have exactly 2 claim types for bi-directional issuance
   if input.claim_types.len() != 2 {
     return Err(
@@ -107,100 +110,108 @@ pub fn issue_participation_receipts(
     .validate()
     .map_err(|e| GovernanceError::InvalidInput(format!("Receiver metrics invalid: {}", e)))?;
 
-  // Create signed data for cryptographic signing
-  let signing_data = create_signing_data(&input)?;
-  let signed_data_hash = create_secure_hash(&signing_data)?;
-
-  let now = sys_time()?;
-
   // Get the calling agent (who is creating these PPRs)
   let calling_agent = agent_info()?.agent_initial_pubkey;
-
-  // DEBUG: Log agent information
-  debug!("Calling agent: {:?}", calling_agent);
-  debug!("Provider: {:?}", input.provider);
-  debug!("Receiver: {:?}", input.receiver);
-
-  // Create signing context for the calling agent
-  let calling_agent_signing_data = if calling_agent == input.provider {
-    debug!("Calling agent is the provider");
-    create_provider_signing_context(&input, &signing_data)?
-  } else if calling_agent == input.receiver {
-    debug!("Calling agent is the receiver");
-    create_receiver_signing_context(&input, &signing_data)?
-  } else {
+  if calling_agent != input.provider && calling_agent != input.receiver {
     return Err(GovernanceError::InvalidInput(
             format!("Calling agent must be either provider or receiver. Calling: {:?}, Provider: {:?}, Receiver: {:?}",
                 calling_agent, input.provider, input.receiver)
         ).into());
-  };
+  }
 
-  // Sign data with calling agent's key (only the calling agent can sign)
-  debug!("About to sign with calling agent key");
-  let calling_agent_signature = sign(calling_agent.clone(), calling_agent_signing_data)?;
-  debug!("Successfully signed with calling agent key");
-
-  // For now, we'll use a placeholder for the other party's signature
-  // In a production system, the other party would need to call a separate function to add their signature
-  let placeholder_signature = Signature([0u8; 64]);
-
-  // Create cryptographic signature structures based on who is calling
-  let (provider_signature, receiver_signature) = if calling_agent == input.provider {
-    // Provider is signing
-    let provider_sig = CryptographicSignature::new(
-      calling_agent_signature.clone(),
-      placeholder_signature.clone(),
-      signed_data_hash,
-      now,
-    );
-    let receiver_sig = CryptographicSignature::new(
-      placeholder_signature.clone(),
-      calling_agent_signature.clone(),
-      signed_data_hash,
-      now,
+  // A receipt pair needs two distinct signatures
+  if input.provider == input.receiver {
+    return Err(
+      GovernanceError::InvalidInput(
+        "Provider and receiver must be different agents to issue PPRs".to_string(),
+      )
+      .into(),
     );
-    (provider_sig, receiver_sig)
+  }
+
+  // Create signed data for cryptographic signing
+  let requested_at = sys_time()?;
+  let signing_data = ppr_signing_data(&input, requested_at);
+  let signed_data_hash = create_secure_hash(&signing_data)?;
+
+  let calling_agent_signature = sign(calling_agent.clone(), signed_data_hash.to_vec())?;
+
+  let counterparty = if calling_agent == input.provider {
+    input.receiver.clone()
   } else {
-    // Receiver is signing
-    let provider_sig = CryptographicSignature::new(
-      placeholder_signature.clone(),
-      calling_agent_signature.clone(),
-      signed_data_hash,
-      now,
-    );
-    let receiver_sig = CryptographicSignature::new(
-      calling_agent_signature.clone(),
-      placeholder_signature.clone(),
-      signed_data_hash,
-      now,
-    );
-    (provider_sig, receiver_sig)
+    input.provider.clone()
   };
 
-  // Create the provider's PPR claim
+  match request_countersignature(
+    &counterparty,
+    CountersignParticipationReceiptsInput {
+      terms: input.clone(),
+      requested_at,
+      initiator_signature: calling_agent_signature.clone(),
+      // Usually committed in this same call, so not yet visible to the counterparty
+      fulfilling_event: get(input.fulfilled_by.clone(), GetOptions::default())?,
+    },
+    signed_data_hash,
+  )? {
+    Some(counterparty_signature) => {
+      let (provider_signature, receiver_signature) = if calling_agent == input.provider {
+        (calling_agent_signature, counterparty_signature)
+      } else {
+        (counterparty_signature, calling_agent_signature)
+      };
+      let output = commit_participation_receipts(
+        input,
+        provider_signature,
+        receiver_signature,
+        signed_data_hash,
+        requested_at,
+      )?;
+      Ok(IssueParticipationReceiptsOutcome::Issued(output))
+    }
+    None => {
+      let request_hash = file_ppr_signature_request(
+        input,
+        &counterparty,
+        signed_data_hash,
+        calling_agent_signature,
+        requested_at,
+      )?;
+      Ok(IssueParticipationReceiptsOutcome::AwaitingCounterpartySignature { request_hash })
+    }
+  }
+}
+
+/// Commit both PPR claims once the provider and receiver signatures over
+/// `signed_data_hash` are in hand, and create their discovery links
+pub(crate) fn commit_participation_receipts(
+  input: IssueParticipationReceiptsInput,
+  provider_signature: Signature,
+  receiver_signature: Signature,
+  signed_data_hash: [u8; 32],
+  requested_at: Timestamp,
+) -> ExternResult<IssueParticipationReceiptsOutput> {
+  let now = sys_time()?;
+
+  // Each claim's recipient_signature is its owner's; counterparty_signature is the other party's
   let provider_claim = PrivateParticipationClaim::new(
-    input.fulfills.clone(),
-    input.fulfilled_by.clone(),
-    input.claim_types[0].clone(),
-    input.provider_metrics,
-    provider_signature,
-    input.receiver.clone(),
-    input.resource_hash.clone(),
-    input.notes.clone(),
+    &input,
+    true,
+    CryptographicSignature::new(
+      provider_signature.clone(),
+      receiver_signature.clone(),
+      signed_data_hash,
+      now,
+    ),
+    requested_at,
     now,
   )
   .map_err(|e| GovernanceError::InvalidInput(e))?;
 
-  // Create the receiver's PPR claim
   let receiver_claim = PrivateParticipationClaim::new(
-    input.fulfills.clone(),
-    input.fulfilled_by.clone(),
-    input.claim_types[1].clone(),
-    input.receiver_metrics,
-    receiver_signature,
-    input.provider.clone(),
-    input.resource_hash.clone(),
-    input.notes.clone(),
+    &input,
+    false,
+    CryptographicSignature::new(receiver_signature, provider_signature, signed_data_hash, now),
+    requested_at,
     now,
   )
   .map_err(|e| GovernanceError::InvalidInput(e))?;
@@ -489,36 +500,8 @@ fn create_claim_links(
   Ok(())
 }
 
-/// Create signing data from the input parameters
-fn create_signing_data(input: &IssueParticipationReceiptsInput) -> ExternResult<Vec<u8>> {
-  // Create a consistent data structure for signing by concatenating serialized components
-  let mut signing_data = Vec::new();
-
-  // Serialize each component individually since complex tuple serialization isn't supported
-  signing_data.extend_from_slice(&input.fulfills.get_raw_39());
-  signing_data.extend_from_slice(&input.fulfilled_by.get_raw_39());
-  signing_data.extend_from_slice(&input.provider.get_raw_39());
-  signing_data.extend_from_slice(&input.receiver.get_raw_39());
-
-  // Serialize claim types as strings
-  for claim_type in &input.claim_types {
-    signing_data.extend_from_slice(format!("{:?}", claim_type).as_bytes());
-  }
-
-  // Add resource hash if present
-  if let Some(ref resource_hash) = input.resource_hash {
-    signing_data.extend_from_slice(&resource_hash.get_raw_39());
-  }
-
-  // Add timestamp for uniqueness
-  let timestamp = sys_time()?;
-  signing_data.extend_from_slice(&timestamp.as_micros().to_le_bytes());
-
-  Ok(signing_data)
-}
-
 /// Create a cryptographically secure hash using BLAKE3
-fn create_secure_hash(data: &[u8]) -> ExternResult<[u8; 32]> {
+pub(crate) fn create_secure_hash(data: &[u8]) -> ExternResult<[u8; 32]> {
   // Use BLAKE3 for cryptographically secure hashing (32 bytes output)
   let mut hasher = Hasher::new();
   hasher.update(data);
@@ -530,56 +513,6 @@ fn create_secure_hash(data: &[u8]) -> ExternResult<[u8; 32]> {
   Ok(*hash_array)
 }
 
-/// Create provider-specific signing context for bilateral authentication
-fn create_provider_signing_context(
-  input: &IssueParticipationReceiptsInput,
-  base_data: &[u8],
-) -> ExternResult<Vec<u8>> {
-  let mut context_data = Vec::new();
-
-  // Add role identifier
-  context_data.extend_from_slice(b"PROVIDER_PPR_SIGNATURE");
-
-  // Add base signing data
-  context_data.extend_from_slice(base_data);
-
-  // Add provider-specific context
-  context_data.extend_from_slice(&input.provider.get_raw_39());
-  context_data.extend_from_slice(&input.receiver.get_raw_39());
-
-  // Add claim types for provider
-  if !input.claim_types.is_empty() {
-    context_data.extend_from_slice(format!("{:?}", input.claim_types[0]).as_bytes());
-  }
-
-  Ok(context_data)
-}
-
-/// Create receiver-specific signing context for bilateral authentication
-fn create_receiver_signing_context(
-  input: &IssueParticipationReceiptsInput,
-  base_data: &[u8],
-) -> ExternResult<Vec<u8>> {
-  let mut context_data = Vec::new();
-
-  // Add role identifier
-  context_data.extend_from_slice(b"RECEIVER_PPR_SIGNATURE");
-
-  // Add base signing data
-  context_data.extend_from_slice(base_data);
-
-  // Add receiver-specific context
-  context_data.extend_from_slice(&input.receiver.get_raw_39());
-  context_data.extend_from_slice(&input.provider.get_raw_39());
-
-  // Add claim types for receiver
-  if input.claim_types.len() > 1 {
-    context_data.extend_from_slice(format!("{:?}", input.claim_types[1]).as_bytes());
-  }
-
-  Ok(context_data)
-}
-
 /// Create bilateral signing context for general participation claim signing
 fn create_bilateral_signing_context(
   data: &[u8],
@@ -628,7 +561,7 @@ pub fn generate_pprs_for_economic_event(
   event: &EconomicEvent,
   commitment_hash: ActionHash,
   event_hash: ActionHash,
-) -> ExternResult<IssueParticipationReceiptsOutput> {
+) -> ExternResult<IssueParticipationReceiptsOutcome> {
   // Determine claim types based on the VfAction
   let claim_types = determine_claim_types_for_action(&event.action)?;
 
@@ -700,7 +633,7 @@ pub fn create_service_commitment_pprs(
   provider: AgentPubKey,
   receiver: AgentPubKey,
   resource_hash: Option<ActionHash>,
-) -> ExternResult<IssueParticipationReceiptsOutput> {
+) -> ExternResult<IssueParticipationReceiptsOutcome> {
   let claim_types = match service_type {
     "maintenance" => vec![
       ParticipationClaimType::MaintenanceCommitmentAccepted,
@@ -746,7 +679,7 @@ pub fn create_service_fulfillment_pprs(
   provider: AgentPubKey,
   receiver: AgentPubKey,
   resource_hash: Option<ActionHash>,
-) -> ExternResult<IssueParticipationReceiptsOutput> {
+) -> ExternResult<IssueParticipationReceiptsOutcome> {
   let claim_types = match service_type {
     "maintenance" => vec![
       ParticipationClaimType::MaintenanceFulfillmentCompleted,
@@ -0,0 +1,543 @@
+use crate::ppr::*;
+use crate::GovernanceError;
+use hdk::ed25519::{sign, verify_signature};
+use hdk::prelude::*;
+use zome_gouvernance_integrity::*;
+
+// ============================================================================
+// Bilateral PPR signing
+// ============================================================================
+//
+// Both parties sign the same `signed_data_hash` before either PrivateParticipationClaim
+// is committed:
+// 1. The initiator signs and calls `countersign_participation_receipts` on the
+//    counterparty's cell (open to everyone via the cap grant created in `init`).
+// 2. If that call fails (counterparty offline or refusing), the initiator files a public
+//    PprSignatureRequest linked into the counterparty's inbox instead. Its metrics and notes
+//    stay in a private PprPrivateTerms entry on the initiator's chain.
+// 3. The counterparty finds it with `get_pending_ppr_signature_requests`, fetches the private
+//    terms from the initiator with `get_ppr_signature_request_terms` (which calls
+//    `share_ppr_signature_request_terms` on the initiator's cell), and signs with
+//    `sign_ppr_signature_request`, committing a PprSignatureResponse.
+// 4. The initiator calls `finalize_participation_receipts` to commit both claims.
+
+pub const COUNTERSIGN_FN_NAME: &str = "countersign_participation_receipts";
+pub const SHARE_TERMS_FN_NAME: &str = "share_ppr_signature_request_terms";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CountersignParticipationReceiptsInput {
+  pub terms: IssueParticipationReceiptsInput,
+  pub requested_at: Timestamp,
+  pub initiator_signature: Signature,
+  #[serde(default)]
+  pub fulfilling_event: Option<Record>, // Signed record of `terms.fulfilled_by`
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PprSignatureRequestRecord {
+  pub request_hash: ActionHash,
+  pub initiator: AgentPubKey,
+  pub request: PprSignatureRequest,
+  pub response_hash: Option<ActionHash>, // Set once the counterparty has signed
+}
+
+/// Grant every agent access to `countersign_participation_receipts` and
+/// `share_ppr_signature_request_terms`; called from `init`. The functions themselves only
+/// serve the caller's own receipts.
+pub fn grant_countersigning_capability() -> ExternResult<()> {
+  let zome_name = zome_info()?.name;
+  create_cap_grant(ZomeCallCapGrant {
+    tag: "ppr_countersigning".to_string(),
+    access: CapAccess::Unrestricted,
+    functions: GrantedFunctions::Listed(HashSet::from([
+      (zome_name.clone(), FunctionName::from(COUNTERSIGN_FN_NAME)),
+      (zome_name, FunctionName::from(SHARE_TERMS_FN_NAME)),
+    ])),
+  })?;
+  Ok(())
+}
+
+/// Remote-callable: countersign a PPR pair the calling agent is issuing with us.
+/// Rebuilds the signing data from the terms, so we only ever sign what we can read, and
+/// signs unattended only for a fulfilling EconomicEvent we can see and are a party to;
+/// otherwise the initiator falls back to a PprSignatureRequest for us to review.
+#[hdk_extern]
+pub fn countersign_participation_receipts(
+  input: CountersignParticipationReceiptsInput,
+) -> ExternResult<Signature> {
+  let initiator = call_info()?.provenance;
+  let me = agent_info()?.agent_initial_pubkey;
+
+  let terms = &input.terms;
+  let expected_counterparty = if initiator == terms.provider {
+    &terms.receiver
+  } else if initiator == terms.receiver {
+    &terms.provider
+  } else {
+    return Err(
+      GovernanceError::NotAuthorized("Caller is not a party to these receipts".to_string()).into(),
+    );
+  };
+  if *expected_counterparty != me {
+    return Err(
+      GovernanceError::NotAuthorized("We are not a party to these receipts".to_string()).into(),
+    );
+  }
+  check_receipt_terms(terms)?;
+  let Some(event) = fulfilling_event(&terms.fulfilled_by, input.fulfilling_event)? else {
+    return Err(
+      GovernanceError::InvalidInput("Fulfilling economic event is not visible to us".to_string())
+        .into(),
+    );
+  };
+  if event.provider != me && event.receiver != me {
+    return Err(
+      GovernanceError::NotAuthorized(
+        "We are not a party to the fulfilling economic event".to_string(),
+      )
+      .into(),
+    );
+  }
+
+  let signed_data_hash = create_secure_hash(&ppr_signing_data(terms, input.requested_at))?;
+  if !verify_signature(
+    initiator,
+    input.initiator_signature,
+    signed_data_hash.to_vec(),
+  )? {
+    return Err(
+      GovernanceError::InvalidInput("Initiator signature does not verify".to_string()).into(),
+    );
+  }
+
+  sign(me, signed_data_hash.to_vec())
+}
+
+/// Ask the counterparty to countersign over a remote call. Returns `None` when they cannot
+/// be reached or refuse, so the caller can fall back to a PprSignatureRequest.
+pub(crate) fn request_countersignature(
+  counterparty: &AgentPubKey,
+  input: CountersignParticipationReceiptsInput,
+  signed_data_hash: [u8; 32],
+) -> ExternResult<Option<Signature>> {
+  let response = call_remote(
+    counterparty.clone(),
+    zome_info()?.name,
+    FunctionName::from(COUNTERSIGN_FN_NAME),
+    None,
+    &input,
+  )?;
+
+  let signature: Signature = match response {
+    ZomeCallResponse::Ok(result) => result
+      .decode()
+      .map_err(|e| GovernanceError::SerializationError(format!("{:?}", e)))?,
+    other => {
+      debug!("Counterparty {} did not countersign: {:?}", counterparty, other);
+      return Ok(None);
+    }
+  };
+
+  if !verify_signature(counterparty.clone(), signature.clone(), signed_data_hash.to_vec())? {
+    return Err(
+      GovernanceError::InvalidInput("Counterparty signature does not verify".to_string()).into(),
+    );
+  }
+  Ok(Some(signature))
+}
+
+/// Commit a PprSignatureRequest into the counterparty's inbox, keeping its metrics and notes
+/// in a private PprPrivateTerms entry
+pub(crate) fn file_ppr_signature_request(
+  input: IssueParticipationReceiptsInput,
+  counterparty: &AgentPubKey,
+  signed_data_hash: [u8; 32],
+  initiator_signature: Signature,
+  requested_at: Timestamp,
+) -> ExternResult<ActionHash> {
+  create_entry(&EntryTypes::PprPrivateTerms(PprPrivateTerms {
+    signed_data_hash,
+    provider_metrics: input.provider_metrics,
+    receiver_metrics: input.receiver_metrics,
+    notes: input.notes,
+  }))?;
+  let request = PprSignatureRequest {
+    fulfills: input.fulfills,
+    fulfilled_by: input.fulfilled_by,
+    provider: input.provider,
+    receiver: input.receiver,
+    claim_types: input.claim_types,
+    resource_hash: input.resource_hash,
+    signed_data_hash,
+    initiator_signature,
+    requested_at,
+  };
+  let request_hash = create_entry(&EntryTypes::PprSignatureRequest(request))?;
+
+  create_link(
+    counterparty.clone(),
+    request_hash.clone(),
+    LinkTypes::AgentToPendingPprSignatures,
+    (),
+  )?;
+  create_link(
+    agent_info()?.agent_initial_pubkey,
+    request_hash.clone(),
+    LinkTypes::AgentToOutgoingPprRequests,
+    (),
+  )?;
+
+  Ok(request_hash)
+}
+
+/// PPR signature requests waiting for the calling agent's countersignature
+#[hdk_extern]
+pub fn get_pending_ppr_signature_requests(_: ()) -> ExternResult<Vec<PprSignatureRequestRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(
+      agent_info()?.agent_initial_pubkey,
+      LinkTypes::AgentToPendingPprSignatures,
+    )?,
+    GetStrategy::default(),
+  )?;
+  let requests = resolve_ppr_signature_requests(links)?;
+  Ok(
+    requests
+      .into_iter()
+      .filter(|r| r.response_hash.is_none())
+      .collect(),
+  )
+}
+
+/// PPR signature requests the calling agent has filed and not yet finalized
+#[hdk_extern]
+pub fn get_outgoing_ppr_signature_requests(
+  _: (),
+) -> ExternResult<Vec<PprSignatureRequestRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(
+      agent_info()?.agent_initial_pubkey,
+      LinkTypes::AgentToOutgoingPprRequests,
+    )?,
+    GetStrategy::default(),
+  )?;
+  let mut pending = Vec::new();
+  for record in resolve_ppr_signature_requests(links)? {
+    if !is_finalized(&record.request_hash)? {
+      pending.push(record);
+    }
+  }
+  Ok(pending)
+}
+
+/// Remote-callable: the metrics and notes of a PprSignatureRequest we filed, for its
+/// counterparty only
+#[hdk_extern]
+pub fn share_ppr_signature_request_terms(
+  request_hash: ActionHash,
+) -> ExternResult<PprPrivateTerms> {
+  let caller = call_info()?.provenance;
+  let record = get_ppr_signature_request(&request_hash)?;
+
+  if record.initiator != agent_info()?.agent_initial_pubkey
+    || record.request.counterparty_of(&record.initiator) != Some(caller)
+  {
+    return Err(
+      GovernanceError::NotAuthorized(
+        "Only the requested counterparty can read these terms".to_string(),
+      )
+      .into(),
+    );
+  }
+  private_terms(&record.request.signed_data_hash)
+}
+
+/// The full terms of a request in our inbox, fetched from its initiator and checked against
+/// the `signed_data_hash` they signed
+#[hdk_extern]
+pub fn get_ppr_signature_request_terms(
+  request_hash: ActionHash,
+) -> ExternResult<IssueParticipationReceiptsInput> {
+  let record = get_ppr_signature_request(&request_hash)?;
+  if record.request.counterparty_of(&record.initiator) != Some(agent_info()?.agent_initial_pubkey)
+  {
+    return Err(
+      GovernanceError::NotAuthorized(
+        "Only the requested counterparty can read these terms".to_string(),
+      )
+      .into(),
+    );
+  }
+  fetch_request_terms(&record)
+}
+
+/// Countersign a request from the inbox; returns the PprSignatureResponse hash
+#[hdk_extern]
+pub fn sign_ppr_signature_request(request_hash: ActionHash) -> ExternResult<ActionHash> {
+  let me = agent_info()?.agent_initial_pubkey;
+  let record = get_ppr_signature_request(&request_hash)?;
+
+  if record.request.counterparty_of(&record.initiator) != Some(me.clone()) {
+    return Err(
+      GovernanceError::NotAuthorized(
+        "Only the requested counterparty can sign this request".to_string(),
+      )
+      .into(),
+    );
+  }
+  if record.response_hash.is_some() {
+    return Err(GovernanceError::InvalidInput("Request is already signed".to_string()).into());
+  }
+  // Sign only the hash of terms we have read
+  let terms = fetch_request_terms(&record)?;
+  check_receipt_terms(&terms)?;
+
+  let counterparty_signature = sign(me, record.request.signed_data_hash.to_vec())?;
+  let response_hash = create_entry(&EntryTypes::PprSignatureResponse(PprSignatureResponse {
+    request_hash: request_hash.clone(),
+    counterparty_signature,
+    signed_at: sys_time()?,
+  }))?;
+
+  create_link(
+    request_hash,
+    response_hash.clone(),
+    LinkTypes::PprRequestToResponse,
+    (),
+  )?;
+
+  Ok(response_hash)
+}
+
+/// Commit both claims for a request the counterparty has signed
+#[hdk_extern]
+pub fn finalize_participation_receipts(
+  request_hash: ActionHash,
+) -> ExternResult<IssueParticipationReceiptsOutput> {
+  let me = agent_info()?.agent_initial_pubkey;
+  let record = get_ppr_signature_request(&request_hash)?;
+
+  if record.initiator != me {
+    return Err(
+      GovernanceError::NotAuthorized("Only the initiator can finalize these receipts".to_string())
+        .into(),
+    );
+  }
+  if is_finalized(&request_hash)? {
+    return Err(
+      GovernanceError::InvalidInput("Receipts have already been finalized".to_string()).into(),
+    );
+  }
+  let response_hash = record.response_hash.clone().ok_or(GovernanceError::InvalidInput(
+    "Counterparty has not signed yet".to_string(),
+  ))?;
+  let response_record = get(response_hash, GetOptions::default())?.ok_or(
+    GovernanceError::EntryOperationFailed("PprSignatureResponse not found".to_string()),
+  )?;
+  let response: PprSignatureResponse = response_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| GovernanceError::SerializationError(format!("{:?}", e)))?
+    .ok_or(GovernanceError::SerializationError(
+      "Invalid PprSignatureResponse entry".to_string(),
+    ))?;
+
+  let request = record.request;
+  let (provider_signature, receiver_signature) = if me == request.provider {
+    (request.initiator_signature.clone(), response.counterparty_signature)
+  } else {
+    (response.counterparty_signature, request.initiator_signature.clone())
+  };
+
+  let private = private_terms(&request.signed_data_hash)?;
+  let output = commit_participation_receipts(
+    request.terms(private),
+    provider_signature,
+    receiver_signature,
+    request.signed_data_hash,
+    request.requested_at,
+  )?;
+
+  // Marks the request as finalized
+  create_link(
+    request_hash,
+    output.provider_claim_hash.clone(),
+    LinkTypes::PprRequestToClaims,
+    (),
+  )?;
+
+  Ok(output)
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// The PprPrivateTerms we kept for the request signing `signed_data_hash`
+fn private_terms(signed_data_hash: &[u8; 32]) -> ExternResult<PprPrivateTerms> {
+  let records = query(
+    ChainQueryFilter::new()
+      .entry_type(UnitEntryTypes::PprPrivateTerms.try_into()?)
+      .include_entries(true),
+  )?;
+  for record in records {
+    if let Ok(Some(terms)) = record.entry().to_app_option::<PprPrivateTerms>() {
+      if terms.signed_data_hash == *signed_data_hash {
+        return Ok(terms);
+      }
+    }
+  }
+  Err(GovernanceError::EntryOperationFailed("PprPrivateTerms not found".to_string()).into())
+}
+
+/// Ask the initiator of a request in our inbox for its private terms, and complete the
+/// request's terms with them once they match its `signed_data_hash`
+fn fetch_request_terms(
+  record: &PprSignatureRequestRecord,
+) -> ExternResult<IssueParticipationReceiptsInput> {
+  let response = call_remote(
+    record.initiator.clone(),
+    zome_info()?.name,
+    FunctionName::from(SHARE_TERMS_FN_NAME),
+    None,
+    &record.request_hash,
+  )?;
+  let private: PprPrivateTerms = match response {
+    ZomeCallResponse::Ok(result) => result
+      .decode()
+      .map_err(|e| GovernanceError::SerializationError(format!("{:?}", e)))?,
+    other => {
+      return Err(
+        GovernanceError::EntryOperationFailed(format!(
+          "Could not get the request's terms from its initiator: {:?}",
+          other
+        ))
+        .into(),
+      )
+    }
+  };
+
+  let terms = record.request.terms(private);
+  let signed_data_hash =
+    create_secure_hash(&ppr_signing_data(&terms, record.request.requested_at))?;
+  if signed_data_hash != record.request.signed_data_hash {
+    return Err(
+      GovernanceError::InvalidInput(
+        "signed_data_hash does not match the request's terms".to_string(),
+      )
+      .into(),
+    );
+  }
+  Ok(terms)
+}
+
+/// Sanity checks the counterparty applies before signing: well-formed claim pair, and if the
+/// fulfilling EconomicEvent is already visible, it must name the same two parties.
+fn check_receipt_terms(terms: &IssueParticipationReceiptsInput) -> ExternResult<()> {
+  if terms.claim_types.len() != 2 {
+    return Err(
+      GovernanceError::InvalidInput("Receipts must carry exactly 2 claim types".to_string())
+        .into(),
+    );
+  }
+  if let Some(EntryTypes::EconomicEvent(event)) = crate::get_entry_for_action(&terms.fulfilled_by)? {
+    if event.provider != terms.provider || event.receiver != terms.receiver {
+      return Err(
+        GovernanceError::InvalidInput(
+          "Receipt parties do not match the fulfilling economic event".to_string(),
+        )
+        .into(),
+      );
+    }
+  }
+  Ok(())
+}
+
+/// The EconomicEvent `fulfilled_by` refers to, from the record the initiator sent (it may not
+/// be published yet) or else from the DHT. A sent record must be that very action, signed by
+/// its author.
+fn fulfilling_event(
+  fulfilled_by: &ActionHash,
+  record: Option<Record>,
+) -> ExternResult<Option<EconomicEvent>> {
+  let record = match record {
+    Some(record) => {
+      let entry_hash = match record.entry().as_option() {
+        Some(entry) => Some(hash_entry(entry.clone())?),
+        None => None,
+      };
+      if hash_action(record.action().clone())? != *fulfilled_by
+        || !verify_signature(
+          record.action().author().clone(),
+          record.signature().clone(),
+          record.action(),
+        )?
+        || entry_hash.as_ref() != record.action().entry_hash()
+      {
+        return Err(
+          GovernanceError::InvalidInput(
+            "Fulfilling economic event record is not authentic".to_string(),
+          )
+          .into(),
+        );
+      }
+      record
+    }
+    None => match get(fulfilled_by.clone(), GetOptions::default())? {
+      Some(record) => record,
+      None => return Ok(None),
+    },
+  };
+  match crate::entry_of_record(&record)? {
+    Some(EntryTypes::EconomicEvent(event)) => Ok(Some(event)),
+    _ => Ok(None),
+  }
+}
+
+fn get_ppr_signature_request(request_hash: &ActionHash) -> ExternResult<PprSignatureRequestRecord> {
+  let record = get(request_hash.clone(), GetOptions::default())?.ok_or(
+    GovernanceError::EntryOperationFailed("PprSignatureRequest not found".to_string()),
+  )?;
+  let request: PprSignatureRequest = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| GovernanceError::SerializationError(format!("{:?}", e)))?
+    .ok_or(GovernanceError::SerializationError(
+      "Invalid PprSignatureRequest entry".to_string(),
+    ))?;
+
+  let responses = get_links(
+    LinkQuery::try_new(request_hash.clone(), LinkTypes::PprRequestToResponse)?,
+    GetStrategy::default(),
+  )?;
+
+  Ok(PprSignatureRequestRecord {
+    request_hash: request_hash.clone(),
+    initiator: record.action().author().clone(),
+    request,
+    response_hash: responses
+      .into_iter()
+      .find_map(|link| link.target.into_action_hash()),
+  })
+}
+
+fn is_finalized(request_hash: &ActionHash) -> ExternResult<bool> {
+  let claims = get_links(
+    LinkQuery::try_new(request_hash.clone(), LinkTypes::PprRequestToClaims)?,
+    GetStrategy::default(),
+  )?;
+  Ok(!claims.is_empty())
+}
+
+fn resolve_ppr_signature_requests(links: Vec<Link>) -> ExternResult<Vec<PprSignatureRequestRecord>> {
+  let mut requests = Vec::new();
+  for link in links {
+    let Some(request_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    if let Ok(record) = get_ppr_signature_request(&request_hash) {
+      requests.push(record);
+    }
+  }
+  Ok(requests)
+}
@@ -40,6 +40,17 @@ pub struct ValidateAgentForPromotionInput {
   pub grant_hash: Option<ActionHash>,
 }
 
+// Only the fields of the person zome's role registry records read here
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleDefinitionRecord {
+  pub definition: RoleDefinitionLevel,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleDefinitionLevel {
+  pub capability_level: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ValidateAgentForCustodianshipInput {
   pub target_agent: AgentPubKey,
@@ -183,10 +194,22 @@ pub fn validate_agent_for_promotion(
         "time_zone".to_string(),
       ]
     }
+    // Community roles from the registry require the fields of their capability level
     _ => {
-      return Err(
-        GovernanceError::InvalidInput(format!("Unknown role type: {}", input.target_role)).into(),
-      )
+      let record: Option<RoleDefinitionRecord> =
+        call_person_zome("get_role_definition", input.target_role.clone())?;
+      let Some(record) = record else {
+        return Err(
+          GovernanceError::InvalidInput(format!("Unknown role type: {}", input.target_role)).into(),
+        );
+      };
+      let fields: &[&str] = match record.definition.capability_level.as_str() {
+        "coordination" => &["email", "phone"],
+        "governance" => &["email", "phone", "location"],
+        "stewardship" => &["email", "phone", "location", "time_zone"],
+        _ => &["email"],
+      };
+      fields.iter().map(|field| field.to_string()).collect()
     }
   };
 
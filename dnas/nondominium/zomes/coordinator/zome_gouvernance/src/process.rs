@@ -0,0 +1,181 @@
+use crate::GovernanceError;
+use hdk::prelude::*;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use nondominium_shared::update_chain::get_latest_record;
+use zome_gouvernance_integrity::*;
+
+// ============================================================================
+// Process Management
+// ============================================================================
+//
+// A ValueFlows Process groups the Commitments, EconomicEvents and Contributions
+// that are `input_of` it. Processes are opened by any agent, accept inputs until
+// their creator finishes them, and are then read-only.
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OpenProcessInput {
+  pub name: String,
+  pub based_on: Option<ActionHash>,
+  pub note: Option<String>,
+  pub planned_start: Option<Timestamp>,
+  pub planned_end: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProcessRecord {
+  pub action_hash: ActionHash, // Original (open) action hash, stable across finishing
+  pub entry: Process,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ProcessInputs {
+  pub commitments: Vec<ActionHash>,
+  pub economic_events: Vec<ActionHash>,
+  pub contributions: Vec<ActionHash>,
+}
+
+#[hdk_extern]
+pub fn open_process(input: OpenProcessInput) -> ExternResult<ProcessRecord> {
+  let agent = agent_info()?.agent_initial_pubkey;
+
+  let process = Process {
+    name: input.name,
+    based_on: input.based_on,
+    note: input.note,
+    planned_start: input.planned_start,
+    planned_end: input.planned_end,
+    finished: false,
+    created_by: agent.clone(),
+    created_at: sys_time()?,
+  };
+
+  let action_hash = create_entry(&EntryTypes::Process(process.clone()))?;
+
+  create_hash_sharded_link("all_processes", action_hash.clone(), LinkTypes::AllProcesses)?;
+  create_link(agent, action_hash.clone(), LinkTypes::AgentToProcesses, ())?;
+
+  Ok(ProcessRecord {
+    action_hash,
+    entry: process,
+  })
+}
+
+/// Latest version of a Process, addressed by its original action hash.
+#[hdk_extern]
+pub fn get_process(process_hash: ActionHash) -> ExternResult<Option<ProcessRecord>> {
+  let Some(record) = get_latest_record(process_hash.clone())? else {
+    return Ok(None);
+  };
+  let Ok(Some(entry)) = record.entry().to_app_option::<Process>() else {
+    return Ok(None);
+  };
+  Ok(Some(ProcessRecord {
+    action_hash: process_hash,
+    entry,
+  }))
+}
+
+#[hdk_extern]
+pub fn get_all_processes(_: ()) -> ExternResult<Vec<ProcessRecord>> {
+  resolve_process_links(all_process_links()?)
+}
+
+/// One page of `get_all_processes`, in link order.
+#[hdk_extern]
+pub fn get_all_processes_paginated(page: PageRequest) -> ExternResult<Page<ProcessRecord>> {
+  let (links, next_cursor) = page.select(all_process_links()?);
+  Ok(Page {
+    items: resolve_process_links(links)?,
+    next_cursor,
+  })
+}
+
+fn all_process_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("all_processes", LinkTypes::AllProcesses)
+}
+
+#[hdk_extern]
+pub fn get_agent_processes(agent: AgentPubKey) -> ExternResult<Vec<ProcessRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(agent, LinkTypes::AgentToProcesses)?,
+    GetStrategy::default(),
+  )?;
+  resolve_process_links(links)
+}
+
+/// Hashes of the Commitments, EconomicEvents and Contributions that are inputs of a Process.
+#[hdk_extern]
+pub fn get_process_inputs(process_hash: ActionHash) -> ExternResult<ProcessInputs> {
+  let targets = |link_type: LinkTypes| -> ExternResult<Vec<ActionHash>> {
+    let links = get_links(
+      LinkQuery::try_new(process_hash.clone(), link_type)?,
+      GetStrategy::default(),
+    )?;
+    Ok(
+      links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .collect(),
+    )
+  };
+
+  Ok(ProcessInputs {
+    commitments: targets(LinkTypes::ProcessToCommitment)?,
+    economic_events: targets(LinkTypes::ProcessToEvent)?,
+    contributions: targets(LinkTypes::ProcessToContribution)?,
+  })
+}
+
+/// Mark a Process finished. Only its creator may do so, and only once.
+#[hdk_extern]
+pub fn finish_process(process_hash: ActionHash) -> ExternResult<ProcessRecord> {
+  let agent = agent_info()?.agent_initial_pubkey;
+  let process = ensure_open_process(&process_hash)?;
+  if process.created_by != agent {
+    return Err(
+      GovernanceError::NotAuthorized("only the Process creator can finish it".to_string()).into(),
+    );
+  }
+
+  let finished = Process {
+    finished: true,
+    ..process
+  };
+  let updated_hash = update_entry(process_hash.clone(), &EntryTypes::Process(finished.clone()))?;
+  create_link(
+    process_hash.clone(),
+    updated_hash,
+    LinkTypes::ProcessUpdates,
+    (),
+  )?;
+
+  Ok(ProcessRecord {
+    action_hash: process_hash,
+    entry: finished,
+  })
+}
+
+/// The latest Process at `process_hash`, failing if it is missing or already finished.
+pub(crate) fn ensure_open_process(process_hash: &ActionHash) -> ExternResult<Process> {
+  let process = get_process(process_hash.clone())?
+    .ok_or(GovernanceError::ProcessNotFound(process_hash.to_string()))?
+    .entry;
+  if process.finished {
+    return Err(GovernanceError::ProcessFinished(process_hash.to_string()).into());
+  }
+  Ok(process)
+}
+
+fn resolve_process_links(links: Vec<Link>) -> ExternResult<Vec<ProcessRecord>> {
+  let mut results = Vec::new();
+  for link in links {
+    let Some(action_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    if let Some(process) = get_process(action_hash)? {
+      results.push(process);
+    }
+  }
+  Ok(results)
+}
@@ -0,0 +1,117 @@
+use hdk::prelude::*;
+pub use nondominium_shared::io::governance::EvaluateGovernanceRulesInput;
+use nondominium_shared::rules::*;
+
+// ============================================================================
+// Governance rule engine
+// ============================================================================
+//
+// The "governance operator": given the GovernanceRules attached to a resource's
+// ResourceSpecification, the operation being attempted, and facts about the acting
+// agent, decide allow/deny. Evaluation is pure; callers (zome_resource) gather the
+// rules and context and enforce the decision before mutating state.
+
+#[hdk_extern]
+pub fn evaluate_governance_rules(
+  input: EvaluateGovernanceRulesInput,
+) -> ExternResult<GovernanceDecision> {
+  Ok(evaluate_rules(
+    &input.operation,
+    &input.rules,
+    &input.context,
+  ))
+}
+
+/// Evaluate every rule against the operation. Rules are validated at integrity time, so
+/// the typed parameters are trusted here; `Custom` rules are not enforceable and skipped,
+/// except under a known rule type (legacy data that failed to parse), which denies.
+pub fn evaluate_rules(
+  operation: &GovernedOperation,
+  rules: &[EvaluatedRule],
+  context: &RuleEvaluationContext,
+) -> GovernanceDecision {
+  let mut violations = Vec::new();
+
+  for rule in rules {
+    let parameters = rule.rule_data.parameters();
+    let reasons = match parameters {
+      RuleParameters::Custom(_) => match parameters.validate(&rule.rule_type) {
+        Ok(()) => continue,
+        Err(e) => vec![e],
+      },
+      _ => check_rule(parameters, operation, context),
+    };
+    violations.extend(reasons.into_iter().map(|reason| RuleViolation {
+      rule_hash: rule.rule_hash.clone(),
+      rule_type: rule.rule_type.clone(),
+      reason,
+    }));
+  }
+
+  GovernanceDecision {
+    allowed: violations.is_empty(),
+    violations,
+  }
+}
+
+/// Reasons a single parsed rule denies the operation; empty when it allows it or
+/// does not apply to this kind of operation.
+fn check_rule(
+  rule: &RuleParameters,
+  operation: &GovernedOperation,
+  context: &RuleEvaluationContext,
+) -> Vec<String> {
+  let mut reasons = Vec::new();
+  match rule {
+    RuleParameters::AccessRequirement(requirement) => {
+      if !roles_satisfy(
+        &context.agent_roles,
+        &requirement.min_role,
+        &context.role_levels,
+      ) {
+        reasons.push(format!("requires role '{}'", requirement.min_role));
+      }
+    }
+    RuleParameters::UsageLimit(limit) => {
+      if !operation.is_usage() {
+        return reasons;
+      }
+      // A bounded use must say how long it lasts
+      match (limit.max_duration_hours, context.duration_hours) {
+        (Some(max), Some(requested)) if requested > max => reasons.push(format!(
+          "requested duration {}h exceeds limit of {}h",
+          requested, max
+        )),
+        (Some(max), None) => reasons.push(format!("requires a duration of at most {}h", max)),
+        _ => {}
+      }
+      if let (Some(max), Some(quantity)) = (limit.max_quantity, context.quantity) {
+        if quantity > max {
+          reasons.push(format!("quantity {} exceeds limit of {}", quantity, max));
+        }
+      }
+    }
+    RuleParameters::TransferConditions(conditions) => {
+      if !operation.is_transfer() {
+        return reasons;
+      }
+      if let Some(min_role) = &conditions.min_role {
+        let recipient_roles = context
+          .recipient_roles
+          .as_deref()
+          .unwrap_or(&context.agent_roles);
+        if !roles_satisfy(recipient_roles, min_role, &context.role_levels) {
+          reasons.push(format!("incoming custodian requires role '{}'", min_role));
+        }
+      }
+      if context.validator_approvals < conditions.required_validators {
+        reasons.push(format!(
+          "requires {} validator approvals, has {}",
+          conditions.required_validators, context.validator_approvals
+        ));
+      }
+    }
+    RuleParameters::Custom(_) => {}
+  }
+  reasons
+}
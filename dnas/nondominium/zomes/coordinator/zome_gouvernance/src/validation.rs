@@ -1,6 +1,23 @@
+use crate::GovernanceError;
 use hdk::prelude::*;
+use nondominium_shared::call_resource_zome;
+pub use nondominium_shared::io::governance::{
+  ResourceValidationOutcome, ValidateNewResourceInput, ValidateNewResourceOutput,
+};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::update_chain::get_latest_record;
 use zome_gouvernance_integrity::*;
 
+// Mirrors `PROMOTE_VALIDATED_RESOURCE_FN_NAME` in zome_resource
+const PROMOTE_VALIDATED_RESOURCE_FN_NAME: &str = "promote_validated_resource";
+
+// Subset of zome_resource's EconomicResource used here
+#[derive(Serialize, Deserialize, Debug)]
+struct ResourceCustodian {
+  pub custodian: AgentPubKey,
+}
+
 // ============================================================================
 // Validation Receipt Management
 // ============================================================================
@@ -19,9 +36,25 @@ pub struct CreateValidationReceiptOutput {
   pub receipt: ValidationReceipt,
 }
 
+/// Record a ValidationReceipt by the calling agent. A `resource_approval` receipt is a vote
+/// on the resource's validation and follows the rules of `cast_validation_vote`.
 #[hdk_extern]
 pub fn create_validation_receipt(
   input: CreateValidationReceiptInput,
+) -> ExternResult<CreateValidationReceiptOutput> {
+  if input.validation_type != RESOURCE_APPROVAL {
+    return commit_validation_receipt(input);
+  }
+
+  let resource_hash = input.validated_item.clone();
+  authorize_validation_vote(&resource_hash)?;
+  let output = commit_validation_receipt(input)?;
+  record_resource_validation_progress(&resource_hash)?;
+  Ok(output)
+}
+
+fn commit_validation_receipt(
+  input: CreateValidationReceiptInput,
 ) -> ExternResult<CreateValidationReceiptOutput> {
   let agent_info = agent_info()?;
   let now = sys_time()?;
@@ -41,18 +74,15 @@ pub fn create_validation_receipt(
   let receipt_hash = create_entry(&EntryTypes::ValidationReceipt(receipt.clone()))?;
 
   // Create discovery link
-  let path = Path::from("all_validation_receipts");
-  let anchor_hash = path.path_entry_hash()?;
-  create_link(
-    anchor_hash,
+  create_hash_sharded_link(
+    "all_validation_receipts",
     receipt_hash.clone(),
     LinkTypes::AllValidationReceipts,
-    (),
   )?;
 
   // Link the receipt to the validated item
   create_link(
-    input.validated_item,
+    input.validated_item.clone(),
     receipt_hash.clone(),
     LinkTypes::ValidatedItemToReceipt,
     (),
@@ -93,13 +123,26 @@ pub fn get_validation_history(item_hash: ActionHash) -> ExternResult<Vec<Validat
 
 #[hdk_extern]
 pub fn get_all_validation_receipts(_: ()) -> ExternResult<Vec<ValidationReceipt>> {
-  let path = Path::from("all_validation_receipts");
-  let anchor_hash = path.path_entry_hash()?;
+  resolve_validation_receipt_links(all_validation_receipt_links()?)
+}
 
-  let links = get_links(
-    LinkQuery::try_new(anchor_hash, LinkTypes::AllValidationReceipts)?,
-    GetStrategy::default(),
-  )?;
+/// One page of `get_all_validation_receipts`, in link order.
+#[hdk_extern]
+pub fn get_all_validation_receipts_paginated(
+  page: PageRequest,
+) -> ExternResult<Page<ValidationReceipt>> {
+  let (links, next_cursor) = page.select(all_validation_receipt_links()?);
+  Ok(Page {
+    items: resolve_validation_receipt_links(links)?,
+    next_cursor,
+  })
+}
+
+fn all_validation_receipt_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("all_validation_receipts", LinkTypes::AllValidationReceipts)
+}
+
+fn resolve_validation_receipt_links(links: Vec<Link>) -> ExternResult<Vec<ValidationReceipt>> {
   let mut receipts = Vec::new();
 
   for link in links {
@@ -125,109 +168,329 @@ pub fn get_all_validation_receipts(_: ()) -> ExternResult<Vec<ValidationReceipt>
 // Resource Validation Management
 // ============================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CreateResourceValidationInput {
-  pub resource: ActionHash,
-  pub validation_scheme: String,
-  pub required_validators: u32,
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CreateResourceValidationOutput {
-  pub validation_hash: ActionHash,
-  pub validation: ResourceValidation,
-}
-
-#[hdk_extern]
-pub fn create_resource_validation(
-  input: CreateResourceValidationInput,
-) -> ExternResult<CreateResourceValidationOutput> {
+/// Open the ResourceValidation of a resource just created by the calling agent, with the
+/// scheme of its ResourceSpecification. Not an extern: integrity only accepts the first
+/// validation its creator opens, so it is only reached through `validate_new_resource`.
+fn open_resource_validation(input: &ValidateNewResourceInput) -> ExternResult<ActionHash> {
   let now = sys_time()?;
 
   let validation = ResourceValidation {
-    resource: input.resource.clone(),
-    validation_scheme: input.validation_scheme,
-    required_validators: input.required_validators,
-    current_validators: 0,
-    status: "pending".to_string(),
+    resource: input.resource_hash.clone(),
+    resource_spec: input.resource_spec_hash.clone(),
+    validation_scheme: input.validation.validation_scheme.clone(),
+    required_validators: input.validation.required_validators,
+    status: ResourceValidationStatus::Pending,
+    receipts: Vec::new(),
     created_at: now,
     updated_at: now,
   };
 
-  let validation_hash = create_entry(&EntryTypes::ResourceValidation(validation.clone()))?;
+  validation
+    .scheme()
+    .map_err(GovernanceError::InvalidValidationScheme)?;
+
+  let validation_hash = create_entry(&EntryTypes::ResourceValidation(validation))?;
 
   // Create discovery link
-  let path = Path::from("all_resource_validations");
-  let anchor_hash = path.path_entry_hash()?;
-  create_link(
-    anchor_hash,
+  create_hash_sharded_link(
+    "all_resource_validations",
     validation_hash.clone(),
     LinkTypes::AllResourceValidations,
-    (),
   )?;
 
   // Link validation to the resource
   create_link(
-    input.resource,
+    input.resource_hash.clone(),
     validation_hash.clone(),
     LinkTypes::ResourceToValidation,
     (),
   )?;
 
-  Ok(CreateResourceValidationOutput {
-    validation_hash,
-    validation,
-  })
+  Ok(validation_hash)
 }
 
 #[hdk_extern]
 pub fn check_validation_status(
   resource_hash: ActionHash,
 ) -> ExternResult<Option<ResourceValidation>> {
+  Ok(get_latest_resource_validation(&resource_hash)?.map(|(_, validation)| validation))
+}
+
+/// Status of the resource's ResourceValidation with the hash of its latest version, which a
+/// promotion out of PendingValidation cites.
+#[hdk_extern]
+pub fn get_resource_validation_outcome(
+  resource_hash: ActionHash,
+) -> ExternResult<Option<ResourceValidationOutcome>> {
+  Ok(
+    get_latest_resource_validation(&resource_hash)?.map(|(validation_hash, validation)| {
+      ResourceValidationOutcome {
+        validation_hash,
+        status: validation.status,
+      }
+    }),
+  )
+}
+
+/// The resource's ResourceValidation as `(latest hash, latest entry)`.
+/// Integrity only lets a resource's creator open its validation, once, and only lets them
+/// link it; of the linked validations the earliest opened by the creator is the one.
+fn get_latest_resource_validation(
+  resource_hash: &ActionHash,
+) -> ExternResult<Option<(ActionHash, ResourceValidation)>> {
+  let Some(creator) = resource_creator(resource_hash)? else {
+    return Ok(None);
+  };
   let links = get_links(
-    LinkQuery::try_new(resource_hash, LinkTypes::ResourceToValidation)?,
+    LinkQuery::try_new(resource_hash.clone(), LinkTypes::ResourceToValidation)?,
     GetStrategy::default(),
   )?;
 
-  // Get the most recent validation (there should only be one per resource)
-  if let Some(link) = links.first() {
-    if let Ok(any_dht_hash) = AnyDhtHash::try_from(link.target.clone()) {
-      if let Some(record) = get(any_dht_hash, GetOptions::default())? {
-        if let Ok(Some(EntryTypes::ResourceValidation(validation))) =
-          record.entry().to_app_option::<EntryTypes>().map_err(|_| {
-            wasm_error!(WasmErrorInner::Guest(
-              "Failed to deserialize resource validation".into()
-            ))
-          })
-        {
-          return Ok(Some(validation));
-        }
+  let mut opened = Vec::new();
+  for link in links {
+    let Some(validation_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get(validation_hash.clone(), GetOptions::default())? else {
+      continue;
+    };
+    if *record.action().author() == creator {
+      opened.push((record.action().timestamp(), validation_hash));
+    }
+  }
+  let Some((_, original_hash)) = opened.into_iter().min() else {
+    return Ok(None);
+  };
+
+  let Some(record) = get_latest_record(original_hash)? else {
+    return Ok(None);
+  };
+  let latest_hash = record.action_address().clone();
+  match record.entry().to_app_option::<EntryTypes>().map_err(|_| {
+    wasm_error!(WasmErrorInner::Guest(
+      "Failed to deserialize resource validation".into()
+    ))
+  })? {
+    Some(EntryTypes::ResourceValidation(validation)) => Ok(Some((latest_hash, validation))),
+    _ => Ok(None),
+  }
+}
+
+/// Votes on a resource, recomputed from its `resource_approval` ValidationReceipts.
+/// Each validator counts once, with their earliest receipt; the resource's creator never
+/// counts, and neither does anyone voting once the validation's panel is full.
+#[hdk_extern]
+pub fn get_validation_tally(resource_hash: ActionHash) -> ExternResult<ValidationTally> {
+  let scheme = match get_latest_resource_validation(&resource_hash)? {
+    Some((_, validation)) => Some(
+      validation
+        .scheme()
+        .map_err(GovernanceError::InvalidValidationScheme)?,
+    ),
+    None => None,
+  };
+  Ok(tally_votes(&resource_votes(&resource_hash, scheme.as_ref())?))
+}
+
+/// The `resource_approval` receipts counted as votes on a resource, with their hashes
+fn resource_votes(
+  resource_hash: &ActionHash,
+  scheme: Option<&ValidationScheme>,
+) -> ExternResult<Vec<(ActionHash, ValidationReceipt)>> {
+  let creator = resource_creator(resource_hash)?;
+  let links = get_links(
+    LinkQuery::try_new(resource_hash.clone(), LinkTypes::ValidatedItemToReceipt)?,
+    GetStrategy::default(),
+  )?;
+
+  let mut receipts = Vec::new();
+  for link in links {
+    let Some(receipt_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get(receipt_hash.clone(), GetOptions::default())? else {
+      continue;
+    };
+    if let Ok(Some(EntryTypes::ValidationReceipt(receipt))) =
+      record.entry().to_app_option::<EntryTypes>()
+    {
+      if receipt.validation_type == RESOURCE_APPROVAL
+        && Some(&receipt.validator) != creator.as_ref()
+      {
+        receipts.push((receipt_hash, receipt));
       }
     }
   }
+  receipts.sort_by_key(|(_, receipt)| receipt.validated_at);
 
-  Ok(None)
+  let mut voted = HashSet::new();
+  receipts.retain(|(_, receipt)| voted.insert(receipt.validator.clone()));
+  Ok(match scheme {
+    Some(scheme) => scheme.counted_votes(receipts),
+    None => receipts,
+  })
 }
 
-// ============================================================================
-// Cross-Zome Validation Functions
-// ============================================================================
+fn tally_votes(votes: &[(ActionHash, ValidationReceipt)]) -> ValidationTally {
+  let approvals = votes.iter().filter(|(_, receipt)| receipt.approved).count() as u32;
+  ValidationTally {
+    approvals,
+    rejections: votes.len() as u32 - approvals,
+  }
+}
+
+/// Author of the resource's create action
+fn resource_creator(resource_hash: &ActionHash) -> ExternResult<Option<AgentPubKey>> {
+  Ok(
+    get(resource_hash.clone(), GetOptions::default())?
+      .map(|record| record.action().author().clone()),
+  )
+}
 
 #[derive(Serialize, Deserialize, Debug)]
-pub struct ValidateNewResourceInput {
+pub struct CastValidationVoteInput {
   pub resource_hash: ActionHash,
-  pub resource_spec_hash: ActionHash,
-  pub creator: AgentPubKey,
-  pub validation_scheme: String,
+  pub approved: bool,
+  pub notes: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-pub struct ValidateNewResourceOutput {
-  pub validation_hash: ActionHash,
-  pub validation_required: bool,
-  pub status: String,
+pub struct CastValidationVoteOutput {
+  pub receipt_hash: ActionHash,
+  pub status: ResourceValidationStatus,
+  pub tally: ValidationTally,
 }
 
+/// Vote on a pending ResourceValidation. Each validator votes once, and the resource's
+/// creator cannot vote on their own resource.
+#[hdk_extern]
+pub fn cast_validation_vote(
+  input: CastValidationVoteInput,
+) -> ExternResult<CastValidationVoteOutput> {
+  authorize_validation_vote(&input.resource_hash)?;
+
+  let receipt = commit_validation_receipt(CreateValidationReceiptInput {
+    validated_item: input.resource_hash.clone(),
+    validation_type: RESOURCE_APPROVAL.to_string(),
+    approved: input.approved,
+    notes: input.notes,
+  })?;
+
+  // The vote may decide the validation
+  record_resource_validation_progress(&input.resource_hash)?;
+
+  let status = check_validation_status(input.resource_hash.clone())?
+    .map(|validation| validation.status)
+    .unwrap_or_default();
+  Ok(CastValidationVoteOutput {
+    receipt_hash: receipt.receipt_hash,
+    status,
+    tally: get_validation_tally(input.resource_hash)?,
+  })
+}
+
+/// Fail unless the calling agent may vote on the resource's validation: it is still pending,
+/// they did not create the resource, and they have not voted yet.
+fn authorize_validation_vote(resource_hash: &ActionHash) -> ExternResult<()> {
+  let me = agent_info()?.agent_initial_pubkey;
+
+  let (_, validation) = get_latest_resource_validation(resource_hash)?.ok_or(
+    GovernanceError::ResourceValidationNotFound(format!("{}", resource_hash)),
+  )?;
+  if validation.status != ResourceValidationStatus::Pending {
+    return Err(
+      GovernanceError::InvalidInput(format!(
+        "ResourceValidation is already {:?}",
+        validation.status
+      ))
+      .into(),
+    );
+  }
+
+  if resource_creator(resource_hash)?.as_ref() == Some(&me) {
+    return Err(GovernanceError::NotAuthorizedValidator.into());
+  }
+
+  let already_voted = get_validation_history(resource_hash.clone())?
+    .into_iter()
+    .any(|receipt| receipt.validation_type == RESOURCE_APPROVAL && receipt.validator == me);
+  if already_voted {
+    return Err(
+      GovernanceError::ValidationAlreadyExists("This validator has already voted".to_string())
+        .into(),
+    );
+  }
+  Ok(())
+}
+
+/// Recompute the tally of a pending ResourceValidation and record its outcome once the
+/// scheme decides it, citing the counted receipts; on approval, ask the custodian's cell to
+/// promote the resource.
+fn record_resource_validation_progress(resource_hash: &ActionHash) -> ExternResult<()> {
+  let Some((latest_hash, mut validation)) =
+    get_latest_resource_validation(resource_hash)?
+  else {
+    return Ok(());
+  };
+  if validation.status != ResourceValidationStatus::Pending {
+    return Ok(());
+  }
+
+  let scheme = validation
+    .scheme()
+    .map_err(GovernanceError::InvalidValidationScheme)?;
+  let votes = resource_votes(resource_hash, Some(&scheme))?;
+  let outcome = scheme.outcome(&tally_votes(&votes));
+  if outcome == ResourceValidationStatus::Pending {
+    return Ok(());
+  }
+
+  validation.status = outcome.clone();
+  validation.receipts = votes
+    .into_iter()
+    .map(|(receipt_hash, _)| receipt_hash)
+    .collect();
+  validation.updated_at = sys_time()?;
+  update_entry(latest_hash, &EntryTypes::ResourceValidation(validation))?;
+
+  if outcome == ResourceValidationStatus::Approved {
+    request_resource_promotion(resource_hash)?;
+  }
+  Ok(())
+}
+
+/// Promotion must be authored by the custodian. If they are unreachable the resource stays
+/// PendingValidation until they call `promote_validated_resource` themselves.
+fn request_resource_promotion(resource_hash: &ActionHash) -> ExternResult<()> {
+  let resource: ResourceCustodian =
+    call_resource_zome("get_latest_economic_resource", resource_hash.clone())?;
+
+  if resource.custodian == agent_info()?.agent_initial_pubkey {
+    let _: Option<Record> =
+      call_resource_zome(PROMOTE_VALIDATED_RESOURCE_FN_NAME, resource_hash.clone())?;
+    return Ok(());
+  }
+
+  let response = call_remote(
+    resource.custodian.clone(),
+    ZomeName::from("zome_resource"),
+    FunctionName::from(PROMOTE_VALIDATED_RESOURCE_FN_NAME),
+    None,
+    resource_hash,
+  )?;
+  if !matches!(response, ZomeCallResponse::Ok(_)) {
+    debug!(
+      "Custodian {} did not promote validated resource: {:?}",
+      resource.custodian, response
+    );
+  }
+  Ok(())
+}
+
+// ============================================================================
+// Cross-Zome Validation Functions
+// ============================================================================
+
 #[hdk_extern]
 pub fn validate_new_resource(
   input: ValidateNewResourceInput,
@@ -239,16 +502,16 @@ pub fn validate_new_resource(
   // Simple Agents creating their first resource need validation
   // Accountable+ Agents may have different validation requirements
 
-  let validation_input = CreateResourceValidationInput {
-    resource: input.resource_hash,
-    validation_scheme: input.validation_scheme,
-    required_validators: 1, // TODO: Make configurable based on resource type
-  };
+  input
+    .validation
+    .validate()
+    .map_err(GovernanceError::InvalidValidationScheme)?;
 
-  let validation_result = create_resource_validation(validation_input)?;
+  // Scheme and threshold come from the resource's ResourceSpecification
+  let validation_hash = open_resource_validation(&input)?;
 
   Ok(ValidateNewResourceOutput {
-    validation_hash: validation_result.validation_hash,
+    validation_hash,
     validation_required: true,
     status: "pending_validation".to_string(),
   })
@@ -291,7 +554,7 @@ pub fn validate_agent_identity(
     ),
   };
 
-  let receipt_result = create_validation_receipt(receipt_input)?;
+  let receipt_result = commit_validation_receipt(receipt_input)?;
 
   Ok(ValidateAgentIdentityOutput {
     validation_receipt_hash: receipt_result.receipt_hash,
@@ -341,7 +604,7 @@ pub fn validate_specialized_role(
     notes: Some(format!("Agent validated for {} role", input.requested_role)),
   };
 
-  let receipt_result = create_validation_receipt(receipt_input)?;
+  let receipt_result = commit_validation_receipt(receipt_input)?;
 
   Ok(ValidateSpecializedRoleOutput {
     validation_receipt_hash: receipt_result.receipt_hash,
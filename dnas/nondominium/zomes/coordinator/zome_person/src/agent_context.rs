@@ -0,0 +1,169 @@
+use crate::role::{get_held_roles, role_capability_level};
+use crate::role_revocation::RoleStatus;
+use crate::PersonError;
+use hdk::prelude::*;
+use zome_person_integrity::*;
+
+// ============================================================================
+// Agent contexts
+// ============================================================================
+//
+// The kind of agent a Person stands for — an individual, a collective, a project, a network,
+// a bot or an external organisation — is recorded in an AgentContext, linked from the agent
+// (AgentToContext) when `create_person` runs. Agents without one are individuals. Bots are
+// answerable to an operator holding an active role at the Accountable Agent level or above,
+// who first consents to operate the bot (`consent_to_operate_bot`); the bot's context cites
+// that consent, found through the BotToOperatorConsents links from the bot's agent key.
+
+/// The operator's active role that makes them an Accountable Agent, if any
+fn find_operator_role(operator: AgentPubKey) -> ExternResult<Option<ActionHash>> {
+  let required_rank = default_role("Accountable Agent")
+    .and_then(|accountable| capability_rank(accountable.capability_level));
+  for held in get_held_roles(operator.clone())? {
+    if held.role.assigned_to != operator || held.status != RoleStatus::Active {
+      continue;
+    }
+    let Some(level) = role_capability_level(&held.role)? else {
+      continue;
+    };
+    if capability_rank(&level) >= required_rank {
+      return Ok(Some(held.latest_hash));
+    }
+  }
+  Ok(None)
+}
+
+/// Agree to answer for a bot, so it can create its Person as a Bot operated by the caller.
+#[hdk_extern]
+pub fn consent_to_operate_bot(bot: AgentPubKey) -> ExternResult<ActionHash> {
+  let operator = agent_info()?.agent_initial_pubkey;
+  if bot == operator {
+    return Err(PersonError::InvalidInput("A bot cannot operate itself".to_string()).into());
+  }
+  if find_operator_role(operator.clone())?.is_none() {
+    return Err(
+      PersonError::InsufficientCapability(
+        "The operator of a bot must be an Accountable Agent".to_string(),
+      )
+      .into(),
+    );
+  }
+
+  let consent = BotOperatorConsent {
+    bot: bot.clone(),
+    operator,
+    consented_at: sys_time()?,
+  };
+  let consent_hash = create_entry(&EntryTypes::BotOperatorConsent(consent))?;
+  create_link(
+    bot,
+    consent_hash.clone(),
+    LinkTypes::BotToOperatorConsents,
+    (),
+  )?;
+  Ok(consent_hash)
+}
+
+/// The operator's consent to operate the calling agent as a bot, if any
+fn find_operator_consent(operator: &AgentPubKey) -> ExternResult<Option<ActionHash>> {
+  let bot = agent_info()?.agent_initial_pubkey;
+  let links = get_links(
+    LinkQuery::try_new(bot.clone(), LinkTypes::BotToOperatorConsents)?,
+    GetStrategy::default(),
+  )?;
+  for link in links {
+    let Some(consent_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get(consent_hash.clone(), GetOptions::default())? else {
+      continue;
+    };
+    if let Ok(Some(consent)) = record.entry().to_app_option::<BotOperatorConsent>() {
+      if consent.bot == bot && consent.operator == *operator {
+        return Ok(Some(consent_hash));
+      }
+    }
+  }
+  Ok(None)
+}
+
+/// Check the agent type of a new Person, naming a bot operator's role and consent for integrity.
+pub(crate) fn resolve_agent_type(agent_type: AgentEntityType) -> ExternResult<AgentEntityType> {
+  let AgentEntityType::Bot {
+    capabilities,
+    operator,
+    ..
+  } = agent_type
+  else {
+    return Ok(agent_type);
+  };
+  if operator == agent_info()?.agent_initial_pubkey {
+    return Err(PersonError::InvalidInput("A bot cannot operate itself".to_string()).into());
+  }
+  let operator_role =
+    find_operator_role(operator.clone())?.ok_or(PersonError::InsufficientCapability(
+      "The operator of a bot must be an Accountable Agent".to_string(),
+    ))?;
+  let operator_consent = find_operator_consent(&operator)?.ok_or(PersonError::InvalidInput(
+    "The operator has not consented to operate this bot".to_string(),
+  ))?;
+  Ok(AgentEntityType::Bot {
+    capabilities,
+    operator,
+    operator_role: Some(operator_role),
+    operator_consent: Some(operator_consent),
+  })
+}
+
+pub(crate) fn create_agent_context(
+  agent_pubkey: AgentPubKey,
+  agent_type: AgentEntityType,
+  person_hash: ActionHash,
+) -> ExternResult<ActionHash> {
+  let context = AgentContext {
+    agent_type,
+    person_hash: Some(person_hash),
+    created_at: sys_time()?,
+    network_seed: dna_info()?.modifiers.network_seed,
+  };
+  let context_hash = create_entry(&EntryTypes::AgentContext(context))?;
+  create_link(
+    agent_pubkey,
+    context_hash.clone(),
+    LinkTypes::AgentToContext,
+    (),
+  )?;
+  Ok(context_hash)
+}
+
+#[hdk_extern]
+pub fn get_agent_context(agent_pubkey: AgentPubKey) -> ExternResult<Option<AgentContext>> {
+  let links = get_links(
+    LinkQuery::try_new(agent_pubkey, LinkTypes::AgentToContext)?,
+    GetStrategy::default(),
+  )?;
+  let Some(action_hash) = links
+    .into_iter()
+    .min_by_key(|link| link.timestamp)
+    .and_then(|link| link.target.into_action_hash())
+  else {
+    return Ok(None);
+  };
+  let Some(record) = get(action_hash, GetOptions::default())? else {
+    return Ok(None);
+  };
+  record
+    .entry()
+    .to_app_option()
+    .map_err(|e| PersonError::SerializationError(format!("{:?}", e)).into())
+}
+
+/// The kind of agent behind an agent key; Individual when it has no AgentContext
+#[hdk_extern]
+pub fn get_agent_entity_type(agent_pubkey: AgentPubKey) -> ExternResult<AgentEntityType> {
+  Ok(
+    get_agent_context(agent_pubkey)?
+      .map(|context| context.agent_type)
+      .unwrap_or_default(),
+  )
+}
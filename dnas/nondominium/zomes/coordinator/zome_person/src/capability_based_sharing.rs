@@ -1,9 +1,9 @@
 use hdk::prelude::*;
 use std::collections::BTreeSet;
-use std::str::FromStr;
 use zome_person_integrity::*;
 
 use crate::PersonError;
+use crate::role_definition::resolve_role;
 
 // ============================================================================
 // CAPABILITY-BASED PRIVATE DATA SHARING
@@ -561,26 +561,12 @@ pub fn validate_capability_grant(grant_hash: ActionHash) -> ExternResult<bool> {
 // ROLE-BASED CAPABILITY GRANTS
 // ============================================================================
 
-/// Serializable wrapper for RoleType
+/// Role named in a role-based grant; any role of the role registry
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SerializableRoleType {
   pub role_name: String,
 }
 
-impl From<RoleType> for SerializableRoleType {
-  fn from(role: RoleType) -> Self {
-    Self {
-      role_name: role.to_string(),
-    }
-  }
-}
-
-impl From<SerializableRoleType> for RoleType {
-  fn from(serializable: SerializableRoleType) -> Self {
-    RoleType::from_str(&serializable.role_name).unwrap_or(RoleType::SimpleAgent)
-  }
-}
-
 /// Grant private data access based on role
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GrantRoleBasedAccessInput {
@@ -594,11 +580,13 @@ pub fn grant_role_based_private_data_access(
   input: GrantRoleBasedAccessInput,
 ) -> ExternResult<GrantPrivateDataAccessOutput> {
   let role_name = input.role.role_name.clone();
-  let role: RoleType = input.role.into();
-  let (fields_allowed, duration_days) = match role {
-    RoleType::SimpleAgent => (vec!["email".to_string()], 7),
-    RoleType::AccountableAgent => (vec!["email".to_string(), "phone".to_string()], 14),
-    RoleType::PrimaryAccountableAgent => (
+  // Access follows the capability level of the role; unknown roles get member access
+  let capability_level = resolve_role(&role_name)?
+    .map(|resolved| resolved.definition.capability_level)
+    .unwrap_or_default();
+  let (fields_allowed, duration_days) = match capability_level.as_str() {
+    "coordination" => (vec!["email".to_string(), "phone".to_string()], 14),
+    "governance" => (
       vec![
         "email".to_string(),
         "phone".to_string(),
@@ -606,7 +594,7 @@ pub fn grant_role_based_private_data_access(
       ],
       30,
     ),
-    RoleType::Transport | RoleType::Repair | RoleType::Storage => (
+    "stewardship" => (
       vec![
         "email".to_string(),
         "phone".to_string(),
@@ -615,6 +603,7 @@ pub fn grant_role_based_private_data_access(
       ],
       21,
     ),
+    _ => (vec!["email".to_string()], 7),
   };
 
   let grant_input = GrantPrivateDataAccessInput {
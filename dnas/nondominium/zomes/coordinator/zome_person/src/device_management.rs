@@ -1,5 +1,6 @@
 use crate::PersonError;
 use hdk::prelude::*;
+use nondominium_shared::update_chain::get_latest_record;
 use zome_person_integrity::*;
 
 /// Helper function to find the person associated with the current agent
@@ -144,8 +145,8 @@ pub fn get_devices_for_person(person_hash: ActionHash) -> ExternResult<Vec<Devic
         device_action_hash
       );
 
-      // Get the latest device record by following DeviceUpdates links
-      match get_latest_device_record(device_action_hash.clone())? {
+      // Get the latest device record by following its update chain
+      match get_latest_record(device_action_hash.clone())? {
         Some(record) => {
           debug!("Got latest record, checking entry");
           if let Ok(Some(device)) = record.entry().to_app_option::<Device>() {
@@ -215,55 +216,6 @@ fn get_device_action_hash(
   Ok(None)
 }
 
-/// Get the latest device record by following DeviceUpdates links
-fn get_latest_device_record(original_action_hash: ActionHash) -> ExternResult<Option<Record>> {
-  warn!(
-    "get_latest_device_record called for: {:?}",
-    original_action_hash
-  );
-
-  // Get all DeviceUpdates links from this record
-  let update_links_query =
-    LinkQuery::try_new(original_action_hash.clone(), LinkTypes::DeviceUpdates)?;
-  let update_links = get_links(update_links_query, GetStrategy::default())?;
-
-  warn!("Found {} DeviceUpdates links", update_links.len());
-
-  if update_links.is_empty() {
-    // No updates, return the original record
-    warn!("No DeviceUpdates links found, returning original record");
-    return get(original_action_hash, GetOptions::default());
-  }
-
-  // Find the latest update by getting the most recent link
-  // For now, we'll take the last one (Holochain typically returns links in creation order)
-  if let Some(latest_link) = update_links.last() {
-    if let Some(latest_action_hash) = latest_link.target.clone().into_action_hash() {
-      warn!("Getting latest record from: {:?}", latest_action_hash);
-      let latest_record = get(latest_action_hash, GetOptions::default())?;
-
-      // Recursively check if there are more updates (this handles multiple update levels)
-      if let Some(record) = latest_record {
-        let action_hash: ActionHash = record.action_address().clone().into();
-        let update_links_query = LinkQuery::try_new(action_hash.clone(), LinkTypes::DeviceUpdates)?;
-        let further_updates = get_links(update_links_query, GetStrategy::default())?;
-
-        if further_updates.is_empty() {
-          warn!("No further updates found, returning this record");
-          return Ok(Some(record));
-        } else {
-          warn!("Found further updates, recursing...");
-          return get_latest_device_record(record.action_address().clone().into());
-        }
-      }
-    }
-  }
-
-  // Fallback to original record
-  warn!("Fallback to original record");
-  get(original_action_hash, GetOptions::default())
-}
-
 /// Update device last active time
 #[hdk_extern]
 pub fn update_device_activity(device_id: String) -> ExternResult<bool> {
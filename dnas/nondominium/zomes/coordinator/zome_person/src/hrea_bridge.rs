@@ -1,5 +1,6 @@
 use hdk::prelude::*;
 use nondominium_shared::call_hrea_zome;
+use zome_person_integrity::AgentEntityType;
 
 /// Local mirror of hREA's `ReaAgent` struct for serialization.
 /// Avoids a hard Cargo dependency on the hREA workspace.
@@ -15,15 +16,33 @@ pub struct ReaAgentInput {
   pub note: Option<String>,
 }
 
+/// hREA `agent_type` of an agent entity type, and its classification when not an individual.
+/// Individuals and bots act as single agents ("Person"); the others are "Organization"s.
+fn rea_agent_type(entity_type: &AgentEntityType) -> (&'static str, Option<&'static str>) {
+  match entity_type {
+    AgentEntityType::Individual => ("Person", None),
+    AgentEntityType::Bot { .. } => ("Person", Some("bot")),
+    AgentEntityType::Collective(_) => ("Organization", Some("collective")),
+    AgentEntityType::Project(_) => ("Organization", Some("project")),
+    AgentEntityType::Network(_) => ("Organization", Some("network")),
+    AgentEntityType::ExternalOrganisation(_) => ("Organization", Some("external_organisation")),
+  }
+}
+
 /// Create a `ReaAgent` in the hREA DNA and return its `ActionHash`.
 /// Called internally by `create_person` to establish the cross-DNA link.
-pub fn create_rea_agent_bridge(name: &str, image: Option<&str>) -> ExternResult<ActionHash> {
+pub fn create_rea_agent_bridge(
+  name: &str,
+  image: Option<&str>,
+  entity_type: &AgentEntityType,
+) -> ExternResult<ActionHash> {
+  let (agent_type, classification) = rea_agent_type(entity_type);
   let input = ReaAgentInput {
     id: None,
     name: name.to_string(),
-    agent_type: "Person".to_string(),
+    agent_type: agent_type.to_string(),
     image: image.map(|s| s.to_string()),
-    classified_as: None,
+    classified_as: classification.map(|class| vec![class.to_string()]),
     note: None,
   };
   let record: Record = call_hrea_zome("create_rea_agent", input)?;
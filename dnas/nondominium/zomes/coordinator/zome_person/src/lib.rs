@@ -1,19 +1,25 @@
 use hdk::prelude::*;
 pub use nondominium_shared::errors::PersonError;
 
+pub mod agent_context;
 pub mod capability_based_sharing;
 pub mod device_management;
 pub mod hrea_bridge;
 pub mod person;
 pub mod private_data;
 pub mod role;
+pub mod role_definition;
+pub mod role_revocation;
 
+pub use agent_context::*;
 pub use capability_based_sharing::*;
 pub use device_management::*;
 pub use hrea_bridge::*;
 pub use person::*;
 pub use private_data::*;
 pub use role::*;
+pub use role_definition::*;
+pub use role_revocation::*;
 
 // Resolve ambiguous re-exports
 pub use capability_based_sharing::ValidationResult as SharingValidationResult;
@@ -21,7 +27,37 @@ pub use person::PromoteAgentInput as PersonPromoteAgentInput;
 pub use role::PromoteAgentInput as RolePromoteAgentInput;
 pub use role::ValidationResult as RoleValidationResult;
 
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum Signal {
+  PromotionRequestReviewed {
+    request_hash: ActionHash,
+    request: zome_person_integrity::RolePromotionRequest,
+  },
+}
+
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+  seed_default_roles()?;
+  grant_remote_signal_capability()?;
   Ok(InitCallbackResult::Pass)
 }
+
+/// Let other agents send this agent signals, such as the review of its promotion requests.
+fn grant_remote_signal_capability() -> ExternResult<()> {
+  create_cap_grant(ZomeCallCapGrant {
+    tag: "remote_signals".to_string(),
+    access: CapAccess::Unrestricted,
+    functions: GrantedFunctions::Listed(HashSet::from([(
+      zome_info()?.name,
+      FunctionName::from("recv_remote_signal"),
+    )])),
+  })?;
+  Ok(())
+}
+
+/// Forward a signal sent by another agent to this agent's UI.
+#[hdk_extern]
+pub fn recv_remote_signal(signal: Signal) -> ExternResult<()> {
+  emit_signal(signal)
+}
@@ -1,5 +1,9 @@
+use crate::agent_context::{create_agent_context, resolve_agent_type};
 use crate::{create_rea_agent_bridge, PersonError};
 use hdk::prelude::*;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use nondominium_shared::update_chain::get_latest_record;
 use zome_person_integrity::*;
 
 // Cross-zome call structure for agent identity validation
@@ -21,6 +25,8 @@ pub struct PersonInput {
   pub name: String,
   pub avatar_url: Option<String>,
   pub bio: Option<String>,
+  #[serde(default)]
+  pub agent_type: Option<AgentEntityType>, // None: Individual; fixed once the person exists
 }
 
 #[hdk_extern]
@@ -34,16 +40,19 @@ pub fn create_person(input: PersonInput) -> ExternResult<Record> {
     return Err(PersonError::PersonAlreadyExists.into());
   }
 
+  let agent_type = resolve_agent_type(input.agent_type.unwrap_or_default())?;
+
   // Create ReaAgent in hREA DNA first (best-effort: None if hREA unavailable)
-  let hrea_agent_hash = create_rea_agent_bridge(&input.name, input.avatar_url.as_deref())
-    .map_err(|e| {
-      warn!(
-        "hREA bridge: create_rea_agent failed, person will have no hrea_agent_hash: {:?}",
+  let hrea_agent_hash =
+    create_rea_agent_bridge(&input.name, input.avatar_url.as_deref(), &agent_type)
+      .map_err(|e| {
+        warn!(
+          "hREA bridge: create_rea_agent failed, person will have no hrea_agent_hash: {:?}",
+          e
+        );
         e
-      );
-      e
-    })
-    .ok();
+      })
+      .ok();
 
   let person = Person {
     name: input.name,
@@ -58,31 +67,15 @@ pub fn create_person(input: PersonInput) -> ExternResult<Record> {
   )?;
 
   // Use the unified Person-centric link creation function
-  create_person_entry_links(person_hash, agent_pubkey)?;
+  create_person_entry_links(person_hash.clone(), agent_pubkey.clone())?;
+  create_agent_context(agent_pubkey, agent_type, person_hash)?;
 
   Ok(record)
 }
 
 #[hdk_extern]
 pub fn get_latest_person_record(original_action_hash: ActionHash) -> ExternResult<Option<Record>> {
-  let link_query = LinkQuery::try_new(original_action_hash.clone(), LinkTypes::PersonUpdates)?;
-  let links = get_links(link_query, GetStrategy::default())?;
-  let latest_link = links
-    .into_iter()
-    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-  let latest_person_hash = match latest_link {
-    Some(link) => {
-      link
-        .target
-        .clone()
-        .into_action_hash()
-        .ok_or(PersonError::EntryOperationFailed(
-          "Invalid action hash in link".to_string(),
-        ))?
-    }
-    None => original_action_hash.clone(),
-  };
-  get(latest_person_hash, GetOptions::default())
+  get_latest_record(original_action_hash)
 }
 
 #[hdk_extern]
@@ -161,12 +154,27 @@ pub struct GetAllPersonsOutput {
 
 #[hdk_extern]
 pub fn get_all_persons(_: ()) -> ExternResult<GetAllPersonsOutput> {
-  let path = Path::from("persons");
+  Ok(GetAllPersonsOutput {
+    persons: resolve_person_links(all_person_links()?),
+  })
+}
 
-  let link_query = LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllPersons)?;
-  let links = get_links(link_query, GetStrategy::default())?;
+/// One page of `get_all_persons`, in link order.
+#[hdk_extern]
+pub fn get_all_persons_paginated(page: PageRequest) -> ExternResult<Page<Person>> {
+  let (links, next_cursor) = page.select(all_person_links()?);
+  Ok(Page {
+    items: resolve_person_links(links),
+    next_cursor,
+  })
+}
 
-  let persons = links
+fn all_person_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("persons", LinkTypes::AllPersons)
+}
+
+fn resolve_person_links(links: Vec<Link>) -> Vec<Person> {
+  links
     .iter()
     .filter_map(|link| {
       let action_hash = link.target.clone().into_action_hash()?;
@@ -174,9 +182,7 @@ pub fn get_all_persons(_: ()) -> ExternResult<GetAllPersonsOutput> {
 
       record.entry().to_app_option::<Person>().ok()?
     })
-    .collect();
-
-  Ok(GetAllPersonsOutput { persons })
+    .collect()
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -285,13 +291,7 @@ fn create_person_entry_links(
   agent_pubkey: AgentPubKey,
 ) -> ExternResult<()> {
   // Create discovery link
-  let path = Path::from("persons");
-  create_link(
-    path.path_entry_hash()?,
-    person_hash.clone(),
-    LinkTypes::AllPersons,
-    (),
-  )?;
+  create_hash_sharded_link("persons", person_hash.clone(), LinkTypes::AllPersons)?;
 
   // Create Agent -> Person link (primary relationship)
   create_link(
@@ -1,7 +1,12 @@
 use crate::person::get_agent_person;
-use crate::PersonError;
+use crate::role_definition::resolve_role;
+use crate::role_revocation::{role_status, RoleStatus};
+use crate::{PersonError, Signal};
 use hdk::prelude::*;
 use nondominium_shared::call_governance_zome;
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links, hash_shard};
+use nondominium_shared::roles::capability_rank;
+use nondominium_shared::update_chain::get_latest_record;
 use zome_person_integrity::*;
 
 // Cross-zome call structure for governance validation
@@ -18,6 +23,8 @@ pub struct PersonRoleInput {
   pub agent_pubkey: AgentPubKey,
   pub role_name: String,
   pub description: Option<String>,
+  #[serde(default)]
+  pub expires_at: Option<Timestamp>, // End of the term of a time-boxed role
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,27 +44,49 @@ pub struct PromoteAgentInput {
   pub grant_hash: Option<ActionHash>,
 } // Whether to validate private data requirements
 
+// Cross-zome call structure for governance promotion validation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidateAgentForPromotionInput {
+  pub target_role: String,
+  pub target_agent: AgentPubKey,
+  pub grant_hash: Option<ActionHash>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
-pub struct RolePromotionRequest {
+pub struct RolePromotionRequestInput {
   pub target_role: String,
   pub justification: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PromotionRequestRecord {
+  pub action_hash: ActionHash,       // Original creation hash
+  pub request: RolePromotionRequest, // Latest version
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ApprovePromotionInput {
   pub request_hash: ActionHash,
-  pub target_agent: AgentPubKey,
-  pub target_role: String,
   pub approval_notes: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RejectPromotionInput {
+  pub request_hash: ActionHash,
+  pub reason: String,
+}
+
 #[hdk_extern]
 pub fn assign_person_role(input: PersonRoleInput) -> ExternResult<Record> {
   let agent_info = agent_info()?;
 
+  let resolved = resolve_role(&input.role_name)?.ok_or(PersonError::RoleNotFound(format!(
+    "'{}' is not in the role registry",
+    input.role_name
+  )))?;
+
   // Check if this is a specialized role that requires governance validation
-  let specialized_roles = ["Transport Agent", "Repair Agent", "Storage Agent"];
-  if specialized_roles.contains(&input.role_name.as_str()) {
+  if resolved.definition.validation_requirements.is_some() {
     // Call governance zome for specialized role validation
     // This implements REQ-GOV-04: Specialized Role Validation
     let _validation_result = call(
@@ -80,6 +109,9 @@ pub fn assign_person_role(input: PersonRoleInput) -> ExternResult<Record> {
     assigned_to: input.agent_pubkey.clone(),
     assigned_by: agent_info.agent_initial_pubkey,
     assigned_at: sys_time()?,
+    role_definition: resolved.definition_hash,
+    assigner_role: find_assigner_role(&resolved.definition.assignable_by)?,
+    expires_at: input.expires_at,
   };
 
   let role_hash = create_entry(&EntryTypes::PersonRole(role.clone()))?;
@@ -100,28 +132,35 @@ pub fn assign_person_role(input: PersonRoleInput) -> ExternResult<Record> {
   Ok(record)
 }
 
+/// The calling agent's own active role that lets it assign roles `assignable_by` this level,
+/// which integrity checks the assignment against. None when any member may assign the role,
+/// or when the agent holds no such role (only the progenitor may then assign it).
+pub(crate) fn find_assigner_role(assignable_by: &str) -> ExternResult<Option<ActionHash>> {
+  let required_rank = capability_rank(assignable_by);
+  if required_rank == Some(0) {
+    return Ok(None);
+  }
+
+  let agent = agent_info()?.agent_initial_pubkey;
+  for held in get_held_roles(agent.clone())? {
+    if held.role.assigned_to != agent || held.status != RoleStatus::Active {
+      continue;
+    }
+    let Some(level) = role_capability_level(&held.role)? else {
+      continue;
+    };
+    if capability_rank(&level) >= required_rank {
+      return Ok(Some(held.latest_hash));
+    }
+  }
+  Ok(None)
+}
+
 #[hdk_extern]
 pub fn get_latest_person_role_record(
   original_action_hash: ActionHash,
 ) -> ExternResult<Option<Record>> {
-  let links_query = LinkQuery::try_new(original_action_hash.clone(), LinkTypes::RoleUpdates)?;
-  let links = get_links(links_query, GetStrategy::default())?;
-  let latest_link = links
-    .into_iter()
-    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-  let latest_role_hash = match latest_link {
-    Some(link) => {
-      link
-        .target
-        .clone()
-        .into_action_hash()
-        .ok_or(PersonError::EntryOperationFailed(
-          "Invalid action hash in link".to_string(),
-        ))?
-    }
-    None => original_action_hash.clone(),
-  };
-  get(latest_role_hash, GetOptions::default())
+  get_latest_record(original_action_hash)
 }
 
 #[hdk_extern]
@@ -154,12 +193,21 @@ pub fn update_person_role(input: UpdatePersonRoleInput) -> ExternResult<Record>
     return Err(PersonError::NotAuthor.into());
   }
 
+  let resolved =
+    resolve_role(&input.updated_role.role_name)?.ok_or(PersonError::RoleNotFound(format!(
+      "'{}' is not in the role registry",
+      input.updated_role.role_name
+    )))?;
+
   let updated_role = PersonRole {
     role_name: input.updated_role.role_name,
     description: input.updated_role.description,
     assigned_to: input.updated_role.agent_pubkey,
     assigned_by: agent_info()?.agent_initial_pubkey,
     assigned_at: sys_time()?,
+    role_definition: resolved.definition_hash,
+    assigner_role: find_assigner_role(&resolved.definition.assignable_by)?,
+    expires_at: input.updated_role.expires_at,
   };
 
   let updated_role_hash = update_entry(input.previous_action_hash, &updated_role)?;
@@ -183,28 +231,93 @@ pub struct GetPersonRolesOutput {
   pub roles: Vec<PersonRole>,
 }
 
-#[hdk_extern]
-pub fn get_person_roles(agent_pubkey: AgentPubKey) -> ExternResult<GetPersonRolesOutput> {
+/// A role of an agent with its status
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PersonRoleRecord {
+  pub action_hash: ActionHash, // Original creation hash
+  pub role: PersonRole,        // Latest version
+  pub status: RoleStatus,
+}
+
+// A role of an agent, as linked from their Person
+pub(crate) struct HeldRole {
+  pub original_hash: ActionHash,
+  pub latest_hash: ActionHash,
+  pub role: PersonRole,
+  pub status: RoleStatus,
+}
+
+pub(crate) fn get_held_roles(agent_pubkey: AgentPubKey) -> ExternResult<Vec<HeldRole>> {
   let mut roles = Vec::new();
 
   // Use the new get_agent_person function for cleaner code
   let person_hash = match get_agent_person(agent_pubkey)? {
     Some(hash) => hash,
-    None => return Ok(GetPersonRolesOutput { roles }),
+    None => return Ok(roles),
   };
 
   let role_links_query = LinkQuery::try_new(person_hash, LinkTypes::PersonToRoles)?;
   let role_links = get_links(role_links_query, GetStrategy::default())?;
 
+  let now = sys_time()?;
   for role_link in role_links {
-    if let Some(action_hash) = role_link.target.into_action_hash() {
-      if let Ok(role) = get_latest_person_role(action_hash) {
-        roles.push(role);
-      }
-    }
+    let Some(original_hash) = role_link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get_latest_record(original_hash.clone())? else {
+      continue;
+    };
+    let Ok(Some(role)) = record.entry().to_app_option::<PersonRole>() else {
+      continue;
+    };
+    let status = role_status(&original_hash, &role, now)?;
+    roles.push(HeldRole {
+      original_hash,
+      latest_hash: record.action_address().clone(),
+      role,
+      status,
+    });
   }
 
-  Ok(GetPersonRolesOutput { roles })
+  Ok(roles)
+}
+
+/// Every role ever assigned to an agent, whatever its status
+#[hdk_extern]
+pub fn get_person_roles(agent_pubkey: AgentPubKey) -> ExternResult<GetPersonRolesOutput> {
+  Ok(GetPersonRolesOutput {
+    roles: get_held_roles(agent_pubkey)?
+      .into_iter()
+      .map(|held| held.role)
+      .collect(),
+  })
+}
+
+/// The roles an agent currently holds: not expired, suspended or revoked
+#[hdk_extern]
+pub fn get_active_person_roles(agent_pubkey: AgentPubKey) -> ExternResult<GetPersonRolesOutput> {
+  Ok(GetPersonRolesOutput {
+    roles: get_held_roles(agent_pubkey)?
+      .into_iter()
+      .filter(|held| held.status == RoleStatus::Active)
+      .map(|held| held.role)
+      .collect(),
+  })
+}
+
+/// An agent's roles with their original hash, to revoke or suspend them, and status
+#[hdk_extern]
+pub fn get_person_role_records(agent_pubkey: AgentPubKey) -> ExternResult<Vec<PersonRoleRecord>> {
+  Ok(
+    get_held_roles(agent_pubkey)?
+      .into_iter()
+      .map(|held| PersonRoleRecord {
+        action_hash: held.original_hash,
+        role: held.role,
+        status: held.status,
+      })
+      .collect(),
+  )
 }
 
 #[hdk_extern]
@@ -213,12 +326,12 @@ pub fn get_my_person_roles(_: ()) -> ExternResult<GetPersonRolesOutput> {
   get_person_roles(agent_info.agent_initial_pubkey)
 }
 
-/// Check if an agent has a specific role capability
+/// Check if an agent currently holds a specific role
 #[hdk_extern]
 pub fn has_person_role_capability(input: (AgentPubKey, String)) -> ExternResult<bool> {
   let (agent_pubkey, required_role) = input;
 
-  let roles_output = get_person_roles(agent_pubkey)?;
+  let roles_output = get_active_person_roles(agent_pubkey)?;
 
   for role in roles_output.roles {
     if role.role_name == required_role {
@@ -229,42 +342,43 @@ pub fn has_person_role_capability(input: (AgentPubKey, String)) -> ExternResult<
   Ok(false)
 }
 
-/// Get agent capability level based on their roles
+/// Get agent capability level: the highest level granted by the definitions of their active roles
 #[hdk_extern]
 pub fn get_person_capability_level(agent_pubkey: AgentPubKey) -> ExternResult<String> {
-  let roles_output = get_person_roles(agent_pubkey)?;
-
-  let mut has_governance_role = false;
-  let mut has_coordination_role = false;
-  let mut has_stewardship_role = false;
+  let roles_output = get_active_person_roles(agent_pubkey)?;
 
+  let mut capability_level = "member".to_string();
   for role in roles_output.roles {
-    match role.role_name.as_str() {
-      "Primary Accountable Agent" => {
-        has_governance_role = true;
-      }
-      "Accountable Agent" => {
-        has_coordination_role = true;
-      }
-      "Transport Agent" | "Repair Agent" | "Storage Agent" => {
-        has_stewardship_role = true;
-      }
-      "Simple Agent" => {
-        // Basic member level - no change to flags
-      }
-      _ => {}
+    let Some(level) = role_capability_level(&role)? else {
+      continue;
+    };
+    if capability_rank(&level) > capability_rank(&capability_level) {
+      capability_level = level;
     }
   }
 
-  if has_governance_role {
-    Ok("governance".to_string())
-  } else if has_coordination_role {
-    Ok("coordination".to_string())
-  } else if has_stewardship_role {
-    Ok("stewardship".to_string())
-  } else {
-    Ok("member".to_string())
+  Ok(capability_level)
+}
+
+/// Capability level a role grants, from the definition it was assigned under
+pub(crate) fn role_capability_level(role: &PersonRole) -> ExternResult<Option<String>> {
+  if let Some(default) = default_role(&role.role_name) {
+    return Ok(Some(default.capability_level.to_string()));
   }
+  let Some(definition_hash) = role.role_definition.clone() else {
+    return Ok(None);
+  };
+  let Some(record) = get(definition_hash, GetOptions::default())? else {
+    return Ok(None);
+  };
+  Ok(
+    record
+      .entry()
+      .to_app_option::<RoleDefinition>()
+      .ok()
+      .flatten()
+      .map(|definition| definition.capability_level),
+  )
 }
 
 // ============================================================================
@@ -277,13 +391,13 @@ pub fn get_person_capability_level(agent_pubkey: AgentPubKey) -> ExternResult<St
 pub fn promote_agent_with_validation(input: PromoteAgentInput) -> ExternResult<Record> {
   let agent_info = agent_info()?;
 
-  // Check that the caller has sufficient authority to promote agents
+  // Check that the caller may assign the role, as integrity will
   let caller_capability = get_person_capability_level(agent_info.agent_initial_pubkey.clone())?;
-  if caller_capability != "governance" && caller_capability != "coordination" {
+  if !may_assign(&caller_capability, &input.target_role)? {
     return Err(
       PersonError::InsufficientCapability(format!(
-        "Need coordination or governance level, have: {}",
-        caller_capability
+        "Insufficient authority to assign {}: {}",
+        input.target_role, caller_capability
       ))
       .into(),
     );
@@ -291,16 +405,9 @@ pub fn promote_agent_with_validation(input: PromoteAgentInput) -> ExternResult<R
 
   // If private data validation is requested, validate with governance zome
   if input.validate_private_data {
-    #[derive(Debug, Clone, Serialize, Deserialize)]
-    struct ValidateInput {
-      target_role: String,
-      target_agent: AgentPubKey,
-      grant_hash: Option<ActionHash>,
-    }
-
     let validation_result: ValidationResult = call_governance_zome(
       "validate_agent_for_promotion",
-      ValidateInput {
+      ValidateAgentForPromotionInput {
         target_role: input.target_role.clone(),
         target_agent: input.target_agent.clone(),
         grant_hash: input.grant_hash,
@@ -328,59 +435,35 @@ pub fn promote_agent_with_validation(input: PromoteAgentInput) -> ExternResult<R
       "Promoted by {}: {}",
       agent_info.agent_initial_pubkey, input.justification
     )),
+    expires_at: None,
   };
 
   assign_person_role(role_input)
 }
 
-// TODO (G13 — Queryable Promotion Requests): This function currently validates the request and
-// then returns a placeholder hash (the hash of the ValidationReceipt). The actual
-// `RolePromotionRequest` is never written to the DHT as a queryable entry, which means
-// authorised approvers have no way to discover pending requests without out-of-band
-// communication.
-//
-// Required changes (see `documentation/implementation_plan.md` Phase 2.4):
-//
-// 1. Define a `RolePromotionRequest` entry type in `zome_person_integrity/src/lib.rs`:
-//    ```rust
-//    pub struct RolePromotionRequest {
-//        pub requesting_agent: AgentPubKey,
-//        pub target_role: String,
-//        pub justification: String,
-//        pub created_at: Timestamp,
-//        pub status: PromotionRequestStatus, // Pending, Approved, Rejected
-//    }
-//    pub enum PromotionRequestStatus { Pending, Approved, Rejected }
-//    ```
-//
-// 2. Store the request as a real DHT entry with these links:
-//    - `AllPendingPromotions` anchor → `request_hash` (so approvers can discover all requests)
-//    - `agent_pubkey` → `request_hash` (so the requesting agent can track their request)
-//    - `request_hash` → `agent_pubkey` (reverse lookup)
-//
-// 3. Implement `get_pending_promotion_requests() -> ExternResult<Vec<RolePromotionRequest>>`
-//    for authorised approvers to query (filtered by their capability level).
-//
-// 4. Update `approve_role_promotion` to change the `PromotionRequestStatus` to `Approved` via
-//    an `update_entry` call, rather than acting on an out-of-band hash.
+// ============================================================================
+// ROLE PROMOTION REQUESTS
+// ============================================================================
 //
-// See `REQ-AGENT-16` in `documentation/requirements/requirements.md` and
-// `agent.md` §2.6 (Known Gaps, G13).
+// A RolePromotionRequest is linked from the requesting agent (AgentToPromotionRequests)
+// and, while pending, from its shard of the "pending_promotions" anchor
+// (AllPendingPromotions), always from its original action hash. Reviewing or withdrawing a
+// request updates its status and removes the pending link. A request can be reviewed by
+// any other agent whose capability level reaches the `assignable_by` level of the role.
+
+const PENDING_PROMOTIONS: &str = "pending_promotions";
+
 /// Request promotion to a higher role
 /// This creates a request that can be approved by authorized agents
 #[hdk_extern]
-pub fn request_role_promotion(input: RolePromotionRequest) -> ExternResult<ActionHash> {
+pub fn request_role_promotion(input: RolePromotionRequestInput) -> ExternResult<ActionHash> {
   let agent_info = agent_info()?;
-  let now = sys_time()?;
 
   // Validate that the target role is a valid promotion
   let current_capability = get_person_capability_level(agent_info.agent_initial_pubkey.clone())?;
-  let target_capability = match input.target_role.as_str() {
-    "Simple Agent" => "member",
-    "Accountable Agent" => "coordination",
-    "Primary Accountable Agent" => "governance",
-    "Transport Agent" | "Repair Agent" | "Storage Agent" => "stewardship",
-    _ => {
+  let target_capability = match resolve_role(&input.target_role)? {
+    Some(resolved) => resolved.definition.capability_level,
+    None => {
       return Err(
         PersonError::InvalidInput(format!("Unknown role type: {}", input.target_role)).into(),
       )
@@ -388,15 +471,8 @@ pub fn request_role_promotion(input: RolePromotionRequest) -> ExternResult<Actio
   };
 
   // Check if this is actually a promotion
-  let promotion_hierarchy = ["member", "stewardship", "coordination", "governance"];
-  let current_level = promotion_hierarchy
-    .iter()
-    .position(|&x| x == current_capability)
-    .unwrap_or(0);
-  let target_level = promotion_hierarchy
-    .iter()
-    .position(|&x| x == target_capability)
-    .unwrap_or(0);
+  let current_level = capability_rank(&current_capability).unwrap_or(0);
+  let target_level = capability_rank(&target_capability).unwrap_or(0);
 
   if target_level <= current_level {
     return Err(
@@ -407,13 +483,28 @@ pub fn request_role_promotion(input: RolePromotionRequest) -> ExternResult<Actio
     );
   }
 
+  let already_pending = get_my_promotion_requests(())?.into_iter().any(|record| {
+    record.request.status == PromotionRequestStatus::Pending
+      && record.request.target_role == input.target_role
+  });
+  if already_pending {
+    return Err(
+      PersonError::InvalidInput(format!(
+        "A promotion to {} is already pending",
+        input.target_role
+      ))
+      .into(),
+    );
+  }
+
   // Check if agent has required private data before making the request
   let validation_result: ValidationResult = call_governance_zome(
     "validate_agent_for_promotion",
-    (
-      input.target_role.clone(),
-      agent_info.agent_initial_pubkey.clone(),
-    ),
+    ValidateAgentForPromotionInput {
+      target_role: input.target_role.clone(),
+      target_agent: agent_info.agent_initial_pubkey.clone(),
+      grant_hash: None,
+    },
   )?;
 
   if !validation_result.is_valid {
@@ -428,42 +519,83 @@ pub fn request_role_promotion(input: RolePromotionRequest) -> ExternResult<Actio
     );
   }
 
-  // Create a promotion request entry (for now, we'll use a simple data structure)
-  // In a full implementation, this would be a new entry type
-  let _request_context = format!(
-    "promotion_request_{}_{}_{}",
+  let request = RolePromotionRequest {
+    requesting_agent: agent_info.agent_initial_pubkey.clone(),
+    target_role: input.target_role,
+    justification: input.justification,
+    created_at: sys_time()?,
+    status: PromotionRequestStatus::Pending,
+    reviewed_by: None,
+    review_notes: None,
+    reviewer_role: None,
+    role_definition: None,
+  };
+  let request_hash = create_entry(&EntryTypes::RolePromotionRequest(request))?;
+
+  create_link(
     agent_info.agent_initial_pubkey,
-    input.target_role.replace(" ", "_").to_lowercase(),
-    now.as_micros()
-  );
+    request_hash.clone(),
+    LinkTypes::AgentToPromotionRequests,
+    (),
+  )?;
+  create_hash_sharded_link(
+    PENDING_PROMOTIONS,
+    request_hash.clone(),
+    LinkTypes::AllPendingPromotions,
+  )?;
 
-  // For now, return a placeholder hash
-  let placeholder_hash = ActionHash::from_raw_36(vec![0; 36]);
-  Ok(placeholder_hash)
+  Ok(request_hash)
+}
+
+/// Pending promotion requests the calling agent may review, oldest first
+#[hdk_extern]
+pub fn get_pending_promotion_requests(_: ()) -> ExternResult<Vec<PromotionRequestRecord>> {
+  let reviewer = agent_info()?.agent_initial_pubkey;
+  let reviewer_level = get_person_capability_level(reviewer.clone())?;
+
+  let mut links = get_hash_sharded_links(PENDING_PROMOTIONS, LinkTypes::AllPendingPromotions)?;
+  links.sort_by_key(|link| link.timestamp);
+
+  let mut requests = Vec::new();
+  for record in resolve_promotion_request_links(links)? {
+    if record.request.status == PromotionRequestStatus::Pending
+      && record.request.requesting_agent != reviewer
+      && may_assign(&reviewer_level, &record.request.target_role)?
+    {
+      requests.push(record);
+    }
+  }
+  Ok(requests)
+}
+
+/// Every promotion request made by the calling agent, whatever its status
+#[hdk_extern]
+pub fn get_my_promotion_requests(_: ()) -> ExternResult<Vec<PromotionRequestRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(
+      agent_info()?.agent_initial_pubkey,
+      LinkTypes::AgentToPromotionRequests,
+    )?,
+    GetStrategy::default(),
+  )?;
+  resolve_promotion_request_links(links)
 }
 
 /// Approve a role promotion request
 /// This function can only be called by agents with sufficient authority
 #[hdk_extern]
 pub fn approve_role_promotion(input: ApprovePromotionInput) -> ExternResult<Record> {
-  let agent_info = agent_info()?;
-
-  // Check authorization
-  let caller_capability = get_person_capability_level(agent_info.agent_initial_pubkey.clone())?;
-  if caller_capability != "governance" && caller_capability != "coordination" {
-    return Err(
-      PersonError::InsufficientCapability(format!(
-        "Insufficient authority to approve promotions: {}",
-        caller_capability
-      ))
-      .into(),
-    );
-  }
+  let record = get_reviewable_request(&input.request_hash)?;
+  let request = &record.request;
 
   // Validate the promotion again to ensure data is still valid
   let validation_result: ValidationResult = call_governance_zome(
     "validate_agent_for_promotion",
-    (input.target_role.clone(), input.target_agent.clone()),
+    ValidateAgentForPromotionInput {
+      target_role: request.target_role.clone(),
+      target_agent: request.requesting_agent.clone(),
+      grant_hash: None,
+    },
   )?;
 
   if !validation_result.is_valid {
@@ -478,16 +610,185 @@ pub fn approve_role_promotion(input: ApprovePromotionInput) -> ExternResult<Reco
     );
   }
 
-  // Create the promotion with validated private data
-  let promotion_input = PromoteAgentInput {
-    target_agent: input.target_agent,
-    target_role: input.target_role,
-    justification: input
-      .approval_notes
-      .unwrap_or("Approved by governance".to_string()),
-    validate_private_data: true,
-    grant_hash: None,
+  // The private data was checked above and the approver's authority by
+  // get_reviewable_request, so the role is assigned directly
+  let role_record = assign_person_role(PersonRoleInput {
+    agent_pubkey: request.requesting_agent.clone(),
+    role_name: request.target_role.clone(),
+    description: Some(format!(
+      "Promoted by {}: {}",
+      agent_info()?.agent_initial_pubkey,
+      input
+        .approval_notes
+        .clone()
+        .unwrap_or("Approved by governance".to_string())
+    )),
+    expires_at: None,
+  })?;
+
+  review_promotion_request(
+    record,
+    PromotionRequestStatus::Approved,
+    input.approval_notes,
+  )?;
+  Ok(role_record)
+}
+
+/// Reject a role promotion request, giving the reason to the requesting agent
+#[hdk_extern]
+pub fn reject_role_promotion(input: RejectPromotionInput) -> ExternResult<PromotionRequestRecord> {
+  if input.reason.trim().is_empty() {
+    return Err(PersonError::InvalidInput("A rejection needs a reason".to_string()).into());
+  }
+  let record = get_reviewable_request(&input.request_hash)?;
+  review_promotion_request(record, PromotionRequestStatus::Rejected, Some(input.reason))
+}
+
+/// Withdraw one of the calling agent's pending promotion requests
+#[hdk_extern]
+pub fn withdraw_role_promotion(request_hash: ActionHash) -> ExternResult<PromotionRequestRecord> {
+  let mut record = get_promotion_request(&request_hash)?;
+  if record.request.requesting_agent != agent_info()?.agent_initial_pubkey {
+    return Err(PersonError::NotAuthor.into());
+  }
+  if record.request.status != PromotionRequestStatus::Pending {
+    return Err(PersonError::InvalidInput("Promotion request is not pending".to_string()).into());
+  }
+
+  record.request.status = PromotionRequestStatus::Withdrawn;
+  update_promotion_request(&record)?;
+  Ok(record)
+}
+
+// The latest version of a request
+fn get_promotion_request(request_hash: &ActionHash) -> ExternResult<PromotionRequestRecord> {
+  let record = get_latest_record(request_hash.clone())?.ok_or(
+    PersonError::EntryOperationFailed("Promotion request not found".to_string()),
+  )?;
+  let request: RolePromotionRequest = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| PersonError::SerializationError(format!("{:?}", e)))?
+    .ok_or(PersonError::EntryOperationFailed(
+      "Promotion request entry not found".to_string(),
+    ))?;
+  Ok(PromotionRequestRecord {
+    action_hash: request_hash.clone(),
+    request,
+  })
+}
+
+// A pending request the calling agent is allowed to review
+fn get_reviewable_request(request_hash: &ActionHash) -> ExternResult<PromotionRequestRecord> {
+  let record = get_promotion_request(request_hash)?;
+  if record.request.status != PromotionRequestStatus::Pending {
+    return Err(PersonError::InvalidInput("Promotion request is not pending".to_string()).into());
+  }
+
+  let reviewer = agent_info()?.agent_initial_pubkey;
+  if record.request.requesting_agent == reviewer {
+    return Err(
+      PersonError::InsufficientCapability(
+        "Agents cannot review their own promotion request".to_string(),
+      )
+      .into(),
+    );
+  }
+  let reviewer_level = get_person_capability_level(reviewer)?;
+  if !may_assign(&reviewer_level, &record.request.target_role)? {
+    return Err(
+      PersonError::InsufficientCapability(format!(
+        "Insufficient authority to review promotions to {}: {}",
+        record.request.target_role, reviewer_level
+      ))
+      .into(),
+    );
+  }
+  Ok(record)
+}
+
+// Whether an agent of `assigner_level` may assign `role_name`: its level must reach the
+// role's `assignable_by` level, which integrity checks the assigner's role against
+fn may_assign(assigner_level: &str, role_name: &str) -> ExternResult<bool> {
+  let Some(role) = resolve_role(role_name)? else {
+    return Ok(false);
   };
+  Ok(capability_rank(assigner_level) >= capability_rank(&role.definition.assignable_by))
+}
+
+fn review_promotion_request(
+  mut record: PromotionRequestRecord,
+  status: PromotionRequestStatus,
+  notes: Option<String>,
+) -> ExternResult<PromotionRequestRecord> {
+  let role = resolve_role(&record.request.target_role)?.ok_or(PersonError::RoleNotFound(
+    format!("Role '{}' is not in the role registry", record.request.target_role),
+  ))?;
+  record.request.status = status;
+  record.request.reviewed_by = Some(agent_info()?.agent_initial_pubkey);
+  record.request.review_notes = notes;
+  record.request.reviewer_role = find_assigner_role(&role.definition.assignable_by)?;
+  record.request.role_definition = role.definition_hash;
+  update_promotion_request(&record)?;
+
+  let signal = Signal::PromotionRequestReviewed {
+    request_hash: record.action_hash.clone(),
+    request: record.request.clone(),
+  };
+  emit_signal(&signal)?;
+  send_remote_signal(&signal, vec![record.request.requesting_agent.clone()])?;
+  Ok(record)
+}
 
-  promote_agent_with_validation(promotion_input)
+// Record the new status as the one update of the request's create, and take the request
+// off the pending anchor
+fn update_promotion_request(record: &PromotionRequestRecord) -> ExternResult<()> {
+  let Some(Details::Record(details)) =
+    get_details(record.action_hash.clone(), GetOptions::default())?
+  else {
+    return Err(
+      PersonError::EntryOperationFailed("Promotion request not found".to_string()).into(),
+    );
+  };
+  if !details.updates.is_empty() {
+    return Err(PersonError::InvalidInput("Promotion request is not pending".to_string()).into());
+  }
+  update_entry(record.action_hash.clone(), &record.request)?;
+
+  let shard = hash_shard(PENDING_PROMOTIONS, &record.action_hash).path_entry_hash()?;
+  let links = get_links(
+    LinkQuery::try_new(shard, LinkTypes::AllPendingPromotions)?,
+    GetStrategy::default(),
+  )?;
+  for link in links {
+    if link.target.clone().into_action_hash().as_ref() == Some(&record.action_hash) {
+      delete_link(link.create_link_hash, GetOptions::default())?;
+    }
+  }
+  Ok(())
+}
+
+fn resolve_promotion_request_links(links: Vec<Link>) -> ExternResult<Vec<PromotionRequestRecord>> {
+  let mut requests: Vec<PromotionRequestRecord> = Vec::new();
+  for link in links {
+    let Some(action_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    if requests
+      .iter()
+      .any(|record| record.action_hash == action_hash)
+    {
+      continue;
+    }
+    let Some(record) = get_latest_record(action_hash.clone())? else {
+      continue;
+    };
+    if let Ok(Some(request)) = record.entry().to_app_option::<RolePromotionRequest>() {
+      requests.push(PromotionRequestRecord {
+        action_hash,
+        request,
+      });
+    }
+  }
+  Ok(requests)
 }
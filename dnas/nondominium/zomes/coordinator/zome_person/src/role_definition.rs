@@ -0,0 +1,230 @@
+use crate::role::find_assigner_role;
+use crate::PersonError;
+use hdk::prelude::*;
+use nondominium_shared::update_chain::get_latest_record;
+use zome_person_integrity::*;
+
+// ============================================================================
+// Role registry
+// ============================================================================
+//
+// Role definitions are linked from the "role_definitions" anchor, tagged with their role
+// name, from their original action hash. Any agent may propose a role; a governance-level
+// agent ratifies it by updating its status, citing their own governance level role, after
+// which it can be assigned. Ratification is the only update integrity accepts. Every agent
+// seeds the default roles at init, so the same entries may be linked several times; the
+// earliest ratified link for a name wins. Default roles cannot be redefined (integrity),
+// and resolve from the built-in table even before the registry has synced.
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RoleDefinitionRecord {
+  pub action_hash: ActionHash,    // Original creation hash
+  pub definition: RoleDefinition, // Latest version
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RoleDefinitionInput {
+  pub role_name: String,
+  pub capability_level: String,
+  pub description: Option<String>,
+  pub validation_requirements: Option<String>,
+  pub assignable_by: String,
+}
+
+/// A role name resolved against the registry.
+#[derive(Debug)]
+pub(crate) struct ResolvedRole {
+  pub definition: RoleDefinition,
+  pub definition_hash: Option<ActionHash>, // Ratified version; None for default roles
+}
+
+fn role_definitions_path() -> ExternResult<EntryHash> {
+  Path::from("role_definitions").path_entry_hash()
+}
+
+/// Create any default role missing from the registry; called from `init`.
+pub fn seed_default_roles() -> ExternResult<()> {
+  let registered: Vec<String> = get_all_role_definitions(())?
+    .into_iter()
+    .map(|record| record.definition.role_name)
+    .collect();
+  for role in DEFAULT_ROLES.iter() {
+    if !registered.iter().any(|name| name == role.role_name) {
+      register_role_definition(RoleDefinition::from(role))?;
+    }
+  }
+  Ok(())
+}
+
+fn register_role_definition(definition: RoleDefinition) -> ExternResult<RoleDefinitionRecord> {
+  let action_hash = create_entry(&EntryTypes::RoleDefinition(definition.clone()))?;
+  create_link(
+    role_definitions_path()?,
+    action_hash.clone(),
+    LinkTypes::AllRoleDefinitions,
+    LinkTag::new(definition.role_name.as_bytes()),
+  )?;
+  Ok(RoleDefinitionRecord {
+    action_hash,
+    definition,
+  })
+}
+
+/// Propose a community-defined role. Its name must not already be in the registry.
+#[hdk_extern]
+pub fn propose_role_definition(input: RoleDefinitionInput) -> ExternResult<RoleDefinitionRecord> {
+  if default_role(&input.role_name).is_some()
+    || get_role_definition(input.role_name.clone())?.is_some()
+  {
+    return Err(
+      PersonError::InvalidInput(format!("Role '{}' is already defined", input.role_name)).into(),
+    );
+  }
+  register_role_definition(RoleDefinition {
+    role_name: input.role_name,
+    capability_level: input.capability_level,
+    description: input.description,
+    validation_requirements: input.validation_requirements,
+    assignable_by: input.assignable_by,
+    status: RoleDefinitionStatus::Proposed,
+    ratifier_role: None,
+  })
+}
+
+/// Ratify a proposed role, making it assignable. Only governance-level agents may ratify.
+#[hdk_extern]
+pub fn ratify_role_definition(action_hash: ActionHash) -> ExternResult<RoleDefinitionRecord> {
+  let Some(ratifier_role) = find_assigner_role("governance")? else {
+    return Err(
+      PersonError::InsufficientCapability(
+        "Need an active governance level role to ratify roles".to_string(),
+      )
+      .into(),
+    );
+  };
+
+  let record = get_latest_record(action_hash.clone())?.ok_or(PersonError::RoleNotFound(
+    "Role definition not found".to_string(),
+  ))?;
+  let mut definition: RoleDefinition = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| PersonError::SerializationError(format!("{:?}", e)))?
+    .ok_or(PersonError::RoleNotFound(
+      "Role definition entry not found".to_string(),
+    ))?;
+  if definition.status != RoleDefinitionStatus::Proposed {
+    return Err(
+      PersonError::InvalidInput(format!(
+        "Role '{}' is already ratified",
+        definition.role_name
+      ))
+      .into(),
+    );
+  }
+  if get_role_definition(definition.role_name.clone())?.is_some() {
+    return Err(
+      PersonError::InvalidInput(format!(
+        "Another role '{}' was ratified first",
+        definition.role_name
+      ))
+      .into(),
+    );
+  }
+
+  definition.status = RoleDefinitionStatus::Ratified;
+  definition.ratifier_role = Some(ratifier_role);
+  update_entry(record.action_address().clone(), &definition)?;
+  Ok(RoleDefinitionRecord {
+    action_hash,
+    definition,
+  })
+}
+
+/// The ratified role with this name, default roles included.
+#[hdk_extern]
+pub fn get_role_definition(role_name: String) -> ExternResult<Option<RoleDefinitionRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(role_definitions_path()?, LinkTypes::AllRoleDefinitions)?
+      .tag_prefix(LinkTag::new(role_name.as_bytes())),
+    GetStrategy::default(),
+  )?;
+  Ok(
+    resolve_role_definition_links(links, RoleDefinitionStatus::Ratified)?
+      .into_iter()
+      .find(|record| record.definition.role_name == role_name),
+  )
+}
+
+/// Every ratified role, one per name.
+#[hdk_extern]
+pub fn get_all_role_definitions(_: ()) -> ExternResult<Vec<RoleDefinitionRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(role_definitions_path()?, LinkTypes::AllRoleDefinitions)?,
+    GetStrategy::default(),
+  )?;
+  resolve_role_definition_links(links, RoleDefinitionStatus::Ratified)
+}
+
+/// Roles proposed and awaiting ratification.
+#[hdk_extern]
+pub fn get_proposed_role_definitions(_: ()) -> ExternResult<Vec<RoleDefinitionRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(role_definitions_path()?, LinkTypes::AllRoleDefinitions)?,
+    GetStrategy::default(),
+  )?;
+  resolve_role_definition_links(links, RoleDefinitionStatus::Proposed)
+}
+
+/// The definition of a role name, from the built-in default roles or the registry.
+pub(crate) fn resolve_role(role_name: &str) -> ExternResult<Option<ResolvedRole>> {
+  if let Some(role) = default_role(role_name) {
+    return Ok(Some(ResolvedRole {
+      definition: RoleDefinition::from(role),
+      definition_hash: None,
+    }));
+  }
+  let Some(record) = get_role_definition(role_name.to_string())? else {
+    return Ok(None);
+  };
+  Ok(Some(ResolvedRole {
+    definition: record.definition,
+    definition_hash: get_latest_record(record.action_hash)?
+      .map(|latest| latest.action_address().clone()),
+  }))
+}
+
+// Earliest link first, keeping one definition per name; proposals are not deduplicated
+fn resolve_role_definition_links(
+  mut links: Vec<Link>,
+  status: RoleDefinitionStatus,
+) -> ExternResult<Vec<RoleDefinitionRecord>> {
+  links.sort_by_key(|link| link.timestamp);
+  let mut records: Vec<RoleDefinitionRecord> = Vec::new();
+  for link in links {
+    let Some(action_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get_latest_record(action_hash.clone())? else {
+      continue;
+    };
+    let Ok(Some(definition)) = record.entry().to_app_option::<RoleDefinition>() else {
+      continue;
+    };
+    if definition.status != status {
+      continue;
+    }
+    let duplicate = records.iter().any(|existing| {
+      existing.action_hash == action_hash
+        || (status == RoleDefinitionStatus::Ratified
+          && existing.definition.role_name == definition.role_name)
+    });
+    if !duplicate {
+      records.push(RoleDefinitionRecord {
+        action_hash,
+        definition,
+      });
+    }
+  }
+  Ok(records)
+}
@@ -0,0 +1,168 @@
+use crate::role::{find_assigner_role, get_latest_person_role};
+use crate::role_definition::resolve_role;
+use crate::PersonError;
+use hdk::prelude::*;
+use zome_person_integrity::*;
+
+// ============================================================================
+// Role expiry, revocation and suspension
+// ============================================================================
+//
+// A PersonRole ends when its `expires_at` passes or when it is revoked. RoleRevocation
+// entries are linked from the role's original action hash (RoleRevocations); folded in
+// the order of their action timestamps, which unlike the author-set `revoked_at` cannot
+// be post- or backdated, they give the role's status: a Revoke is final, a Suspend holds until a
+// Reinstate. Agents with authority to assign a role may suspend, reinstate or revoke it;
+// holders may only revoke their own roles. Capability checks count Active roles only.
+
+/// Status of a PersonRole, from its expiry and revocations
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum RoleStatus {
+  Active,
+  Expired,
+  Suspended,
+  Revoked,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RoleRevocationInput {
+  pub person_role: ActionHash, // Original action hash of the role
+  pub reason: String,
+}
+
+/// End a role for good. Holders may revoke their own roles.
+#[hdk_extern]
+pub fn revoke_person_role(input: RoleRevocationInput) -> ExternResult<Record> {
+  record_role_revocation(input, RoleRevocationKind::Revoke)
+}
+
+/// Pause an active role until it is reinstated.
+#[hdk_extern]
+pub fn suspend_person_role(input: RoleRevocationInput) -> ExternResult<Record> {
+  record_role_revocation(input, RoleRevocationKind::Suspend)
+}
+
+/// Lift the suspension of a role.
+#[hdk_extern]
+pub fn reinstate_person_role(input: RoleRevocationInput) -> ExternResult<Record> {
+  record_role_revocation(input, RoleRevocationKind::Reinstate)
+}
+
+fn record_role_revocation(
+  input: RoleRevocationInput,
+  kind: RoleRevocationKind,
+) -> ExternResult<Record> {
+  if input.reason.trim().is_empty() {
+    return Err(PersonError::InvalidInput("A reason is required".to_string()).into());
+  }
+
+  let role = get_latest_person_role(input.person_role.clone())?;
+  let now = sys_time()?;
+  let status = role_status(&input.person_role, &role, now)?;
+  let allowed = match kind {
+    RoleRevocationKind::Revoke => status != RoleStatus::Revoked,
+    RoleRevocationKind::Suspend => status == RoleStatus::Active,
+    RoleRevocationKind::Reinstate => status == RoleStatus::Suspended,
+  };
+  if !allowed {
+    return Err(
+      PersonError::InvalidInput(format!(
+        "Cannot {:?} role '{}' while it is {:?}",
+        kind, role.role_name, status
+      ))
+      .into(),
+    );
+  }
+
+  let agent = agent_info()?.agent_initial_pubkey;
+  let revoker_role = if role.assigned_to == agent {
+    None
+  } else {
+    match resolve_role(&role.role_name)? {
+      Some(resolved) => find_assigner_role(&resolved.definition.assignable_by)?,
+      None => None,
+    }
+  };
+
+  let revocation = RoleRevocation {
+    person_role: input.person_role.clone(),
+    kind,
+    reason: input.reason,
+    revoked_by: agent,
+    revoker_role,
+    revoked_at: now,
+  };
+  let revocation_hash = create_entry(&EntryTypes::RoleRevocation(revocation))?;
+  create_link(
+    input.person_role,
+    revocation_hash.clone(),
+    LinkTypes::RoleRevocations,
+    (),
+  )?;
+
+  get(revocation_hash, GetOptions::default())?.ok_or(
+    PersonError::EntryOperationFailed("Failed to retrieve created revocation".to_string()).into(),
+  )
+}
+
+/// The revocations, suspensions and reinstatements of a role, oldest action first. Revocations
+/// of another role linked from this one are ignored.
+#[hdk_extern]
+pub fn get_role_revocations(person_role: ActionHash) -> ExternResult<Vec<RoleRevocation>> {
+  let links = get_links(
+    LinkQuery::try_new(person_role.clone(), LinkTypes::RoleRevocations)?,
+    GetStrategy::default(),
+  )?;
+  let mut revocations = Vec::new();
+  for link in links {
+    let Some(action_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get(action_hash.clone(), GetOptions::default())? else {
+      continue;
+    };
+    if let Ok(Some(revocation)) = record.entry().to_app_option::<RoleRevocation>() {
+      if revocation.person_role == person_role {
+        revocations.push((record.action().timestamp(), action_hash, revocation));
+      }
+    }
+  }
+  revocations.sort_by(|(a_time, a_hash, _), (b_time, b_hash, _)| {
+    (a_time, a_hash).cmp(&(b_time, b_hash))
+  });
+  Ok(
+    revocations
+      .into_iter()
+      .map(|(_, _, revocation)| revocation)
+      .collect(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_person_role_status(person_role: ActionHash) -> ExternResult<RoleStatus> {
+  let role = get_latest_person_role(person_role.clone())?;
+  role_status(&person_role, &role, sys_time()?)
+}
+
+/// Status of a role (latest version `role` of `original_hash`) at time `now`
+pub(crate) fn role_status(
+  original_hash: &ActionHash,
+  role: &PersonRole,
+  now: Timestamp,
+) -> ExternResult<RoleStatus> {
+  let mut suspended = false;
+  for revocation in get_role_revocations(original_hash.clone())? {
+    match revocation.kind {
+      RoleRevocationKind::Revoke => return Ok(RoleStatus::Revoked),
+      RoleRevocationKind::Suspend => suspended = true,
+      RoleRevocationKind::Reinstate => suspended = false,
+    }
+  }
+  if suspended {
+    return Ok(RoleStatus::Suspended);
+  }
+  match role.expires_at {
+    Some(expires_at) if expires_at <= now => Ok(RoleStatus::Expired),
+    _ => Ok(RoleStatus::Active),
+  }
+}
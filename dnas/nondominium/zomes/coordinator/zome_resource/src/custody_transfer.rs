@@ -0,0 +1,438 @@
+use crate::economic_resource::{ensure_latest_version, move_resource_link};
+use crate::governance_check::enforce_governance_rules;
+use crate::ResourceError;
+use hdk::prelude::*;
+use nondominium_shared::call_governance_zome;
+use nondominium_shared::io::governance::LogEconomicEventInput;
+use nondominium_shared::io::resource::EvaluateResourceGovernanceInput;
+use nondominium_shared::rules::GovernedOperation;
+use nondominium_shared::types::VfAction;
+use nondominium_shared::update_chain::get_original_action_hash;
+use zome_resource_integrity::*;
+
+// ============================================================================
+// Two-step custody transfer
+// ============================================================================
+//
+// 1. The current custodian calls `propose_custody_transfer`; nothing changes hands yet.
+// 2. The proposed custodian calls `accept_custody_transfer`, which in a single zome call
+//    commits the CustodyTransferAcceptance, the EconomicResource update naming them
+//    custodian, and (via zome_gouvernance) the TransferCustody EconomicEvent with its
+//    CustodyTransfer / CustodyAcceptance PPR pair. Any failure aborts the whole call.
+// 3. Until accepted, the proposer may `cancel_custody_transfer` and the recipient may
+//    `decline_custody_transfer`; both delete the proposal.
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProposeCustodyTransferInput {
+  pub resource_hash: ActionHash, // Latest EconomicResource version
+  pub new_custodian: AgentPubKey,
+  pub note: Option<String>,
+  pub request_contact_info: Option<bool>, // Whether to auto-request private data for coordination
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CustodyTransferProposalRecord {
+  pub proposal_hash: ActionHash,
+  pub proposal: CustodyTransferProposal,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AcceptCustodyTransferOutput {
+  pub updated_resource_hash: ActionHash,
+  pub updated_resource: EconomicResource,
+  pub acceptance_hash: ActionHash,
+  pub event_hash: ActionHash,
+}
+
+// Subset of zome_gouvernance's LogEconomicEventOutput used here.
+#[derive(Serialize, Deserialize, Debug)]
+struct LoggedEconomicEvent {
+  pub event_hash: ActionHash,
+}
+
+#[hdk_extern]
+pub fn propose_custody_transfer(
+  input: ProposeCustodyTransferInput,
+) -> ExternResult<CustodyTransferProposalRecord> {
+  open_custody_proposal(input, None)
+}
+
+/// Propose a custody transfer, optionally on behalf of the Transfer EconomicEvent that
+/// requested it (see `apply_economic_event`).
+pub(crate) fn open_custody_proposal(
+  input: ProposeCustodyTransferInput,
+  transfer_event: Option<ActionHash>,
+) -> ExternResult<CustodyTransferProposalRecord> {
+  let agent_info = agent_info()?;
+
+  let resource = get_resource_version(&input.resource_hash)?;
+
+  // Verify the calling agent is the current custodian
+  if resource.custodian != agent_info.agent_initial_pubkey {
+    return Err(ResourceError::NotCustodian.into());
+  }
+  // A superseded version could never be accepted
+  ensure_latest_version(&input.resource_hash)?;
+
+  if input.new_custodian == agent_info.agent_initial_pubkey {
+    return Err(
+      ResourceError::InvalidInput("Cannot transfer custody to yourself".to_string()).into(),
+    );
+  }
+
+  if resource.state == OperationalState::PendingValidation {
+    return Err(
+      ResourceError::InvalidInput(
+        "Resource is pending validation and cannot be transferred".to_string(),
+      )
+      .into(),
+    );
+  }
+
+  // A version is offered to one agent at a time, so that only one can accept it. A declined
+  // proposal was deleted on the recipient's chain only; integrity still counts it as open on
+  // ours until we delete it too.
+  for (proposal_hash, declined) in own_open_proposals(&input.resource_hash)? {
+    if !declined {
+      return Err(
+        ResourceError::InvalidInput(
+          "Resource already has an open custody transfer proposal; cancel it first".to_string(),
+        )
+        .into(),
+      );
+    }
+    delete_entry(proposal_hash)?;
+  }
+
+  // Governance rules (transfer_conditions) are enforced on acceptance, against the
+  // incoming custodian.
+  // TODO: In Phase 2, check that the calling agent has restricted_access capability
+
+  // If requested, create a private data access request for coordination
+  // This allows the new custodian to request contact info from the current custodian
+  if input.request_contact_info.unwrap_or(true) {
+    // We can't directly create a request on behalf of another agent
+    // This is logged for now - in practice, the UI would handle this workflow
+    debug!(
+      "Custody transfer proposed from {} to {} for resource {}. New custodian should request contact info for coordination.",
+      agent_info.agent_initial_pubkey,
+      input.new_custodian,
+      input.resource_hash
+    );
+  }
+
+  let proposal = CustodyTransferProposal {
+    resource_hash: input.resource_hash.clone(),
+    current_custodian: agent_info.agent_initial_pubkey.clone(),
+    proposed_custodian: input.new_custodian.clone(),
+    note: input.note,
+    proposed_at: sys_time()?,
+    transfer_event,
+  };
+
+  let proposal_hash = create_entry(&EntryTypes::CustodyTransferProposal(proposal.clone()))?;
+
+  create_link(
+    input.resource_hash,
+    proposal_hash.clone(),
+    LinkTypes::ResourceToCustodyProposals,
+    (),
+  )?;
+  create_link(
+    input.new_custodian,
+    proposal_hash.clone(),
+    LinkTypes::AgentToIncomingCustodyProposals,
+    (),
+  )?;
+  create_link(
+    agent_info.agent_initial_pubkey,
+    proposal_hash.clone(),
+    LinkTypes::AgentToOutgoingCustodyProposals,
+    (),
+  )?;
+
+  Ok(CustodyTransferProposalRecord {
+    proposal_hash,
+    proposal,
+  })
+}
+
+#[hdk_extern]
+pub fn accept_custody_transfer(
+  proposal_hash: ActionHash,
+) -> ExternResult<AcceptCustodyTransferOutput> {
+  let agent_info = agent_info()?;
+
+  let proposal = get_pending_proposal(&proposal_hash)?;
+  if proposal.proposed_custodian != agent_info.agent_initial_pubkey {
+    return Err(ResourceError::NotProposedCustodian.into());
+  }
+
+  // The proposal pins a resource version; refuse if it has been superseded since, or sits on
+  // a branch the custodian forked off a version already handed over
+  if ensure_latest_version(&proposal.resource_hash).is_err() {
+    return Err(
+      ResourceError::InvalidInput(
+        "Resource has changed since the transfer was proposed".to_string(),
+      )
+      .into(),
+    );
+  }
+  let mut resource = get_resource_version(&proposal.resource_hash)?;
+
+  enforce_governance_rules(EvaluateResourceGovernanceInput {
+    resource_hash: proposal.resource_hash.clone(),
+    operation: GovernedOperation::CustodyTransfer,
+    quantity: Some(resource.onhand_quantity),
+    recipient: None,
+    duration_hours: None,
+  })?;
+
+  // The acceptance and the resource update must be consecutive on our chain: integrity
+  // validation of the update checks that its prev_action is this acceptance.
+  let acceptance_hash = create_entry(&EntryTypes::CustodyTransferAcceptance(
+    CustodyTransferAcceptance {
+      proposal_hash: proposal_hash.clone(),
+      accepted_at: sys_time()?,
+    },
+  ))?;
+
+  resource.custodian = agent_info.agent_initial_pubkey.clone();
+  let updated_resource_hash = update_entry(
+    proposal.resource_hash.clone(),
+    &EntryTypes::EconomicResource(resource.clone()),
+  )?;
+
+  create_link(
+    proposal_hash.clone(),
+    acceptance_hash.clone(),
+    LinkTypes::CustodyProposalToAcceptance,
+    (),
+  )?;
+
+  // Resource links are based on the original version
+  let original_hash = get_original_action_hash(proposal.resource_hash.clone())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
+  )?;
+
+  // Move the custodian link from the previous custodian to us
+  move_resource_link(
+    proposal.current_custodian.clone().into(),
+    agent_info.agent_initial_pubkey.clone().into(),
+    LinkTypes::CustodianToResource,
+    &original_hash,
+    &proposal.resource_hash,
+  )?;
+
+  // Record the transfer in the governance zome; this also issues the
+  // CustodyTransfer / CustodyAcceptance PPR pair for the two parties. A handover
+  // requested by a Transfer event is already recorded by that event.
+  let event_hash = match proposal.transfer_event {
+    Some(event_hash) => event_hash,
+    None => {
+      let event: LoggedEconomicEvent = call_governance_zome(
+        "log_economic_event",
+        LogEconomicEventInput {
+          action: VfAction::TransferCustody,
+          provider: proposal.current_custodian.clone(),
+          receiver: agent_info.agent_initial_pubkey,
+          resource_inventoried_as: proposal.resource_hash.clone(),
+          resource_quantity: resource.onhand_quantity, // Custody moves what is on hand
+          note: proposal.note.clone(),
+          commitment_hash: None,
+          generate_pprs: Some(true),
+          input_of: None,
+          to_location: None,
+          resource_unit: Some(resource.unit.clone()),
+          duration_hours: None,
+        },
+      )?;
+      event.event_hash
+    }
+  };
+
+  Ok(AcceptCustodyTransferOutput {
+    updated_resource_hash,
+    updated_resource: resource,
+    acceptance_hash,
+    event_hash,
+  })
+}
+
+#[hdk_extern]
+pub fn decline_custody_transfer(proposal_hash: ActionHash) -> ExternResult<ActionHash> {
+  let proposal = get_pending_proposal(&proposal_hash)?;
+  if proposal.proposed_custodian != agent_info()?.agent_initial_pubkey {
+    return Err(ResourceError::NotProposedCustodian.into());
+  }
+  delete_entry(proposal_hash)
+}
+
+#[hdk_extern]
+pub fn cancel_custody_transfer(proposal_hash: ActionHash) -> ExternResult<ActionHash> {
+  let proposal = get_pending_proposal(&proposal_hash)?;
+  if proposal.current_custodian != agent_info()?.agent_initial_pubkey {
+    return Err(ResourceError::NotCustodian.into());
+  }
+  delete_entry(proposal_hash)
+}
+
+/// Pending custody transfers awaiting the calling agent's acceptance.
+#[hdk_extern]
+pub fn get_incoming_custody_proposals(_: ()) -> ExternResult<Vec<CustodyTransferProposalRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(
+      agent_info()?.agent_initial_pubkey,
+      LinkTypes::AgentToIncomingCustodyProposals,
+    )?,
+    GetStrategy::default(),
+  )?;
+  resolve_pending_proposals(links)
+}
+
+/// Pending custody transfers the calling agent has proposed.
+#[hdk_extern]
+pub fn get_outgoing_custody_proposals(_: ()) -> ExternResult<Vec<CustodyTransferProposalRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(
+      agent_info()?.agent_initial_pubkey,
+      LinkTypes::AgentToOutgoingCustodyProposals,
+    )?,
+    GetStrategy::default(),
+  )?;
+  resolve_pending_proposals(links)
+}
+
+/// Pending custody transfers for a specific resource version.
+#[hdk_extern]
+pub fn get_custody_proposals_for_resource(
+  resource_hash: ActionHash,
+) -> ExternResult<Vec<CustodyTransferProposalRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(resource_hash, LinkTypes::ResourceToCustodyProposals)?,
+    GetStrategy::default(),
+  )?;
+  resolve_pending_proposals(links)
+}
+
+/// Withdraw the calling custodian's proposals for a resource version before a new version
+/// of it is written, as integrity requires. Declined proposals were deleted by the
+/// recipient and are deleted again by us; an accepted proposal means the version has
+/// changed hands.
+pub(crate) fn withdraw_custody_proposals(resource_hash: &ActionHash) -> ExternResult<()> {
+  for (proposal_hash, _) in own_open_proposals(resource_hash)? {
+    let acceptances = get_links(
+      LinkQuery::try_new(
+        proposal_hash.clone(),
+        LinkTypes::CustodyProposalToAcceptance,
+      )?,
+      GetStrategy::default(),
+    )?;
+    if !acceptances.is_empty() {
+      return Err(
+        ResourceError::InvalidInput("Resource has been handed over to a new custodian".to_string())
+          .into(),
+      );
+    }
+    delete_entry(proposal_hash)?;
+  }
+  Ok(())
+}
+
+/// The calling agent's proposals for a resource version that they have not deleted, each
+/// flagged whether the recipient declined it.
+fn own_open_proposals(resource_hash: &ActionHash) -> ExternResult<Vec<(ActionHash, bool)>> {
+  let agent = agent_info()?.agent_initial_pubkey;
+  let links = get_links(
+    LinkQuery::try_new(resource_hash.clone(), LinkTypes::ResourceToCustodyProposals)?,
+    GetStrategy::default(),
+  )?;
+  let mut proposals = Vec::new();
+  for link in links {
+    let Some(proposal_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(Details::Record(details)) = get_details(proposal_hash.clone(), GetOptions::default())?
+    else {
+      continue;
+    };
+    if *details.record.action().author() != agent
+      || details
+        .deletes
+        .iter()
+        .any(|delete| *delete.action().author() == agent)
+    {
+      continue;
+    }
+    proposals.push((proposal_hash, !details.deletes.is_empty()));
+  }
+  Ok(proposals)
+}
+
+fn get_resource_version(resource_hash: &ActionHash) -> ExternResult<EconomicResource> {
+  let record = get(resource_hash.clone(), GetOptions::default())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
+  )?;
+  record
+    .entry()
+    .to_app_option()
+    .map_err(|e| ResourceError::SerializationError(format!("Failed to deserialize: {:?}", e)))?
+    .ok_or(
+      ResourceError::EconomicResourceNotFound("Invalid EconomicResource entry".to_string()).into(),
+    )
+}
+
+/// Returns the proposal if it still exists (not cancelled or declined) and has not been accepted.
+fn get_pending_proposal(proposal_hash: &ActionHash) -> ExternResult<CustodyTransferProposal> {
+  let not_found = || {
+    ResourceError::CustodyProposalNotFound(
+      "Proposal was cancelled, declined or never existed".to_string(),
+    )
+  };
+  let Some(Details::Record(details)) = get_details(proposal_hash.clone(), GetOptions::default())?
+  else {
+    return Err(not_found().into());
+  };
+  // Either party deleting the proposal closes it, whoever did so first
+  if !details.deletes.is_empty() {
+    return Err(not_found().into());
+  }
+  let proposal: CustodyTransferProposal = details
+    .record
+    .entry()
+    .to_app_option()
+    .map_err(|e| ResourceError::SerializationError(format!("Failed to deserialize: {:?}", e)))?
+    .ok_or(ResourceError::CustodyProposalNotFound(
+      "Invalid CustodyTransferProposal entry".to_string(),
+    ))?;
+
+  let acceptances = get_links(
+    LinkQuery::try_new(proposal_hash.clone(), LinkTypes::CustodyProposalToAcceptance)?,
+    GetStrategy::default(),
+  )?;
+  if !acceptances.is_empty() {
+    return Err(
+      ResourceError::CustodyProposalNotFound("Proposal has already been accepted".to_string())
+        .into(),
+    );
+  }
+
+  Ok(proposal)
+}
+
+fn resolve_pending_proposals(links: Vec<Link>) -> ExternResult<Vec<CustodyTransferProposalRecord>> {
+  let mut proposals = Vec::new();
+  for link in links {
+    let Some(proposal_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Ok(proposal) = get_pending_proposal(&proposal_hash) else {
+      continue;
+    };
+    proposals.push(CustodyTransferProposalRecord {
+      proposal_hash,
+      proposal,
+    });
+  }
+  Ok(proposals)
+}
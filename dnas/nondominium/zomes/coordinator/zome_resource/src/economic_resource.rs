@@ -1,14 +1,55 @@
+use crate::custody_transfer::withdraw_custody_proposals;
+use crate::governance_check::enforce_governance_rules;
+use crate::location::relink_resource_location;
+use crate::resource_specification::latest_resource_specification;
+use crate::unit::{convert_quantity, ConvertQuantityInput};
 use crate::ResourceError;
 use hdk::prelude::*;
+use nondominium_shared::call_governance_zome;
+pub use nondominium_shared::io::governance::{
+  ResourceValidationOutcome, ValidateNewResourceInput, ValidateNewResourceOutput,
+};
+use nondominium_shared::io::resource::EvaluateResourceGovernanceInput;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use nondominium_shared::rules::GovernedOperation;
+use nondominium_shared::types::ResourceValidationStatus;
+use nondominium_shared::update_chain::{get_original_action_hash, newest_update};
 use zome_resource_integrity::*;
 
-// Cross-zome call structure for governance validation
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ValidateNewResourceInput {
-  pub resource_hash: ActionHash,
-  pub resource_spec_hash: ActionHash,
-  pub creator: AgentPubKey,
-  pub validation_scheme: String,
+pub const PROMOTE_VALIDATED_RESOURCE_FN_NAME: &str = "promote_validated_resource";
+
+fn operational_state_path(state: &OperationalState) -> ExternResult<EntryHash> {
+  Path::from(format!("resource.operational.{:?}", state)).path_entry_hash()
+}
+
+fn resource_lifecycle_stage_path(stage: &LifecycleStage) -> ExternResult<EntryHash> {
+  Path::from(format!("resource.lifecycle.{:?}", stage)).path_entry_hash()
+}
+
+/// Moves a resource's link from `old_base` to `new_base`. Links target the resource's
+/// original action hash; links written before that targeted the version current at the
+/// time, so a link to `previous_version` is removed as well.
+pub(crate) fn move_resource_link(
+  old_base: AnyLinkableHash,
+  new_base: AnyLinkableHash,
+  link_type: LinkTypes,
+  original_hash: &ActionHash,
+  previous_version: &ActionHash,
+) -> ExternResult<()> {
+  let old_links = get_links(
+    LinkQuery::try_new(old_base, link_type)?,
+    GetStrategy::default(),
+  )?;
+  for link in old_links {
+    if let Some(target_hash) = link.target.into_action_hash() {
+      if &target_hash == original_hash || &target_hash == previous_version {
+        delete_link(link.create_link_hash, GetOptions::default())?;
+      }
+    }
+  }
+  create_link(new_base, original_hash.clone(), link_type, ())?;
+  Ok(())
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -16,13 +57,18 @@ pub struct EconomicResourceInput {
   pub spec_hash: ActionHash,
   pub quantity: f64,
   pub unit: String,
-  pub current_location: Option<String>,
+  #[serde(
+    default,
+    deserialize_with = "nondominium_shared::location::deserialize_location"
+  )]
+  pub current_location: Option<Location>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateEconomicResourceOutput {
   pub resource_hash: ActionHash,
   pub resource: EconomicResource,
+  pub validation_hash: ActionHash, // ResourceValidation opened in zome_gouvernance
 }
 
 #[hdk_extern]
@@ -40,28 +86,32 @@ pub fn create_economic_resource(
     return Err(ResourceError::InvalidInput("Unit cannot be empty".to_string()).into());
   }
 
-  // Validate that the specification exists
-  let _spec_record = get(input.spec_hash.clone(), GetOptions::default())?.ok_or(
-    ResourceError::ResourceSpecNotFound("ResourceSpecification not found".to_string()),
-  )?;
+  if let Some(location) = &input.current_location {
+    location.validate().map_err(ResourceError::InvalidInput)?;
+  }
 
+  // Validate that the specification exists; its validation config applies to the resource
+  let (spec_version, spec) = latest_resource_specification(input.spec_hash.clone())?;
+
+  // A new resource is wholly held by its creator: accounted for and on hand alike
   let resource = EconomicResource {
-    quantity: input.quantity,
+    accounting_quantity: input.quantity,
+    onhand_quantity: input.quantity,
     unit: input.unit,
     custodian: agent_info.agent_initial_pubkey.clone(),
     current_location: input.current_location,
-    state: ResourceState::PendingValidation, // New resources start in pending validation state
+    state: OperationalState::PendingValidation, // New resources start in pending validation state
+    lifecycle_stage: LifecycleStage::Prototype,
+    validated_by: None,
   };
 
   let resource_hash = create_entry(&EntryTypes::EconomicResource(resource.clone()))?;
 
   // Create discovery links
-  let path = Path::from("economic_resources");
-  create_link(
-    path.path_entry_hash()?,
+  create_hash_sharded_link(
+    "economic_resources",
     resource_hash.clone(),
     LinkTypes::AllEconomicResources,
-    (),
   )?;
 
   // Link resource to its specification
@@ -71,6 +121,12 @@ pub fn create_economic_resource(
     LinkTypes::SpecificationToResource,
     (),
   )?;
+  create_link(
+    resource_hash.clone(),
+    input.spec_hash.clone(),
+    LinkTypes::ResourceToSpecification,
+    (),
+  )?;
 
   // Link custodian to resource
   create_link(
@@ -88,53 +144,181 @@ pub fn create_economic_resource(
     (),
   )?;
 
-  // TEMPORARILY COMMENTED OUT - Call governance zome to initiate resource validation
-  // This implements REQ-GOV-02: Resource Validation
-  // TODO: Re-enable once cross-zome call issues are resolved
-  // let _validation_result = call(
-  //   CallTargetCell::Local,
-  //   "zome_gouvernance",
-  //   "validate_new_resource".into(),
-  //   None,
-  //   &ValidateNewResourceInput {
-  //     resource_hash: resource_hash.clone(),
-  //     resource_spec_hash: input.spec_hash.clone(),
-  //     creator: agent_info.agent_initial_pubkey.clone(),
-  //     validation_scheme: "simple_approval".to_string(), // TODO: Make configurable
-  //   },
-  // )?;
+  // Faceted state anchors (REQ-NDO-OS-06)
+  create_link(
+    operational_state_path(&resource.state)?,
+    resource_hash.clone(),
+    LinkTypes::ResourcesByOperationalState,
+    (),
+  )?;
+  create_link(
+    resource_lifecycle_stage_path(&resource.lifecycle_stage)?,
+    resource_hash.clone(),
+    LinkTypes::ResourcesByLifecycleStage,
+    (),
+  )?;
+  relink_resource_location(&resource_hash, None, resource.current_location.as_ref())?;
+
+  // Open the resource's validation in the governance zome (REQ-GOV-02). Once enough
+  // validators approve, the resource is promoted via `promote_validated_resource`.
+  let validation: ValidateNewResourceOutput = call_governance_zome(
+    "validate_new_resource",
+    ValidateNewResourceInput {
+      resource_hash: resource_hash.clone(),
+      resource_spec_hash: spec_version,
+      creator: agent_info.agent_initial_pubkey.clone(),
+      validation: spec.validation,
+    },
+  )?;
 
   Ok(CreateEconomicResourceOutput {
     resource_hash,
     resource,
+    validation_hash: validation.validation_hash,
   })
 }
 
+/// Grant every agent access to `promote_validated_resource`; called from `init`. The
+/// governance zome of the validator whose approval completes a ResourceValidation calls
+/// it on the custodian's cell. The function itself only acts on approved validations.
+pub fn grant_resource_promotion_capability() -> ExternResult<()> {
+  create_cap_grant(ZomeCallCapGrant {
+    tag: "resource_promotion".to_string(),
+    access: CapAccess::Unrestricted,
+    functions: GrantedFunctions::Listed(HashSet::from([(
+      zome_info()?.name,
+      FunctionName::from(PROMOTE_VALIDATED_RESOURCE_FN_NAME),
+    )])),
+  })?;
+  Ok(())
+}
+
+/// Promote a PendingValidation resource to Available / Active once its ResourceValidation
+/// is approved. Must run on the custodian's cell; returns `None` when there is nothing to do
+/// (validation still pending, or resource already promoted).
+#[hdk_extern]
+pub fn promote_validated_resource(resource_hash: ActionHash) -> ExternResult<Option<Record>> {
+  let original_hash = get_original_action_hash(resource_hash.clone())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
+  )?;
+  let latest_record = get_latest_economic_resource_record(original_hash.clone())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
+  )?;
+  let mut resource: EconomicResource = latest_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| ResourceError::SerializationError(format!("Failed to deserialize: {:?}", e)))?
+    .ok_or(ResourceError::EconomicResourceNotFound(
+      "Invalid EconomicResource entry".to_string(),
+    ))?;
+
+  if resource.custodian != agent_info()?.agent_initial_pubkey {
+    return Err(ResourceError::NotCustodian.into());
+  }
+  if resource.state != OperationalState::PendingValidation {
+    return Ok(None);
+  }
+
+  let validation: Option<ResourceValidationOutcome> =
+    call_governance_zome("get_resource_validation_outcome", original_hash.clone())?;
+  let Some(validation) = validation else {
+    return Ok(None);
+  };
+  if validation.status != ResourceValidationStatus::Approved {
+    return Ok(None);
+  }
+
+  // The approved validation is the governance decision here, so rules are not re-evaluated;
+  // integrity checks the cited validation
+  let old_state = std::mem::replace(&mut resource.state, OperationalState::Available);
+  let old_stage = std::mem::replace(&mut resource.lifecycle_stage, LifecycleStage::Active);
+  resource.validated_by = Some(validation.validation_hash);
+  let latest_hash = latest_record.action_address().clone();
+
+  let updated_resource_hash = update_entry(
+    latest_hash.clone(),
+    &EntryTypes::EconomicResource(resource.clone()),
+  )?;
+
+  move_resource_link(
+    operational_state_path(&old_state)?.into(),
+    operational_state_path(&resource.state)?.into(),
+    LinkTypes::ResourcesByOperationalState,
+    &original_hash,
+    &latest_hash,
+  )?;
+  if old_stage != resource.lifecycle_stage {
+    move_resource_link(
+      resource_lifecycle_stage_path(&old_stage)?.into(),
+      resource_lifecycle_stage_path(&resource.lifecycle_stage)?.into(),
+      LinkTypes::ResourcesByLifecycleStage,
+      &original_hash,
+      &latest_hash,
+    )?;
+  }
+
+  get(updated_resource_hash, GetOptions::default())
+}
+
+/// Latest version of an EconomicResource, addressed by any of its versions.
+///
+/// Walks the update chain like `get_latest_record`, except where a version was handed over:
+/// the walk then follows the first handover, so updates its previous custodian made to it
+/// afterwards (or a later handover of a withdrawn acceptance) are ignored.
 #[hdk_extern]
 pub fn get_latest_economic_resource_record(
-  original_action_hash: ActionHash,
+  resource_hash: ActionHash,
 ) -> ExternResult<Option<Record>> {
-  let links_query = LinkQuery::try_new(
-    original_action_hash.clone(),
-    LinkTypes::EconomicResourceUpdates,
-  )?;
-  let links = get_links(links_query, GetStrategy::default())?;
-  let latest_link = links
-    .into_iter()
-    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-  let latest_resource_hash = match latest_link {
-    Some(link) => {
-      link
-        .target
-        .clone()
-        .into_action_hash()
-        .ok_or(ResourceError::EntryOperationFailed(
-          "Invalid action hash in link".to_string(),
-        ))?
+  let mut current = resource_hash;
+  loop {
+    let Some(Details::Record(details)) = get_details(current.clone(), GetOptions::default())?
+    else {
+      return Ok(None);
+    };
+    let Ok(Some(resource)) = details.record.entry().to_app_option::<EconomicResource>() else {
+      return Ok(None);
+    };
+
+    // Integrity only lets an incoming custodian update a version they did not hold
+    let first_handover = details
+      .updates
+      .iter()
+      .filter(|update| *update.action().author() != resource.custodian)
+      .map(|update| (update.action().timestamp(), &update.hashed.hash))
+      .min()
+      .map(|(_, hash)| hash);
+    let next = match first_handover {
+      Some(handover) => Some(handover.clone()),
+      None => newest_update(
+        details
+          .updates
+          .iter()
+          .map(|update| (update.action().timestamp(), &update.hashed.hash)),
+      )
+      .cloned(),
+    };
+    match next {
+      Some(next) => current = next,
+      None if details.deletes.is_empty() => return Ok(Some(details.record)),
+      None => return Ok(None),
     }
-    None => original_action_hash.clone(),
-  };
-  get(latest_resource_hash, GetOptions::default())
+  }
+}
+
+/// Fail unless `resource_hash` is the latest version of its EconomicResource; a superseded
+/// version may have changed hands since. Resolved from the original, so that a version on a
+/// branch its custodian forked off after a handover is not mistaken for the latest.
+pub(crate) fn ensure_latest_version(resource_hash: &ActionHash) -> ExternResult<()> {
+  let original_hash = get_original_action_hash(resource_hash.clone())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
+  )?;
+  let latest = get_latest_economic_resource_record(original_hash)?;
+  if latest.as_ref().map(Record::action_address) != Some(resource_hash) {
+    return Err(
+      ResourceError::InvalidInput("Resource has changed since this version".to_string()).into(),
+    );
+  }
+  Ok(())
 }
 
 #[hdk_extern]
@@ -166,47 +350,85 @@ pub struct UpdateEconomicResourceInput {
 
 #[hdk_extern]
 pub fn update_economic_resource(input: UpdateEconomicResourceInput) -> ExternResult<Record> {
-  let original_record = must_get_valid_record(input.original_action_hash.clone())?;
+  ensure_latest_version(&input.previous_action_hash)?;
+  let previous_record = must_get_valid_record(input.previous_action_hash.clone())?;
+
+  // Links hang off the chain's own original, whatever the caller passed
+  let original_hash = get_original_action_hash(input.previous_action_hash.clone())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
+  )?;
+  if original_hash != input.original_action_hash {
+    return Err(
+      ResourceError::InvalidInput(
+        "original_action_hash is not the original of previous_action_hash".to_string(),
+      )
+      .into(),
+    );
+  }
 
-  // Get the original resource to check custodian
-  let original_resource: EconomicResource = original_record
+  // Custody is checked against the version being updated, as integrity validation does
+  let previous_resource: EconomicResource = previous_record
     .entry()
     .to_app_option()
     .map_err(|e| ResourceError::SerializationError(format!("Failed to deserialize: {:?}", e)))?
     .ok_or(ResourceError::EconomicResourceNotFound(
-      "Original resource not found".to_string(),
+      "Previous resource version not found".to_string(),
     ))?;
 
   // Verify the agent is the custodian
   let agent_pubkey = agent_info()?.agent_initial_pubkey;
-  if original_resource.custodian != agent_pubkey {
+  if previous_resource.custodian != agent_pubkey {
     return Err(ResourceError::NotCustodian.into());
   }
 
-  // Validate input
-  if input.updated_resource.quantity <= 0.0 {
-    return Err(ResourceError::InvalidInput("Quantity must be positive".to_string()).into());
+  // Validate input; a recount may find nothing left on hand
+  if !(input.updated_resource.quantity.is_finite() && input.updated_resource.quantity >= 0.0) {
+    return Err(
+      ResourceError::InvalidInput("Quantity must be a number, not negative".to_string()).into(),
+    );
   }
 
   if input.updated_resource.unit.trim().is_empty() {
     return Err(ResourceError::InvalidInput("Unit cannot be empty".to_string()).into());
   }
 
+  if let Some(location) = &input.updated_resource.current_location {
+    location.validate().map_err(ResourceError::InvalidInput)?;
+  }
+
+  enforce_governance_rules(EvaluateResourceGovernanceInput {
+    resource_hash: input.previous_action_hash.clone(),
+    operation: GovernedOperation::ResourceUpdate,
+    quantity: Some(input.updated_resource.quantity),
+    recipient: None,
+    duration_hours: None,
+  })?;
+
+  // A manual edit recounts what is on hand. The accounting quantity only moves through
+  // events, and is converted if the unit changes
+  let accounting_quantity = convert_quantity(ConvertQuantityInput {
+    quantity: previous_resource.accounting_quantity,
+    from_unit: previous_resource.unit.clone(),
+    to_unit: input.updated_resource.unit.clone(),
+  })?;
   let updated_resource = EconomicResource {
-    quantity: input.updated_resource.quantity,
+    accounting_quantity,
+    onhand_quantity: input.updated_resource.quantity,
     unit: input.updated_resource.unit,
-    custodian: original_resource.custodian, // Keep the same custodian
+    custodian: previous_resource.custodian, // Keep the same custodian
     current_location: input.updated_resource.current_location,
-    state: original_resource.state, // Keep the same state unless explicitly changed
+    state: previous_resource.state, // Keep the same state unless explicitly changed
+    lifecycle_stage: previous_resource.lifecycle_stage,
+    validated_by: previous_resource.validated_by,
   };
 
+  withdraw_custody_proposals(&input.previous_action_hash)?;
   let updated_resource_hash = update_entry(input.previous_action_hash, &updated_resource)?;
 
-  create_link(
-    input.original_action_hash,
-    updated_resource_hash.clone(),
-    LinkTypes::EconomicResourceUpdates,
-    (),
+  relink_resource_location(
+    &original_hash,
+    previous_resource.current_location.as_ref(),
+    updated_resource.current_location.as_ref(),
   )?;
 
   let record = get(updated_resource_hash, GetOptions::default())?.ok_or(
@@ -223,16 +445,34 @@ pub struct GetAllEconomicResourcesOutput {
 
 #[hdk_extern]
 pub fn get_all_economic_resources(_: ()) -> ExternResult<GetAllEconomicResourcesOutput> {
-  let path = Path::from("economic_resources");
-  let links_query = LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllEconomicResources)?;
-  let links = get_links(links_query, GetStrategy::default())?;
+  Ok(GetAllEconomicResourcesOutput {
+    resources: latest_resources(all_economic_resource_links()?)?,
+  })
+}
+
+/// One page of `get_all_economic_resources`, in link order.
+#[hdk_extern]
+pub fn get_all_economic_resources_paginated(
+  page: PageRequest,
+) -> ExternResult<Page<EconomicResource>> {
+  let (links, next_cursor) = page.select(all_economic_resource_links()?);
+  Ok(Page {
+    items: latest_resources(links)?,
+    next_cursor,
+  })
+}
+
+fn all_economic_resource_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("economic_resources", LinkTypes::AllEconomicResources)
+}
 
+fn latest_resources(links: Vec<Link>) -> ExternResult<Vec<EconomicResource>> {
   let mut resources = Vec::new();
 
   for link in links {
     if let Some(action_hash) = link.target.into_action_hash() {
-      // Get the record directly since we're now updating links to point to the latest version
-      if let Some(record) = get(action_hash, GetOptions::default())? {
+      // Links target original hashes (older ones a version); both resolve to the latest
+      if let Some(record) = get_latest_economic_resource_record(action_hash)? {
         if let Ok(Some(resource)) = record.entry().to_app_option::<EconomicResource>() {
           resources.push(resource);
         }
@@ -240,7 +480,7 @@ pub fn get_all_economic_resources(_: ()) -> ExternResult<GetAllEconomicResources
     }
   }
 
-  Ok(GetAllEconomicResourcesOutput { resources })
+  Ok(resources)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -313,23 +553,19 @@ pub fn check_first_resource_requirement(agent_pub_key: AgentPubKey) -> ExternRes
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-pub struct TransferCustodyInput {
+pub struct UpdateResourceStateInput {
   pub resource_hash: ActionHash,
-  pub new_custodian: AgentPubKey,
-  pub request_contact_info: Option<bool>, // Whether to auto-request private data for coordination
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct TransferCustodyOutput {
-  pub updated_resource_hash: ActionHash,
-  pub updated_resource: EconomicResource,
+  pub new_state: OperationalState,
+  #[serde(default)]
+  pub duration_hours: Option<u64>, // How long the resource is put in use or reserved for
 }
 
 #[hdk_extern]
-pub fn transfer_custody(input: TransferCustodyInput) -> ExternResult<TransferCustodyOutput> {
+pub fn update_resource_state(input: UpdateResourceStateInput) -> ExternResult<Record> {
   let agent_info = agent_info()?;
 
   // Get the current resource
+  ensure_latest_version(&input.resource_hash)?;
   let resource_record = get(input.resource_hash.clone(), GetOptions::default())?.ok_or(
     ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
   )?;
@@ -347,172 +583,123 @@ pub fn transfer_custody(input: TransferCustodyInput) -> ExternResult<TransferCus
     return Err(ResourceError::NotCustodian.into());
   }
 
-  // TODO: In Phase 2, check governance rules and validate with zome_governance
-  // TODO: In Phase 2, check that the calling agent has restricted_access capability
-
-  // If requested, create a private data access request for coordination
-  // This allows the new custodian to request contact info from the current custodian
-  if input.request_contact_info.unwrap_or(true) {
-    // We can't directly create a request on behalf of another agent
-    // Instead, we'll create a special entry that the new custodian can use to auto-request
-    // This is logged for now - in practice, the UI would handle this workflow
-    debug!(
-      "Custody transfer initiated from {} to {} for resource {}. New custodian should request contact info for coordination.",
-      agent_info.agent_initial_pubkey,
-      input.new_custodian,
-      input.resource_hash
+  // Only an approved validation takes a resource out of PendingValidation
+  if resource.state == OperationalState::PendingValidation {
+    return Err(
+      ResourceError::InvalidInput(
+        "Resources leave PendingValidation through promote_validated_resource once validated"
+          .to_string(),
+      )
+      .into(),
+    );
+  }
+
+  // Pre-flight check against the transition graph; integrity validation enforces the same rule
+  if !resource.state.can_transition_to(&input.new_state) {
+    return Err(
+      ResourceError::InvalidInput(format!(
+        "Invalid OperationalState transition: {:?} -> {:?}",
+        resource.state, input.new_state
+      ))
+      .into(),
     );
   }
 
-  // Update the custodian
-  resource.custodian = input.new_custodian.clone();
+  enforce_governance_rules(EvaluateResourceGovernanceInput {
+    resource_hash: input.resource_hash.clone(),
+    operation: GovernedOperation::StateChange {
+      from: resource.state.clone(),
+      to: input.new_state.clone(),
+    },
+    quantity: Some(resource.onhand_quantity),
+    recipient: None,
+    duration_hours: input.duration_hours,
+  })?;
+
+  // Update the state
+  let old_state = std::mem::replace(&mut resource.state, input.new_state);
 
   // Create updated resource entry
+  withdraw_custody_proposals(&input.resource_hash)?;
   let updated_resource_hash = update_entry(
     input.resource_hash.clone(),
     &EntryTypes::EconomicResource(resource.clone()),
   )?;
 
-  // Create update link from original to new version
-  create_link(
-    input.resource_hash.clone(), // original action hash
-    updated_resource_hash.clone(),
-    LinkTypes::EconomicResourceUpdates,
-    (),
+  // Resource links are based on the original version
+  let original_hash = get_original_action_hash(input.resource_hash.clone())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
   )?;
 
-  // TEMPORARY FIX: Also update the AllEconomicResources link to point to the new version
-  let path = Path::from("economic_resources");
+  // Move the ResourcesByOperationalState anchor link to the new state
+  if old_state != resource.state {
+    move_resource_link(
+      operational_state_path(&old_state)?.into(),
+      operational_state_path(&resource.state)?.into(),
+      LinkTypes::ResourcesByOperationalState,
+      &original_hash,
+      &input.resource_hash,
+    )?;
+  }
 
-  // Remove the old link
-  let existing_links = get_links(
-    LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllEconomicResources)?,
-    GetStrategy::default(),
+  let record = get(updated_resource_hash, GetOptions::default())?.ok_or(
+    ResourceError::EntryOperationFailed("Failed to retrieve updated resource".to_string()),
   )?;
-  for link in existing_links {
-    if let Some(link_target) = link.target.into_action_hash() {
-      if link_target == input.resource_hash {
-        delete_link(link.create_link_hash, GetOptions::default())?;
-        break;
-      }
+
+  Ok(record)
+}
+
+/// Resolve state-anchor links to the latest record of each resource, keeping only those whose
+/// latest version still matches the queried facet. Anchor links can lag behind updates made
+/// by other paths (e.g. custody transfer), so the entry itself is authoritative.
+pub(crate) fn resolve_resource_links(
+  links: Vec<Link>,
+  matches: impl Fn(&EconomicResource) -> bool,
+) -> ExternResult<Vec<Record>> {
+  let mut records = Vec::new();
+  for link in links {
+    let Some(action_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get_latest_economic_resource_record(action_hash)? else {
+      continue;
+    };
+    let Ok(Some(resource)) = record.entry().to_app_option::<EconomicResource>() else {
+      continue;
+    };
+    if matches(&resource) {
+      records.push(record);
     }
   }
+  Ok(records)
+}
 
-  // Create new link pointing to updated version
-  create_link(
-    path.path_entry_hash()?,
-    updated_resource_hash.clone(),
-    LinkTypes::AllEconomicResources,
-    (),
-  )?;
-
-  // Remove old custodian link
-  let old_links = get_links(
+/// Return all EconomicResources currently in the given OperationalState.
+///
+/// REQ-NDO-OS-06
+#[hdk_extern]
+pub fn get_resources_by_operational_state(state: OperationalState) -> ExternResult<Vec<Record>> {
+  let links = get_links(
     LinkQuery::try_new(
-      agent_info.agent_initial_pubkey.clone(),
-      LinkTypes::CustodianToResource,
+      operational_state_path(&state)?,
+      LinkTypes::ResourcesByOperationalState,
     )?,
     GetStrategy::default(),
   )?;
-  for link in old_links {
-    let link_target_hash: Result<ActionHash, _> = link.target.clone().try_into();
-    if let Ok(target_hash) = link_target_hash {
-      if target_hash == input.resource_hash {
-        delete_link(link.create_link_hash, GetOptions::default())?;
-        break;
-      }
-    }
-  }
-
-  // Create new custodian link
-  create_link(
-    input.new_custodian.clone(),
-    updated_resource_hash.clone(),
-    LinkTypes::CustodianToResource,
-    (),
-  )?;
-
-  Ok(TransferCustodyOutput {
-    updated_resource_hash,
-    updated_resource: resource,
-  })
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct UpdateResourceStateInput {
-  pub resource_hash: ActionHash,
-  pub new_state: ResourceState,
+  resolve_resource_links(links, |resource| resource.state == state)
 }
 
+/// Return all EconomicResources at the given LifecycleStage.
+///
+/// REQ-NDO-OS-06
 #[hdk_extern]
-pub fn update_resource_state(input: UpdateResourceStateInput) -> ExternResult<Record> {
-  let agent_info = agent_info()?;
-
-  // Get the current resource
-  let resource_record = get(input.resource_hash.clone(), GetOptions::default())?.ok_or(
-    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
-  )?;
-
-  let mut resource: EconomicResource = resource_record
-    .entry()
-    .to_app_option()
-    .map_err(|e| ResourceError::SerializationError(format!("Failed to deserialize: {:?}", e)))?
-    .ok_or(ResourceError::EconomicResourceNotFound(
-      "Invalid EconomicResource entry".to_string(),
-    ))?;
-
-  // Verify the calling agent is the current custodian
-  if resource.custodian != agent_info.agent_initial_pubkey {
-    return Err(ResourceError::NotCustodian.into());
-  }
-
-  // Update the state
-  resource.state = input.new_state;
-
-  // Create updated resource entry
-  let updated_resource_hash = update_entry(
-    input.resource_hash.clone(),
-    &EntryTypes::EconomicResource(resource.clone()),
-  )?;
-
-  // Create update link from original to new version
-  // For the first update, the resource_hash is both the original and previous
-  create_link(
-    input.resource_hash.clone(), // original action hash
-    updated_resource_hash.clone(),
-    LinkTypes::EconomicResourceUpdates,
-    (),
-  )?;
-
-  // TEMPORARY FIX: Also update the AllEconomicResources link to point to the new version
-  // This is a workaround until the get_latest update chain logic is fixed
-  let path = Path::from("economic_resources");
-
-  // Remove the old link
-  let existing_links = get_links(
-    LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllEconomicResources)?,
+pub fn get_resources_by_lifecycle_stage(stage: LifecycleStage) -> ExternResult<Vec<Record>> {
+  let links = get_links(
+    LinkQuery::try_new(
+      resource_lifecycle_stage_path(&stage)?,
+      LinkTypes::ResourcesByLifecycleStage,
+    )?,
     GetStrategy::default(),
   )?;
-  for link in existing_links {
-    if let Some(link_target) = link.target.into_action_hash() {
-      if link_target == input.resource_hash {
-        delete_link(link.create_link_hash, GetOptions::default())?;
-        break;
-      }
-    }
-  }
-
-  // Create new link pointing to updated version
-  create_link(
-    path.path_entry_hash()?,
-    updated_resource_hash.clone(),
-    LinkTypes::AllEconomicResources,
-    (),
-  )?;
-
-  let record = get(updated_resource_hash, GetOptions::default())?.ok_or(
-    ResourceError::EntryOperationFailed("Failed to retrieve updated resource".to_string()),
-  )?;
-
-  Ok(record)
+  resolve_resource_links(links, |resource| resource.lifecycle_stage == stage)
 }
@@ -0,0 +1,113 @@
+use crate::custody_transfer::{
+  open_custody_proposal, withdraw_custody_proposals, ProposeCustodyTransferInput,
+};
+use crate::economic_resource::get_latest_economic_resource_record;
+use crate::location::relink_resource_location;
+use crate::unit::resolve_unit;
+use crate::ResourceError;
+use hdk::prelude::*;
+pub use nondominium_shared::io::resource::{AppliedEventEffect, ApplyEconomicEventInput};
+use nondominium_shared::update_chain::get_original_action_hash;
+use zome_resource_integrity::*;
+
+// ============================================================================
+// Economic event effects
+// ============================================================================
+//
+// zome_gouvernance records an EconomicEvent, then asks this zome to apply the event's
+// VF 1.0 effect (`VfAction::resource_effect`) to the resource version it was recorded
+// against. Quantity and location changes are written as a new resource version by the
+// custodian. Custody can only move through the acceptance flow, so a Transfer opens a
+// custody proposal for the receiver, tagged with the event so that accepting it does
+// not record the transfer a second time.
+
+#[hdk_extern]
+pub fn apply_economic_event(input: ApplyEconomicEventInput) -> ExternResult<AppliedEventEffect> {
+  let original_hash = get_original_action_hash(input.resource_hash.clone())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
+  )?;
+  let latest_record = get_latest_economic_resource_record(original_hash.clone())?.ok_or(
+    ResourceError::EconomicResourceNotFound("EconomicResource not found".to_string()),
+  )?;
+  let latest_hash = latest_record.action_address().clone();
+  if latest_hash != input.resource_hash {
+    return Err(
+      ResourceError::InvalidInput("Resource has changed since the event was recorded".to_string())
+        .into(),
+    );
+  }
+  let mut resource: EconomicResource = latest_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| ResourceError::SerializationError(format!("Failed to deserialize: {:?}", e)))?
+    .ok_or(ResourceError::EconomicResourceNotFound(
+      "Invalid EconomicResource entry".to_string(),
+    ))?;
+
+  // Only the custodian may write the resource, or hand it over
+  if resource.custodian != agent_info()?.agent_initial_pubkey {
+    return Err(ResourceError::NotCustodian.into());
+  }
+
+  if !(input.resource_quantity.is_finite() && input.resource_quantity > 0.0) {
+    return Err(
+      ResourceError::InvalidInput("resource_quantity must be a positive number".to_string()).into(),
+    );
+  }
+
+  // Quantities are kept in the resource's own unit
+  let quantity = match &input.resource_unit {
+    Some(unit) if *unit != resource.unit => resolve_unit(unit)?
+      .convert(input.resource_quantity, &resolve_unit(&resource.unit)?)
+      .map_err(ResourceError::InvalidInput)?,
+    _ => input.resource_quantity,
+  };
+  let effect = input.action.resource_effect(quantity);
+  let mut applied = AppliedEventEffect::default();
+  let previous_location = resource.current_location.clone();
+
+  if effect.changes_quantity() || effect.changes_location {
+    (resource.accounting_quantity, resource.onhand_quantity) = effect
+      .apply_quantities(resource.accounting_quantity, resource.onhand_quantity)
+      .map_err(ResourceError::InvalidInput)?;
+    if effect.changes_location {
+      let to_location = input
+        .to_location
+        .clone()
+        .ok_or(ResourceError::InvalidInput(
+          "Move events need a to_location".to_string(),
+        ))?;
+      to_location
+        .validate()
+        .map_err(ResourceError::InvalidInput)?;
+      resource.current_location = Some(to_location);
+    }
+
+    withdraw_custody_proposals(&latest_hash)?;
+    let updated_resource_hash = update_entry(
+      latest_hash.clone(),
+      &EntryTypes::EconomicResource(resource.clone()),
+    )?;
+    relink_resource_location(
+      &original_hash,
+      previous_location.as_ref(),
+      resource.current_location.as_ref(),
+    )?;
+    applied.updated_resource_hash = Some(updated_resource_hash);
+  }
+
+  if effect.transfers_custody && resource.custodian != input.receiver {
+    let proposal = open_custody_proposal(
+      ProposeCustodyTransferInput {
+        resource_hash: latest_hash,
+        new_custodian: input.receiver,
+        note: Some(format!("{:?} event {}", input.action, input.event_hash)),
+        request_contact_info: Some(false),
+      },
+      Some(input.event_hash),
+    )?;
+    applied.custody_proposal_hash = Some(proposal.proposal_hash);
+  }
+
+  Ok(applied)
+}
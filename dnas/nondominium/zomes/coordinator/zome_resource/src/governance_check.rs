@@ -0,0 +1,182 @@
+use crate::governance_rule::get_latest_governance_rule;
+use crate::ResourceError;
+use hdk::prelude::*;
+use nondominium_shared::io::governance::EvaluateGovernanceRulesInput;
+pub use nondominium_shared::io::resource::EvaluateResourceGovernanceInput;
+use nondominium_shared::rules::*;
+use nondominium_shared::update_chain::{get_latest_action_hash, get_original_action_hash};
+use nondominium_shared::{call_governance_zome, call_person_zome};
+use std::collections::BTreeMap;
+use zome_resource_integrity::*;
+
+// ============================================================================
+// Governance rule enforcement
+// ============================================================================
+//
+// Before each EconomicResource state change we gather the GovernanceRules attached to
+// the resource's ResourceSpecification plus facts about the acting agent, and ask
+// zome_gouvernance's `evaluate_governance_rules` for a decision. Role names, held and
+// required, are resolved to capability levels through zome_person's role registry.
+
+// Subsets of zome_person / zome_gouvernance outputs used here.
+#[derive(Serialize, Deserialize, Debug)]
+struct PersonRoleName {
+  pub role_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct PersonRoles {
+  pub roles: Vec<PersonRoleName>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RoleDefinitionLevel {
+  pub capability_level: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RoleDefinitionRecord {
+  pub action_hash: ActionHash, // Original creation hash
+  pub definition: RoleDefinitionLevel,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ValidationVote {
+  pub validator: AgentPubKey,
+  pub approved: bool,
+}
+
+/// Evaluate the resource's governance rules for an operation by the calling agent.
+/// Resources that cannot be found, or whose specification carries no rules, are allowed.
+#[hdk_extern]
+pub fn evaluate_resource_governance(
+  input: EvaluateResourceGovernanceInput,
+) -> ExternResult<GovernanceDecision> {
+  let Some(original_hash) = get_original_action_hash(input.resource_hash.clone())? else {
+    return Ok(GovernanceDecision::allow());
+  };
+
+  let rules = resource_governance_rules(&original_hash)?;
+  if rules.is_empty() {
+    return Ok(GovernanceDecision::allow());
+  }
+
+  let agent = agent_info()?.agent_initial_pubkey;
+  let recipient_roles = match input.recipient {
+    Some(recipient) if recipient != agent => Some(agent_roles(recipient)?),
+    _ => None,
+  };
+
+  let agent_roles = agent_roles(agent)?;
+  let mut role_levels = BTreeMap::new();
+  let role_names = agent_roles
+    .iter()
+    .chain(recipient_roles.iter().flatten())
+    .map(String::as_str)
+    .chain(
+      rules
+        .iter()
+        .flat_map(|rule| rule.rule_data.parameters().role_names()),
+    );
+  for role_name in role_names {
+    if role_levels.contains_key(role_name) {
+      continue;
+    }
+    if let Some(level) = role_capability_level(role_name)? {
+      role_levels.insert(role_name.to_string(), level);
+    }
+  }
+
+  let context = RuleEvaluationContext {
+    agent_roles,
+    recipient_roles,
+    role_levels,
+    quantity: input.quantity,
+    duration_hours: input.duration_hours,
+    validator_approvals: validator_approvals(&original_hash)?,
+  };
+
+  call_governance_zome(
+    "evaluate_governance_rules",
+    EvaluateGovernanceRulesInput {
+      operation: input.operation,
+      rules,
+      context,
+    },
+  )
+}
+
+/// Fail with `GovernanceViolation` unless the resource's rules allow the operation.
+pub(crate) fn enforce_governance_rules(input: EvaluateResourceGovernanceInput) -> ExternResult<()> {
+  let decision = evaluate_resource_governance(input)?;
+  if !decision.allowed {
+    return Err(ResourceError::GovernanceViolation(decision.reasons()).into());
+  }
+  Ok(())
+}
+
+fn resource_governance_rules(original_hash: &ActionHash) -> ExternResult<Vec<EvaluatedRule>> {
+  let spec_links = get_links(
+    LinkQuery::try_new(original_hash.clone(), LinkTypes::ResourceToSpecification)?,
+    GetStrategy::default(),
+  )?;
+  let Some(spec_hash) = spec_links
+    .into_iter()
+    .find_map(|link| link.target.into_action_hash())
+  else {
+    return Ok(Vec::new());
+  };
+
+  let rule_links = get_links(
+    LinkQuery::try_new(spec_hash, LinkTypes::SpecificationToGovernanceRule)?,
+    GetStrategy::default(),
+  )?;
+
+  let mut rules = Vec::new();
+  for link in rule_links {
+    let Some(rule_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let rule = get_latest_governance_rule(rule_hash.clone())?;
+    rules.push(EvaluatedRule {
+      rule_hash: Some(rule_hash),
+      rule_type: rule.rule_type,
+      rule_data: rule.rule_data,
+    });
+  }
+  Ok(rules)
+}
+
+fn agent_roles(agent: AgentPubKey) -> ExternResult<Vec<String>> {
+  let roles: PersonRoles = call_person_zome("get_active_person_roles", agent)?;
+  Ok(roles.roles.into_iter().map(|r| r.role_name).collect())
+}
+
+/// Capability level of a ratified role of the registry; None when no such role exists
+pub(crate) fn role_capability_level(role_name: &str) -> ExternResult<Option<String>> {
+  let record: Option<RoleDefinitionRecord> =
+    call_person_zome("get_role_definition", role_name.to_string())?;
+  Ok(record.map(|record| record.definition.capability_level))
+}
+
+/// Latest (ratified) version of the registry's definition of `role_name`, for rules to cite
+pub(crate) fn ratified_role_definition_hash(role_name: &str) -> ExternResult<Option<ActionHash>> {
+  let record: Option<RoleDefinitionRecord> =
+    call_person_zome("get_role_definition", role_name.to_string())?;
+  match record {
+    Some(record) => get_latest_action_hash(record.action_hash),
+    None => Ok(None),
+  }
+}
+
+/// Distinct validators who approved the resource
+fn validator_approvals(original_hash: &ActionHash) -> ExternResult<u32> {
+  let votes: Vec<ValidationVote> =
+    call_governance_zome("get_validation_history", original_hash.clone())?;
+  let approvers: HashSet<AgentPubKey> = votes
+    .into_iter()
+    .filter(|vote| vote.approved)
+    .map(|vote| vote.validator)
+    .collect();
+  Ok(approvers.len() as u32)
+}
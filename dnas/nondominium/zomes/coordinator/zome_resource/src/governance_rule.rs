@@ -1,5 +1,10 @@
+use crate::governance_check::ratified_role_definition_hash;
 use crate::ResourceError;
 use hdk::prelude::*;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use nondominium_shared::roles::default_role;
+use nondominium_shared::update_chain::get_latest_record;
 use zome_resource_integrity::*;
 
 fn something_to_do() -> Result<String, String> {
@@ -11,28 +16,63 @@ fn something_to_do() -> Result<String, String> {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GovernanceRuleInput {
   pub rule_type: String,
-  pub rule_data: String,
+  pub rule_data: String, // JSON parameters for `rule_type`; unknown types are stored as Custom
   pub enforced_by: Option<String>,
 }
 
-#[hdk_extern]
-pub fn create_governance_rule(input: GovernanceRuleInput) -> ExternResult<Record> {
-  let agent_info = agent_info()?;
+impl GovernanceRuleInput {
+  /// Parse `rule_data` into the typed schema and apply the same checks as integrity,
+  /// so a malformed rule fails with a readable error before it is committed. Roles the
+  /// rule names must be in the role registry; the ratified definitions of those that are
+  /// not default roles are cited for integrity to check.
+  pub(crate) fn into_rule(self) -> ExternResult<GovernanceRule> {
+    if self.rule_type.trim().is_empty() {
+      return Err(ResourceError::InvalidInput("Rule type cannot be empty".to_string()).into());
+    }
 
-  // Validate input
-  if input.rule_type.trim().is_empty() {
-    return Err(ResourceError::InvalidInput("Rule type cannot be empty".to_string()).into());
-  }
+    if self.rule_data.trim().is_empty() {
+      return Err(ResourceError::InvalidInput("Rule data cannot be empty".to_string()).into());
+    }
+
+    let rule_data = GovernanceRuleData::from_json(&self.rule_type, &self.rule_data)
+      .map_err(ResourceError::InvalidInput)?;
+    rule_data
+      .validate(&self.rule_type, self.enforced_by.as_deref())
+      .map_err(ResourceError::InvalidInput)?;
+    let mut role_definitions = Vec::new();
+    let role_names = rule_data.parameters().role_names();
+    for role_name in role_names.into_iter().chain(self.enforced_by.as_deref()) {
+      if default_role(role_name).is_some() {
+        continue;
+      }
+      let Some(definition_hash) = ratified_role_definition_hash(role_name)? else {
+        return Err(
+          ResourceError::InvalidInput(format!(
+            "'{}' is not a role of the role registry",
+            role_name
+          ))
+          .into(),
+        );
+      };
+      if !role_definitions.contains(&definition_hash) {
+        role_definitions.push(definition_hash);
+      }
+    }
 
-  if input.rule_data.trim().is_empty() {
-    return Err(ResourceError::InvalidInput("Rule data cannot be empty".to_string()).into());
+    Ok(GovernanceRule {
+      rule_type: self.rule_type,
+      rule_data,
+      enforced_by: self.enforced_by,
+      role_definitions,
+    })
   }
+}
 
-  let rule = GovernanceRule {
-    rule_type: input.rule_type,
-    rule_data: input.rule_data,
-    enforced_by: input.enforced_by,
-  };
+#[hdk_extern]
+pub fn create_governance_rule(input: GovernanceRuleInput) -> ExternResult<Record> {
+  let agent_info = agent_info()?;
+
+  let rule = input.into_rule()?;
 
   let rule_hash = create_entry(&EntryTypes::GovernanceRule(rule.clone()))?;
 
@@ -41,12 +81,10 @@ pub fn create_governance_rule(input: GovernanceRuleInput) -> ExternResult<Record
   )?;
 
   // Create discovery link
-  let path = Path::from("governance_rules");
-  create_link(
-    path.path_entry_hash()?,
+  create_hash_sharded_link(
+    "governance_rules",
     rule_hash.clone(),
     LinkTypes::AllGovernanceRules,
-    (),
   )?;
 
   // Create type-based discovery link
@@ -73,27 +111,7 @@ pub fn create_governance_rule(input: GovernanceRuleInput) -> ExternResult<Record
 pub fn get_latest_governance_rule_record(
   original_action_hash: ActionHash,
 ) -> ExternResult<Option<Record>> {
-  let links_query = LinkQuery::try_new(
-    original_action_hash.clone(),
-    LinkTypes::GovernanceRuleUpdates,
-  )?;
-  let links = get_links(links_query, GetStrategy::default())?;
-  let latest_link = links
-    .into_iter()
-    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-  let latest_rule_hash = match latest_link {
-    Some(link) => {
-      link
-        .target
-        .clone()
-        .into_action_hash()
-        .ok_or(ResourceError::EntryOperationFailed(
-          "Invalid action hash in link".to_string(),
-        ))?
-    }
-    None => original_action_hash.clone(),
-  };
-  get(latest_rule_hash, GetOptions::default())
+  get_latest_record(original_action_hash)
 }
 
 #[hdk_extern]
@@ -132,20 +150,7 @@ pub fn update_governance_rule(input: UpdateGovernanceRuleInput) -> ExternResult<
     return Err(ResourceError::NotAuthor.into());
   }
 
-  // Validate input
-  if input.updated_rule.rule_type.trim().is_empty() {
-    return Err(ResourceError::InvalidInput("Rule type cannot be empty".to_string()).into());
-  }
-
-  if input.updated_rule.rule_data.trim().is_empty() {
-    return Err(ResourceError::InvalidInput("Rule data cannot be empty".to_string()).into());
-  }
-
-  let updated_rule = GovernanceRule {
-    rule_type: input.updated_rule.rule_type,
-    rule_data: input.updated_rule.rule_data,
-    enforced_by: input.updated_rule.enforced_by,
-  };
+  let updated_rule = input.updated_rule.into_rule()?;
 
   let updated_rule_hash = update_entry(input.previous_action_hash, &updated_rule)?;
 
@@ -170,11 +175,26 @@ pub struct GetAllGovernanceRulesOutput {
 
 #[hdk_extern]
 pub fn get_all_governance_rules(_: ()) -> ExternResult<GetAllGovernanceRulesOutput> {
-  let path = Path::from("governance_rules");
+  Ok(GetAllGovernanceRulesOutput {
+    rules: resolve_rule_links(all_governance_rule_links()?)?,
+  })
+}
 
-  let links_query = LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllGovernanceRules)?;
-  let links = get_links(links_query, GetStrategy::default())?;
+/// One page of `get_all_governance_rules`, in link order.
+#[hdk_extern]
+pub fn get_all_governance_rules_paginated(page: PageRequest) -> ExternResult<Page<GovernanceRule>> {
+  let (links, next_cursor) = page.select(all_governance_rule_links()?);
+  Ok(Page {
+    items: resolve_rule_links(links)?,
+    next_cursor,
+  })
+}
+
+fn all_governance_rule_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("governance_rules", LinkTypes::AllGovernanceRules)
+}
 
+fn resolve_rule_links(links: Vec<Link>) -> ExternResult<Vec<GovernanceRule>> {
   let mut rules = Vec::new();
 
   for link in links {
@@ -187,7 +207,7 @@ pub fn get_all_governance_rules(_: ()) -> ExternResult<GetAllGovernanceRulesOutp
     }
   }
 
-  Ok(GetAllGovernanceRulesOutput { rules })
+  Ok(rules)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1,15 +1,25 @@
 use hdk::prelude::*;
 pub use nondominium_shared::errors::ResourceError;
 
+pub mod custody_transfer;
 pub mod economic_resource;
+pub mod event_effects;
+pub mod governance_check;
 pub mod governance_rule;
+pub mod location;
 pub mod ndo_identity;
 pub mod resource_specification;
+pub mod unit;
 
+pub use custody_transfer::*;
 pub use economic_resource::*;
+pub use event_effects::*;
+pub use governance_check::*;
 pub use governance_rule::*;
+pub use location::*;
 pub use ndo_identity::*;
 pub use resource_specification::*;
+pub use unit::*;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Deserialize, Debug)]
@@ -40,6 +50,7 @@ pub enum Signal {
 
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+  grant_resource_promotion_capability()?;
   Ok(InitCallbackResult::Pass)
 }
 
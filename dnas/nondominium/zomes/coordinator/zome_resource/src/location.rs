@@ -0,0 +1,214 @@
+use crate::economic_resource::resolve_resource_links;
+use crate::ResourceError;
+use hdk::prelude::*;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::location::GEOHASH_PRECISION;
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use zome_resource_integrity::*;
+
+// ============================================================================
+// Resource locations
+// ============================================================================
+//
+// Every EconomicResource with a location is linked (ResourcesByLocation, from its original
+// action hash) under the anchors of that location: "resource.location.{key}" for named,
+// postal and virtual places, and "resource.geo.{prefix}" for each geohash prefix of a geo
+// point. The links move whenever a create, update or Move event changes the location;
+// readers still check the latest resource version, which is authoritative.
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SpatialThingRecord {
+  pub action_hash: ActionHash,
+  pub spatial_thing: SpatialThing,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResourcesNearInput {
+  pub lat: f64,
+  pub long: f64,
+  pub radius_km: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NearbyResource {
+  pub record: Record, // Latest version of the resource
+  pub distance_km: f64,
+}
+
+fn location_anchors(location: &Location) -> ExternResult<Vec<EntryHash>> {
+  match (location, location.anchor_key()) {
+    (Location::Geo(point), _) => point
+      .geohash_prefixes()
+      .into_iter()
+      .map(|prefix| geohash_anchor(&prefix))
+      .collect(),
+    (_, Some(key)) => Ok(vec![
+      Path::from(format!("resource.location.{}", key)).path_entry_hash()?
+    ]),
+    (_, None) => Ok(vec![]),
+  }
+}
+
+fn geohash_anchor(prefix: &str) -> ExternResult<EntryHash> {
+  Path::from(format!("resource.geo.{}", prefix)).path_entry_hash()
+}
+
+/// Move a resource's ResourcesByLocation links from its old location to its new one.
+pub(crate) fn relink_resource_location(
+  original_hash: &ActionHash,
+  old_location: Option<&Location>,
+  new_location: Option<&Location>,
+) -> ExternResult<()> {
+  if old_location == new_location {
+    return Ok(());
+  }
+  if let Some(location) = old_location {
+    for anchor in location_anchors(location)? {
+      let links = get_links(
+        LinkQuery::try_new(anchor, LinkTypes::ResourcesByLocation)?,
+        GetStrategy::default(),
+      )?;
+      for link in links {
+        if link.target.clone().into_action_hash().as_ref() == Some(original_hash) {
+          delete_link(link.create_link_hash, GetOptions::default())?;
+        }
+      }
+    }
+  }
+  if let Some(location) = new_location {
+    for anchor in location_anchors(location)? {
+      create_link(
+        anchor,
+        original_hash.clone(),
+        LinkTypes::ResourcesByLocation,
+        (),
+      )?;
+    }
+  }
+  Ok(())
+}
+
+/// Register a named place that resources and Move events can refer to.
+#[hdk_extern]
+pub fn create_spatial_thing(spatial_thing: SpatialThing) -> ExternResult<SpatialThingRecord> {
+  spatial_thing
+    .location
+    .validate()
+    .map_err(ResourceError::InvalidInput)?;
+  let action_hash = create_entry(&EntryTypes::SpatialThing(spatial_thing.clone()))?;
+  create_hash_sharded_link(
+    "spatial_things",
+    action_hash.clone(),
+    LinkTypes::AllSpatialThings,
+  )?;
+  Ok(SpatialThingRecord {
+    action_hash,
+    spatial_thing,
+  })
+}
+
+#[hdk_extern]
+pub fn get_all_spatial_things(_: ()) -> ExternResult<Vec<SpatialThingRecord>> {
+  resolve_spatial_thing_links(all_spatial_thing_links()?)
+}
+
+/// One page of `get_all_spatial_things`, in link order.
+#[hdk_extern]
+pub fn get_all_spatial_things_paginated(
+  page: PageRequest,
+) -> ExternResult<Page<SpatialThingRecord>> {
+  let (links, next_cursor) = page.select(all_spatial_thing_links()?);
+  Ok(Page {
+    items: resolve_spatial_thing_links(links)?,
+    next_cursor,
+  })
+}
+
+fn all_spatial_thing_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("spatial_things", LinkTypes::AllSpatialThings)
+}
+
+fn resolve_spatial_thing_links(links: Vec<Link>) -> ExternResult<Vec<SpatialThingRecord>> {
+  let mut places = Vec::new();
+  for link in links {
+    let Some(action_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get(action_hash.clone(), GetOptions::default())? else {
+      continue;
+    };
+    if let Ok(Some(spatial_thing)) = record.entry().to_app_option::<SpatialThing>() {
+      places.push(SpatialThingRecord {
+        action_hash,
+        spatial_thing,
+      });
+    }
+  }
+  Ok(places)
+}
+
+/// Return all EconomicResources currently at exactly this location.
+#[hdk_extern]
+pub fn get_resources_at_location(location: Location) -> ExternResult<Vec<Record>> {
+  let anchor = match &location {
+    Location::Geo(point) => geohash_anchor(&point.geohash(GEOHASH_PRECISION))?,
+    _ => match location_anchors(&location)?.pop() {
+      Some(anchor) => anchor,
+      None => return Ok(vec![]),
+    },
+  };
+  let links = get_links(
+    LinkQuery::try_new(anchor, LinkTypes::ResourcesByLocation)?,
+    GetStrategy::default(),
+  )?;
+  // Places written the same way are the same place
+  resolve_resource_links(links, |resource| {
+    match (&resource.current_location, &location) {
+      (Some(Location::Geo(at)), Location::Geo(point)) => at == point,
+      (Some(at), _) => at.anchor_key().is_some() && at.anchor_key() == location.anchor_key(),
+      (None, _) => false,
+    }
+  })
+}
+
+/// Return the EconomicResources located within `radius_km` of a point, closest first.
+/// Only resources with a geo location can be found this way.
+#[hdk_extern]
+pub fn get_resources_near(input: ResourcesNearInput) -> ExternResult<Vec<NearbyResource>> {
+  let center = GeoPoint {
+    lat: input.lat,
+    long: input.long,
+  };
+  center.validate().map_err(ResourceError::InvalidInput)?;
+  if !(input.radius_km.is_finite() && input.radius_km > 0.0) {
+    return Err(ResourceError::InvalidInput("Radius must be a positive number".to_string()).into());
+  }
+
+  let distance = |resource: &EconomicResource| match &resource.current_location {
+    Some(Location::Geo(point)) => Some(center.distance_km(point)),
+    _ => None,
+  };
+  let mut links = Vec::new();
+  for cell in center.geohash_cover(input.radius_km) {
+    links.extend(get_links(
+      LinkQuery::try_new(geohash_anchor(&cell)?, LinkTypes::ResourcesByLocation)?,
+      GetStrategy::default(),
+    )?);
+  }
+  let records = resolve_resource_links(links, |resource| {
+    distance(resource).is_some_and(|km| km <= input.radius_km)
+  })?;
+
+  let mut nearby: Vec<NearbyResource> = records
+    .into_iter()
+    .filter_map(|record| {
+      let resource: EconomicResource = record.entry().to_app_option().ok()??;
+      Some(NearbyResource {
+        distance_km: distance(&resource)?,
+        record,
+      })
+    })
+    .collect();
+  nearby.sort_by(|a, b| a.distance_km.total_cmp(&b.distance_km));
+  Ok(nearby)
+}
@@ -1,5 +1,8 @@
 use crate::ResourceError;
 use hdk::prelude::*;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use nondominium_shared::update_chain::get_latest_record;
 use zome_resource_integrity::*;
 
 // Input for creating a NondominiumIdentity (Layer 0 anchor)
@@ -65,7 +68,7 @@ fn resolve_ndo_links(links: Vec<Link>) -> ExternResult<Vec<NdoOutput>> {
     let Some(action_hash) = link.target.into_action_hash() else {
       continue;
     };
-    let Some(record) = resolve_latest_ndo_record(action_hash.clone())? else {
+    let Some(record) = get_latest_record(action_hash.clone())? else {
       continue;
     };
     let Ok(Some(entry)) = record.entry().to_app_option::<NondominiumIdentity>() else {
@@ -76,38 +79,6 @@ fn resolve_ndo_links(links: Vec<Link>) -> ExternResult<Vec<NdoOutput>> {
   Ok(ndos)
 }
 
-/// Resolves the HDK update chain to the latest Record for a NondominiumIdentity.
-/// Returns None if the original_action_hash does not exist on the DHT.
-///
-/// Used by both get_ndo and update_lifecycle_stage to avoid duplicated chain traversal logic.
-fn resolve_latest_ndo_record(original_action_hash: ActionHash) -> ExternResult<Option<Record>> {
-  let mut current_hash = original_action_hash;
-  loop {
-    match get_details(current_hash.clone(), GetOptions::default())? {
-      Some(Details::Record(record_details)) => {
-        if record_details.updates.is_empty() {
-          return Ok(Some(record_details.record));
-        }
-        // Follow the most recent update in the chain.
-        // Tie-breaking: if two updates share the same timestamp (rare on the DHT),
-        // max_by_key picks one deterministically by iteration order. This is acceptable
-        // because simultaneous updates from different agents would themselves be a
-        // conflict the initiator must resolve via a subsequent update.
-        current_hash = record_details
-          .updates
-          .into_iter()
-          .max_by_key(|sah| sah.action().timestamp())
-          .ok_or(ResourceError::EntryOperationFailed(
-            "Empty updates list during chain traversal".to_string(),
-          ))?
-          .hashed
-          .hash;
-      }
-      _ => return Ok(None),
-    }
-  }
-}
-
 /// Create a new NondominiumIdentity (NDO Layer 0 anchor).
 ///
 /// The returned action_hash is the stable, permanent Layer 0 identity for this resource.
@@ -137,13 +108,7 @@ pub fn create_ndo(input: NdoInput) -> ExternResult<NdoOutput> {
   let action_hash = create_entry(&EntryTypes::NondominiumIdentity(entry.clone()))?;
 
   // Global discovery anchor — all NDOs discoverable by anyone
-  let all_ndos_path = Path::from("ndo_identities");
-  create_link(
-    all_ndos_path.path_entry_hash()?,
-    action_hash.clone(),
-    LinkTypes::AllNdos,
-    (),
-  )?;
+  create_hash_sharded_link("ndo_identities", action_hash.clone(), LinkTypes::AllNdos)?;
 
   // Agent-centric discovery — initiator's NDOs discoverable per agent
   create_link(
@@ -189,7 +154,7 @@ pub fn create_ndo(input: NdoInput) -> ExternResult<NdoOutput> {
 /// REQ-NDO-L0-01, REQ-NDO-L0-07
 #[hdk_extern]
 pub fn get_ndo(original_action_hash: ActionHash) -> ExternResult<Option<NondominiumIdentity>> {
-  let Some(record) = resolve_latest_ndo_record(original_action_hash)? else {
+  let Some(record) = get_latest_record(original_action_hash)? else {
     return Ok(None);
   };
   record
@@ -226,7 +191,7 @@ pub fn get_ndo(original_action_hash: ActionHash) -> ExternResult<Option<Nondomin
 pub fn update_lifecycle_stage(input: UpdateLifecycleStageInput) -> ExternResult<ActionHash> {
   let caller = agent_info()?.agent_initial_pubkey;
 
-  let record = resolve_latest_ndo_record(input.original_action_hash.clone())?
+  let record = get_latest_record(input.original_action_hash.clone())?
     .ok_or(ResourceError::EntryOperationFailed(
       "NondominiumIdentity not found for update".to_string(),
     ))?;
@@ -347,11 +312,18 @@ pub fn update_lifecycle_stage(input: UpdateLifecycleStageInput) -> ExternResult<
 /// REQ-NDO-L0-07
 #[hdk_extern]
 pub fn get_all_ndos(_: ()) -> ExternResult<GetAllNdosOutput> {
-  let path = Path::from("ndo_identities");
-  let links_query =
-    LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllNdos)?;
-  let links = get_links(links_query, GetStrategy::default())?;
-  Ok(GetAllNdosOutput { ndos: resolve_ndo_links(links)? })
+  Ok(GetAllNdosOutput { ndos: resolve_ndo_links(all_ndo_links()?)? })
+}
+
+/// One page of `get_all_ndos`, in link order.
+#[hdk_extern]
+pub fn get_all_ndos_paginated(page: PageRequest) -> ExternResult<Page<NdoOutput>> {
+  let (links, next_cursor) = page.select(all_ndo_links()?);
+  Ok(Page { items: resolve_ndo_links(links)?, next_cursor })
+}
+
+fn all_ndo_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links("ndo_identities", LinkTypes::AllNdos)
 }
 
 /// Return all NondominiumIdentities at a given lifecycle stage.
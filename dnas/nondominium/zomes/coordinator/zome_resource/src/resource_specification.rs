@@ -1,5 +1,9 @@
 use crate::{GovernanceRuleInput, ResourceError};
 use hdk::prelude::*;
+use nondominium_shared::io::{Page, PageRequest};
+use nondominium_shared::paths::{create_hash_sharded_link, get_hash_sharded_links};
+use nondominium_shared::search::{index_prefixes, match_score, query_prefix, tokenize};
+use nondominium_shared::update_chain::get_latest_record;
 use zome_resource_integrity::*;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -10,6 +14,8 @@ pub struct ResourceSpecificationInput {
   pub image_url: Option<String>,
   pub tags: Vec<String>,
   pub governance_rules: Vec<GovernanceRuleInput>,
+  #[serde(default)]
+  pub validation: Option<ResourceValidationConfig>, // Defaults to a single approval
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -40,22 +46,16 @@ pub fn create_resource_specification(
   let mut governance_rule_hashes = Vec::new();
 
   for rule_input in input.governance_rules {
-    let rule = GovernanceRule {
-      rule_type: rule_input.rule_type,
-      rule_data: rule_input.rule_data,
-      enforced_by: rule_input.enforced_by,
-    };
+    let rule = rule_input.into_rule()?;
 
     let rule_hash = create_entry(&EntryTypes::GovernanceRule(rule.clone()))?;
     governance_rule_hashes.push(rule_hash.clone());
 
     // Create discovery link for this governance rule (same as in create_governance_rule)
-    let rules_path = Path::from("governance_rules");
-    create_link(
-      rules_path.path_entry_hash()?,
+    create_hash_sharded_link(
+      "governance_rules",
       rule_hash.clone(),
       LinkTypes::AllGovernanceRules,
-      (),
     )?;
 
     // Create type-based discovery link
@@ -76,6 +76,7 @@ pub fn create_resource_specification(
     image_url: input.image_url,
     tags: input.tags.clone(),
     is_active: true, // New specs are active by default
+    validation: input.validation.unwrap_or_default(),
   };
 
   let spec_hash = create_entry(&EntryTypes::ResourceSpecification(spec.clone()))?;
@@ -83,22 +84,14 @@ pub fn create_resource_specification(
   // Create discovery links (inspired by R&O efficient query patterns)
 
   // 1. Global discovery anchor
-  let all_specs_path = Path::from("resource_specifications");
-  create_link(
-    all_specs_path.path_entry_hash()?,
+  create_hash_sharded_link(
+    "resource_specifications",
     spec_hash.clone(),
     LinkTypes::AllResourceSpecifications,
-    (),
   )?;
 
-  // 2. Category-based discovery (like ServiceType patterns)
-  let category_path = Path::from(format!("specs_by_category_{}", input.category));
-  create_link(
-    category_path.path_entry_hash()?,
-    spec_hash.clone(),
-    LinkTypes::SpecsByCategory,
-    LinkTag::new(input.category.as_str()),
-  )?;
+  // 2. Category, tag and name-word discovery (like ServiceType patterns)
+  relink_specification_facets(&spec_hash, None, &spec)?;
 
   // 3. Agent-owned specs for efficient "my specs" queries
   create_link(
@@ -108,17 +101,6 @@ pub fn create_resource_specification(
     (),
   )?;
 
-  // 4. Tag-based discovery for flexible queries
-  for tag in &input.tags {
-    let tag_path = Path::from(format!("specs_by_tag_{}", tag));
-    create_link(
-      tag_path.path_entry_hash()?,
-      spec_hash.clone(),
-      LinkTypes::SpecsByCategory, // Reuse for tags
-      LinkTag::new(tag.as_str()),
-    )?;
-  }
-
   // Link governance rules to the specification
   for rule_hash in &governance_rule_hashes {
     create_link(
@@ -140,38 +122,25 @@ pub fn create_resource_specification(
 pub fn get_latest_resource_specification_record(
   original_action_hash: ActionHash,
 ) -> ExternResult<Option<Record>> {
-  let links_query = LinkQuery::try_new(
-    original_action_hash.clone(),
-    LinkTypes::ResourceSpecificationUpdates,
-  )?;
-  let links = get_links(links_query, GetStrategy::default())?;
-  let latest_link = links
-    .into_iter()
-    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-  let latest_spec_hash = match latest_link {
-    Some(link) => {
-      link
-        .target
-        .clone()
-        .into_action_hash()
-        .ok_or(ResourceError::EntryOperationFailed(
-          "Invalid action hash in link".to_string(),
-        ))?
-    }
-    None => original_action_hash.clone(),
-  };
-  get(latest_spec_hash, GetOptions::default())
+  get_latest_record(original_action_hash)
 }
 
 #[hdk_extern]
 pub fn get_latest_resource_specification(
   original_action_hash: ActionHash,
 ) -> ExternResult<ResourceSpecification> {
-  let record = get_latest_resource_specification_record(original_action_hash)?.ok_or(
+  latest_resource_specification(original_action_hash).map(|(_, spec)| spec)
+}
+
+/// Latest version of a ResourceSpecification with its action hash
+pub(crate) fn latest_resource_specification(
+  spec_hash: ActionHash,
+) -> ExternResult<(ActionHash, ResourceSpecification)> {
+  let record = get_latest_resource_specification_record(spec_hash)?.ok_or(
     ResourceError::ResourceSpecNotFound("Resource specification record not found".to_string()),
   )?;
 
-  record
+  let spec = record
     .entry()
     .to_app_option()
     .map_err(|e| {
@@ -180,10 +149,10 @@ pub fn get_latest_resource_specification(
         e
       ))
     })?
-    .ok_or(
-      ResourceError::ResourceSpecNotFound("Resource specification entry not found".to_string())
-        .into(),
-    )
+    .ok_or(ResourceError::ResourceSpecNotFound(
+      "Resource specification entry not found".to_string(),
+    ))?;
+  Ok((record.action_address().clone(), spec))
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -213,16 +182,26 @@ pub fn update_resource_specification(
   // Create updated governance rules
   let mut governance_rule_hashes = Vec::new();
   for rule_input in input.updated_specification.governance_rules {
-    let rule = GovernanceRule {
-      rule_type: rule_input.rule_type,
-      rule_data: rule_input.rule_data,
-      enforced_by: rule_input.enforced_by,
-    };
+    let rule = rule_input.into_rule()?;
 
     let rule_hash = create_entry(&EntryTypes::GovernanceRule(rule))?;
     governance_rule_hashes.push(rule_hash);
   }
 
+  let previous_spec = must_get_valid_record(input.previous_action_hash.clone())?
+    .entry()
+    .to_app_option::<ResourceSpecification>()
+    .map_err(|e| ResourceError::SerializationError(format!("{:?}", e)))?;
+
+  // Keep the current validation config unless the update sets one
+  let validation = match input.updated_specification.validation {
+    Some(validation) => validation,
+    None => previous_spec
+      .as_ref()
+      .map(|previous| previous.validation.clone())
+      .unwrap_or_default(),
+  };
+
   let updated_spec = ResourceSpecification {
     name: input.updated_specification.name,
     description: input.updated_specification.description,
@@ -230,10 +209,17 @@ pub fn update_resource_specification(
     image_url: input.updated_specification.image_url,
     tags: input.updated_specification.tags,
     is_active: true,
+    validation,
   };
 
   let updated_spec_hash = update_entry(input.previous_action_hash, &updated_spec)?;
 
+  relink_specification_facets(
+    &input.original_action_hash,
+    previous_spec.as_ref(),
+    &updated_spec,
+  )?;
+
   create_link(
     input.original_action_hash,
     updated_spec_hash.clone(),
@@ -266,34 +252,63 @@ pub struct GetAllResourceSpecificationsOutput {
   pub action_hashes: Vec<ActionHash>,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResourceSpecificationListing {
+  pub action_hash: ActionHash, // Original creation hash, as in `action_hashes`
+  pub specification: ResourceSpecification,
+}
+
 #[hdk_extern]
 pub fn get_all_resource_specifications(_: ()) -> ExternResult<GetAllResourceSpecificationsOutput> {
-  let path = Path::from("resource_specifications");
+  let (action_hashes, specifications) = resolve_specification_links(all_specification_links()?)?
+    .into_iter()
+    .map(|record| (record.action_hash, record.specification))
+    .unzip();
 
-  let links_query = LinkQuery::try_new(
-    path.path_entry_hash()?,
+  Ok(GetAllResourceSpecificationsOutput {
+    specifications,
+    action_hashes,
+  })
+}
+
+/// One page of `get_all_resource_specifications`, in link order.
+#[hdk_extern]
+pub fn get_all_resource_specifications_paginated(
+  page: PageRequest,
+) -> ExternResult<Page<ResourceSpecificationListing>> {
+  let (links, next_cursor) = page.select(all_specification_links()?);
+  Ok(Page {
+    items: resolve_specification_links(links)?,
+    next_cursor,
+  })
+}
+
+fn all_specification_links() -> ExternResult<Vec<Link>> {
+  get_hash_sharded_links(
+    "resource_specifications",
     LinkTypes::AllResourceSpecifications,
-  )?;
-  let links = get_links(links_query, GetStrategy::default())?;
+  )
+}
 
+fn resolve_specification_links(
+  links: Vec<Link>,
+) -> ExternResult<Vec<ResourceSpecificationListing>> {
   let mut specifications = Vec::new();
-  let mut action_hashes = Vec::new();
 
   for link in links {
     if let Some(action_hash) = link.target.into_action_hash() {
       if let Some(record) = get(action_hash.clone(), GetOptions::default())? {
-        if let Ok(Some(spec)) = record.entry().to_app_option::<ResourceSpecification>() {
-          specifications.push(spec);
-          action_hashes.push(action_hash);
+        if let Ok(Some(specification)) = record.entry().to_app_option::<ResourceSpecification>() {
+          specifications.push(ResourceSpecificationListing {
+            action_hash,
+            specification,
+          });
         }
       }
     }
   }
 
-  Ok(GetAllResourceSpecificationsOutput {
-    specifications,
-    action_hashes,
-  })
+  Ok(specifications)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -374,9 +389,7 @@ pub fn get_my_resource_specifications(_: ()) -> ExternResult<Vec<Link>> {
 
 #[hdk_extern]
 pub fn get_resource_specifications_by_category(category: String) -> ExternResult<Vec<Record>> {
-  let category_path = Path::from(format!("specs_by_category_{}", category));
-  let links_query =
-    LinkQuery::try_new(category_path.path_entry_hash()?, LinkTypes::SpecsByCategory)?;
+  let links_query = LinkQuery::try_new(category_anchor(&category)?, LinkTypes::SpecsByCategory)?;
   let links = get_links(links_query, GetStrategy::default())?;
 
   let get_input: Vec<GetInput> = links
@@ -396,8 +409,7 @@ pub fn get_resource_specifications_by_category(category: String) -> ExternResult
 
 #[hdk_extern]
 pub fn get_resource_specifications_by_tag(tag: String) -> ExternResult<Vec<Record>> {
-  let tag_path = Path::from(format!("specs_by_tag_{}", tag));
-  let links_query = LinkQuery::try_new(tag_path.path_entry_hash()?, LinkTypes::SpecsByCategory)?;
+  let links_query = LinkQuery::try_new(tag_anchor(&tag)?, LinkTypes::SpecsByCategory)?;
   let links = get_links(links_query, GetStrategy::default())?;
 
   let get_input: Vec<GetInput> = links
@@ -414,3 +426,211 @@ pub fn get_resource_specifications_by_tag(tag: String) -> ExternResult<Vec<Recor
   let records: Vec<Record> = records.into_iter().flatten().collect();
   Ok(records)
 }
+
+// ============================================================================
+// Faceted search
+// ============================================================================
+//
+// Specs are linked, from their original action hash, under an anchor for their category,
+// for each of their tags, and for each word prefix of their name (nondominium_shared::
+// search). The links are moved when an update changes these fields. A search intersects
+// the anchors of its facets, then checks and ranks the latest version of every candidate.
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SearchResourceSpecificationsInput {
+  #[serde(default)]
+  pub text: Option<String>, // Every word must start a word of the name
+  #[serde(default)]
+  pub category: Option<String>,
+  #[serde(default)]
+  pub tags: Vec<String>, // The spec must have all of them
+  #[serde(default)]
+  pub is_active: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResourceSpecificationMatch {
+  pub action_hash: ActionHash,              // Original creation hash
+  pub specification: ResourceSpecification, // Latest version
+  pub score: u32,                           // Name relevance; 0 for searches without text
+}
+
+fn category_anchor(category: &str) -> ExternResult<EntryHash> {
+  Path::from(format!("specs_by_category_{}", category)).path_entry_hash()
+}
+
+fn tag_anchor(tag: &str) -> ExternResult<EntryHash> {
+  Path::from(format!("specs_by_tag_{}", tag)).path_entry_hash()
+}
+
+fn name_anchor(prefix: &str) -> ExternResult<EntryHash> {
+  Path::from(format!("specs_by_name.{}", prefix)).path_entry_hash()
+}
+
+// Anchors a spec is linked under, with the link type and tag of each link
+fn specification_facets(
+  spec: &ResourceSpecification,
+) -> ExternResult<Vec<(EntryHash, LinkTypes, LinkTag)>> {
+  let mut facets = vec![(
+    category_anchor(&spec.category)?,
+    LinkTypes::SpecsByCategory,
+    LinkTag::new(spec.category.as_str()),
+  )];
+  for tag in &spec.tags {
+    facets.push((
+      tag_anchor(tag)?,
+      LinkTypes::SpecsByCategory, // Reuse for tags
+      LinkTag::new(tag.as_str()),
+    ));
+  }
+  for prefix in index_prefixes(&spec.name) {
+    facets.push((
+      name_anchor(&prefix)?,
+      LinkTypes::SpecsByNameToken,
+      LinkTag::new(prefix.as_str()),
+    ));
+  }
+  Ok(facets)
+}
+
+/// Move a spec's discovery links from the facets of its old version to those of its new one.
+fn relink_specification_facets(
+  original_hash: &ActionHash,
+  old_spec: Option<&ResourceSpecification>,
+  new_spec: &ResourceSpecification,
+) -> ExternResult<()> {
+  let old_facets = match old_spec {
+    Some(spec) => specification_facets(spec)?,
+    None => vec![],
+  };
+  let new_facets = specification_facets(new_spec)?;
+
+  for (anchor, link_type, _) in &old_facets {
+    if new_facets
+      .iter()
+      .any(|(new_anchor, _, _)| new_anchor == anchor)
+    {
+      continue;
+    }
+    let links = get_links(
+      LinkQuery::try_new(anchor.clone(), *link_type)?,
+      GetStrategy::default(),
+    )?;
+    for link in links {
+      if link.target.clone().into_action_hash().as_ref() == Some(original_hash) {
+        delete_link(link.create_link_hash, GetOptions::default())?;
+      }
+    }
+  }
+  for (anchor, link_type, tag) in new_facets {
+    if old_facets
+      .iter()
+      .any(|(old_anchor, _, _)| *old_anchor == anchor)
+    {
+      continue;
+    }
+    create_link(anchor, original_hash.clone(), link_type, tag)?;
+  }
+  Ok(())
+}
+
+/// Find ResourceSpecifications by name words, category, tags and status; all given
+/// criteria must match. Results are ranked by name relevance, then sorted by name.
+#[hdk_extern]
+pub fn search_resource_specifications(
+  input: SearchResourceSpecificationsInput,
+) -> ExternResult<Vec<ResourceSpecificationMatch>> {
+  let terms: Vec<String> = tokenize(input.text.as_deref().unwrap_or_default())
+    .into_iter()
+    .filter(|term| query_prefix(term).is_some())
+    .collect();
+
+  let mut anchors = Vec::new();
+  if let Some(category) = &input.category {
+    anchors.push((category_anchor(category)?, LinkTypes::SpecsByCategory));
+  }
+  for tag in &input.tags {
+    anchors.push((tag_anchor(tag)?, LinkTypes::SpecsByCategory));
+  }
+  for term in &terms {
+    if let Some(prefix) = query_prefix(term) {
+      anchors.push((name_anchor(&prefix)?, LinkTypes::SpecsByNameToken));
+    }
+  }
+
+  // Specs linked under every anchor, or every spec when no facet narrows the search
+  let mut candidates: Option<Vec<ActionHash>> = None;
+  for (anchor, link_type) in anchors {
+    let links = get_links(
+      LinkQuery::try_new(anchor, link_type)?,
+      GetStrategy::default(),
+    )?;
+    let targets: Vec<ActionHash> = links
+      .into_iter()
+      .filter_map(|link| link.target.into_action_hash())
+      .collect();
+    let narrowed: Vec<ActionHash> = match candidates {
+      Some(previous) => previous
+        .into_iter()
+        .filter(|hash| targets.contains(hash))
+        .collect(),
+      None => targets,
+    };
+    if narrowed.is_empty() {
+      return Ok(vec![]);
+    }
+    candidates = Some(narrowed);
+  }
+  let mut candidates = match candidates {
+    Some(candidates) => candidates,
+    None => all_specification_links()?
+      .into_iter()
+      .filter_map(|link| link.target.into_action_hash())
+      .collect(),
+  };
+  candidates.sort();
+  candidates.dedup();
+
+  // Anchors may lag behind updates: the latest version decides
+  let mut matches = Vec::new();
+  for action_hash in candidates {
+    let Some(record) = get_latest_record(action_hash.clone())? else {
+      continue;
+    };
+    let Ok(Some(specification)) = record.entry().to_app_option::<ResourceSpecification>() else {
+      continue;
+    };
+    let score = match_score(&terms, &specification.name);
+    if (!terms.is_empty() && score == 0)
+      || input
+        .category
+        .as_ref()
+        .is_some_and(|category| *category != specification.category)
+      || !input
+        .tags
+        .iter()
+        .all(|tag| specification.tags.contains(tag))
+      || input
+        .is_active
+        .is_some_and(|is_active| is_active != specification.is_active)
+    {
+      continue;
+    }
+    matches.push(ResourceSpecificationMatch {
+      action_hash,
+      specification,
+      score,
+    });
+  }
+
+  matches.sort_by(|a, b| {
+    b.score
+      .cmp(&a.score)
+      .then_with(|| {
+        let a_name = a.specification.name.to_lowercase();
+        a_name.cmp(&b.specification.name.to_lowercase())
+      })
+      .then_with(|| a.action_hash.cmp(&b.action_hash))
+  });
+  Ok(matches)
+}
@@ -0,0 +1,130 @@
+use crate::ResourceError;
+use hdk::prelude::*;
+pub use nondominium_shared::io::resource::ConvertQuantityInput;
+use zome_resource_integrity::*;
+
+// ============================================================================
+// Unit of measure registry
+// ============================================================================
+//
+// Community units are linked from the "units" anchor, tagged with their symbol; the
+// earliest link for a symbol wins. The OM2 common units are built in: they resolve from
+// the OM2 table without any entry, and integrity rejects registering their symbols.
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UnitRecord {
+  pub action_hash: Option<ActionHash>, // None for a built-in OM2 unit
+  pub unit: UnitOfMeasure,
+}
+
+impl From<&Om2Unit> for UnitRecord {
+  fn from(unit: &Om2Unit) -> Self {
+    UnitRecord {
+      action_hash: None,
+      unit: UnitOfMeasure::from(unit),
+    }
+  }
+}
+
+fn units_path() -> ExternResult<EntryHash> {
+  Path::from("units").path_entry_hash()
+}
+
+/// Register a community-defined unit. Its symbol must not already be in use.
+#[hdk_extern]
+pub fn create_unit(unit: UnitOfMeasure) -> ExternResult<UnitRecord> {
+  if get_unit(unit.symbol.clone())?.is_some() {
+    return Err(
+      ResourceError::InvalidInput(format!("Unit '{}' is already registered", unit.symbol)).into(),
+    );
+  }
+  let action_hash = create_entry(&EntryTypes::UnitOfMeasure(unit.clone()))?;
+  create_link(
+    units_path()?,
+    action_hash.clone(),
+    LinkTypes::AllUnits,
+    LinkTag::new(unit.symbol.as_bytes()),
+  )?;
+  Ok(UnitRecord {
+    action_hash: Some(action_hash),
+    unit,
+  })
+}
+
+#[hdk_extern]
+pub fn get_unit(symbol: String) -> ExternResult<Option<UnitRecord>> {
+  if let Some(built_in) = om2_unit(&symbol) {
+    return Ok(Some(UnitRecord::from(built_in)));
+  }
+  let links = get_links(
+    LinkQuery::try_new(units_path()?, LinkTypes::AllUnits)?
+      .tag_prefix(LinkTag::new(symbol.as_bytes())),
+    GetStrategy::default(),
+  )?;
+  Ok(
+    resolve_unit_links(links)?
+      .into_iter()
+      .find(|record| record.unit.symbol == symbol),
+  )
+}
+
+/// Every unit, one per symbol: the built-in OM2 units, then the registered ones.
+#[hdk_extern]
+pub fn get_all_units(_: ()) -> ExternResult<Vec<UnitRecord>> {
+  let links = get_links(
+    LinkQuery::try_new(units_path()?, LinkTypes::AllUnits)?,
+    GetStrategy::default(),
+  )?;
+  let mut units: Vec<UnitRecord> = OM2_UNITS.iter().map(UnitRecord::from).collect();
+  units.extend(resolve_unit_links(links)?);
+  Ok(units)
+}
+
+/// Express a quantity given in one unit in another unit of the same dimension.
+#[hdk_extern]
+pub fn convert_quantity(input: ConvertQuantityInput) -> ExternResult<f64> {
+  if input.from_unit == input.to_unit {
+    return Ok(input.quantity);
+  }
+  let from = resolve_unit(&input.from_unit)?;
+  let to = resolve_unit(&input.to_unit)?;
+  Ok(
+    from
+      .convert(input.quantity, &to)
+      .map_err(ResourceError::InvalidInput)?,
+  )
+}
+
+/// The unit with this symbol, from the built-in OM2 table or the registry.
+pub(crate) fn resolve_unit(symbol: &str) -> ExternResult<UnitOfMeasure> {
+  get_unit(symbol.to_string())?
+    .map(|record| record.unit)
+    .ok_or(ResourceError::UnitNotFound(symbol.to_string()).into())
+}
+
+// Earliest link first, keeping one unit per symbol
+fn resolve_unit_links(mut links: Vec<Link>) -> ExternResult<Vec<UnitRecord>> {
+  links.sort_by_key(|link| link.timestamp);
+  let mut records: Vec<UnitRecord> = Vec::new();
+  for link in links {
+    let Some(action_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    let Some(record) = get(action_hash.clone(), GetOptions::default())? else {
+      continue;
+    };
+    let Ok(Some(unit)) = record.entry().to_app_option::<UnitOfMeasure>() else {
+      continue;
+    };
+    if !records
+      .iter()
+      .any(|existing| existing.unit.symbol == unit.symbol)
+    {
+      records.push(UnitRecord {
+        action_hash: Some(action_hash),
+        unit,
+      });
+    }
+  }
+  Ok(records)
+}
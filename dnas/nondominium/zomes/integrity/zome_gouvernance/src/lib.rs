@@ -2,13 +2,19 @@ use hdi::prelude::*;
 // VfAction, NdoLinkType, BeneficiaryRef, BenefitType, BenefitClause are defined in
 // nondominium_shared::types so coordinator zomes and Sweettest test crates can import
 // them directly without WASM-crate dependency constraints.
+pub use nondominium_shared::location::Location;
 pub use nondominium_shared::types::{
-  BeneficiaryRef, BenefitClause, BenefitType, NdoLinkType, VfAction,
+  BeneficiaryRef, BenefitClause, BenefitType, CommitmentState, CommitmentStatus,
+  FulfillmentProgress, NdoLinkType, ResourceValidationConfig, ResourceValidationStatus,
+  ValidationScheme, ValidationTally, VfAction,
 };
 
 pub mod ppr;
 pub use ppr::*;
 
+/// `validation_type` of the ValidationReceipts that count as votes on a ResourceValidation
+pub const RESOURCE_APPROVAL: &str = "resource_approval";
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct ValidationReceipt {
@@ -27,10 +33,14 @@ pub struct EconomicEvent {
   pub provider: AgentPubKey,
   pub receiver: AgentPubKey,
   pub resource_inventoried_as: ActionHash, // Link to the EconomicResource
-  pub affects: ActionHash,                 // Link to the EconomicResource that is affected
+  pub affects: ActionHash,                 // EconomicResource version the event was applied to
   pub resource_quantity: f64,
   pub event_time: Timestamp,
   pub note: Option<String>,
+  #[serde(default, deserialize_with = "nondominium_shared::location::deserialize_location")]
+  pub to_location: Option<Location>, // Destination of a Move
+  #[serde(default)]
+  pub resource_unit: Option<String>, // Unit symbol of resource_quantity
 }
 
 #[hdk_entry_helper]
@@ -45,6 +55,29 @@ pub struct Commitment {
   pub due_date: Timestamp,
   pub note: Option<String>,
   pub committed_at: Timestamp,
+  #[serde(default)]
+  pub resource_quantity: Option<f64>, // Quantity to deliver; None when not quantified
+  #[serde(default)]
+  pub status: CommitmentStatus, // Only field that changes after creation
+}
+
+/// Action and due date carried on ProviderToCommitment / ReceiverToCommitment links, so
+/// listings can be filtered before any Commitment is fetched.
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+pub struct CommitmentLinkTag {
+  pub action: VfAction,
+  pub due_date: Timestamp,
+}
+
+impl CommitmentLinkTag {
+  pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+    let bytes = SerializedBytes::try_from(self.clone()).map_err(|e| wasm_error!(e))?;
+    Ok(LinkTag::new(bytes.bytes().clone()))
+  }
+
+  pub fn from_link_tag(tag: LinkTag) -> Option<Self> {
+    Self::try_from(SerializedBytes::from(UnsafeBytes::from(tag.into_inner()))).ok()
+  }
 }
 
 #[hdk_entry_helper]
@@ -60,14 +93,23 @@ pub struct Claim {
 #[derive(Clone, PartialEq)]
 pub struct ResourceValidation {
   pub resource: ActionHash, // Link to the EconomicResource being validated
-  pub validation_scheme: String, // e.g., "2-of-3", "simple_majority"
-  pub required_validators: u32,
-  pub current_validators: u32,
-  pub status: String, // "pending", "approved", "rejected"
+  pub resource_spec: ActionHash, // ResourceSpecification version the scheme is taken from
+  pub validation_scheme: String, // e.g., "2-of-3", "simple_majority", "unanimous"
+  pub required_validators: u32, // Validator panel size (n)
+  // Votes are not counted here: the tally is recomputed from ValidatedItemToReceipt links
+  pub status: ResourceValidationStatus,
+  #[serde(default)]
+  pub receipts: Vec<ActionHash>, // resource_approval ValidationReceipts behind the outcome
   pub created_at: Timestamp,
   pub updated_at: Timestamp,
 }
 
+impl ResourceValidation {
+  pub fn scheme(&self) -> Result<ValidationScheme, String> {
+    ValidationScheme::parse(&self.validation_scheme, self.required_validators)
+  }
+}
+
 /// Permanent validated structural link between two NDOs.
 /// Created only on EconomicEvent fulfillment. Immutable and undeletable (OVN license).
 #[hdk_entry_helper]
@@ -116,6 +158,22 @@ pub struct Agreement {
 // BenefitClause, BeneficiaryRef, BenefitType are re-exported from nondominium_shared::types
 // (see the `pub use` at the top of this file).
 
+/// An activity (repair, transport, assembly...) that Commitments, EconomicEvents and
+/// Contributions are inputs of. Only `finished` changes after creation.
+/// VF: vf:Process
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Process {
+  pub name: String,
+  pub based_on: Option<ActionHash>, // Specification the process follows (vf:basedOn)
+  pub note: Option<String>,
+  pub planned_start: Option<Timestamp>,
+  pub planned_end: Option<Timestamp>,
+  pub finished: bool,
+  pub created_by: AgentPubKey,
+  pub created_at: Timestamp,
+}
+
 #[hdk_entry_types]
 #[unit_enum(UnitEntryTypes)]
 #[derive(Serialize, Deserialize, SerializedBytes)]
@@ -131,6 +189,12 @@ pub enum EntryTypes {
   NdoHardLink(NdoHardLink),
   Contribution(Contribution),
   Agreement(Agreement),
+  // Bilateral PPR signing inbox
+  PprSignatureRequest(PprSignatureRequest),
+  PprSignatureResponse(PprSignatureResponse),
+  Process(Process),
+  #[entry_type(visibility = "private")]
+  PprPrivateTerms(PprPrivateTerms),
 }
 
 #[hdk_link_types]
@@ -157,6 +221,25 @@ pub enum LinkTypes {
   ContributionToEvent,    // Contribution -> EconomicEvent
   NdoToAgreement,         // ndo_identity_hash -> Agreement (latest)
   AgreementUpdates,       // Agreement -> Agreement (version chain)
+  // Bilateral PPR signing inbox
+  AgentToPendingPprSignatures, // counterparty -> PprSignatureRequest
+  AgentToOutgoingPprRequests,  // initiator -> PprSignatureRequest
+  PprRequestToResponse,        // PprSignatureRequest -> PprSignatureResponse
+  PprRequestToClaims,          // PprSignatureRequest -> committed PrivateParticipationClaim
+  // Resource validation progress
+  ResourceValidationUpdates, // No longer written: chains are walked with get_details
+  // ValueFlows processes
+  AllProcesses,          // Path("all_processes") -> Process
+  AgentToProcesses,      // creator -> Process
+  ProcessUpdates,        // Process (original) -> finished Process
+  ProcessToCommitment,   // Process -> Commitment (input_of)
+  ProcessToEvent,        // Process -> EconomicEvent
+  ProcessToContribution, // Process -> Contribution (input_of)
+  CommitmentUpdates,     // No longer written: chains are walked with get_details
+  ProviderToCommitment,  // provider -> Commitment, tagged with action and due date
+  ReceiverToCommitment,  // receiver -> Commitment, tagged with action and due date
+  // Sharded discovery anchors
+  EconomicEventMonths, // Path("all_economic_events") -> Path("all_economic_events.{YYYY-MM}")
 }
 
 #[hdk_extern]
@@ -179,7 +262,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
     match store_entry {
       OpEntry::CreateEntry { app_entry, action } => match app_entry {
         EntryTypes::PrivateParticipationClaim(claim) => {
-          return validate_private_participation_claim(claim);
+          return validate_private_participation_claim(claim, action);
         }
         EntryTypes::NdoHardLink(link) => {
           return validate_create_ndo_hard_link(link, action);
@@ -190,7 +273,59 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
         EntryTypes::Agreement(a) => {
           return validate_create_agreement(a, action);
         }
-        _ => {}
+        EntryTypes::PprSignatureRequest(request) => {
+          return validate_create_ppr_signature_request(request, action);
+        }
+        EntryTypes::PprSignatureResponse(response) => {
+          return validate_create_ppr_signature_response(response, action);
+        }
+        EntryTypes::PprPrivateTerms(terms) => {
+          return validate_ppr_private_terms(terms);
+        }
+        EntryTypes::ValidationReceipt(receipt) => {
+          if receipt.validator != action.author {
+            return Ok(ValidateCallbackResult::Invalid(
+              "validator must equal action.author".to_string(),
+            ));
+          }
+          // Creators cannot vote on their own resource
+          if receipt.validation_type == RESOURCE_APPROVAL
+            && *must_get_action(receipt.validated_item)?.action().author() == action.author
+          {
+            return Ok(ValidateCallbackResult::Invalid(
+              "A resource's creator cannot vote on its validation".to_string(),
+            ));
+          }
+        }
+        EntryTypes::Process(process) => {
+          return validate_create_process(process, action);
+        }
+        EntryTypes::Commitment(commitment) => {
+          return validate_create_commitment(commitment, action);
+        }
+        EntryTypes::Claim(claim) => {
+          return validate_create_claim(claim, action);
+        }
+        EntryTypes::EconomicEvent(event)
+          if action.author != event.provider && action.author != event.receiver =>
+        {
+          return Ok(ValidateCallbackResult::Invalid(
+            "EconomicEvents must be authored by their provider or receiver".to_string(),
+          ));
+        }
+        EntryTypes::EconomicEvent(event) => {
+          if !(event.resource_quantity.is_finite() && event.resource_quantity > 0.0) {
+            return Ok(ValidateCallbackResult::Invalid(
+              "EconomicEvent resource_quantity must be a positive number".to_string(),
+            ));
+          }
+          if let Some(Err(e)) = event.to_location.as_ref().map(Location::validate) {
+            return Ok(ValidateCallbackResult::Invalid(e));
+          }
+        }
+        EntryTypes::ResourceValidation(validation) => {
+          return validate_create_resource_validation(validation, action);
+        }
       },
       OpEntry::UpdateEntry { app_entry, .. } => match app_entry {
         EntryTypes::NdoHardLink(_) => {
@@ -204,6 +339,13 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
           // and ndo_identity_hash immutability.
           return validate_agreement_content(&a);
         }
+        EntryTypes::PprSignatureRequest(_)
+        | EntryTypes::PprSignatureResponse(_)
+        | EntryTypes::PprPrivateTerms(_) => {
+          return Ok(ValidateCallbackResult::Invalid(
+            "PPR signature requests, responses and terms cannot be updated".to_string(),
+          ));
+        }
         _ => {}
       },
       _ => {}
@@ -246,10 +388,62 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
               "Agreement entries cannot be deleted; supersede via versioned update".to_string(),
             ));
           }
+          Some(EntryTypes::PprSignatureRequest(_))
+          | Some(EntryTypes::PprSignatureResponse(_))
+          | Some(EntryTypes::PprPrivateTerms(_)) => {
+            return Ok(ValidateCallbackResult::Invalid(
+              "PPR signature requests, responses and terms cannot be deleted".to_string(),
+            ));
+          }
           _ => {}
         }
       }
-      OpRecord::UpdateEntry { original_action_hash, app_entry, .. } => {
+      OpRecord::UpdateEntry {
+        original_action_hash,
+        app_entry,
+        action,
+        ..
+      } => {
+        if let EntryTypes::Commitment(updated) = &app_entry {
+          let previous: Commitment = must_get_valid_record(original_action_hash.clone())?
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(WasmErrorInner::Guest(format!("{:?}", e))))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+              "Original Commitment entry not found".to_string()
+            )))?;
+          return Ok(validate_commitment_update(
+            &previous,
+            updated,
+            &action.author,
+          ));
+        }
+        if let EntryTypes::Process(updated) = &app_entry {
+          let original_record = must_get_valid_record(original_action_hash.clone())?;
+          let original: Process = original_record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(WasmErrorInner::Guest(format!("{:?}", e))))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+              "Original Process entry not found".to_string()
+            )))?;
+          return Ok(validate_process_update(
+            &original,
+            updated,
+            original_record.action().author(),
+            &action.author,
+          ));
+        }
+        if let EntryTypes::ResourceValidation(updated) = &app_entry {
+          let original: ResourceValidation = must_get_valid_record(original_action_hash.clone())?
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(WasmErrorInner::Guest(format!("{:?}", e))))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+              "Original ResourceValidation entry not found".to_string()
+            )))?;
+          return validate_resource_validation_update(&original, updated, &action);
+        }
         if let EntryTypes::Agreement(updated) = app_entry {
           let original_record = must_get_valid_record(original_action_hash)?;
           let original_action = original_record.action().clone();
@@ -288,9 +482,528 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
     }
   }
 
+  if let FlatOp::RegisterCreateLink {
+    link_type: LinkTypes::ResourceToValidation,
+    base_address,
+    target_address,
+    action,
+    ..
+  } = op.flattened::<EntryTypes, LinkTypes>()?
+  {
+    return validate_create_resource_to_validation_link(
+      base_address,
+      target_address,
+      &action.author,
+    );
+  }
+
+  // Phase 3: agent-centric commitment indexes
+  if let FlatOp::RegisterCreateLink {
+    link_type: link_type @ (LinkTypes::ProviderToCommitment | LinkTypes::ReceiverToCommitment),
+    base_address,
+    target_address,
+    tag,
+    action,
+  } = op.flattened::<EntryTypes, LinkTypes>()?
+  {
+    return validate_create_commitment_party_link(
+      link_type,
+      base_address,
+      target_address,
+      tag,
+      &action.author,
+    );
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// ProviderToCommitment / ReceiverToCommitment links hang off the party they name, are
+// made by one of the commitment's parties, and carry its action and due date, so that
+// listings can trust the tag and the base.
+fn validate_create_commitment_party_link(
+  link_type: LinkTypes,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  tag: LinkTag,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  let Some(commitment_hash) = target_address.into_action_hash() else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A commitment party link must target a Commitment".to_string(),
+    ));
+  };
+  let Some(commitment) = must_get_valid_record(commitment_hash)?
+    .entry()
+    .to_app_option::<Commitment>()
+    .map_err(|e| wasm_error!(e))?
+  else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A commitment party link must target a Commitment".to_string(),
+    ));
+  };
+
+  let party = match link_type {
+    LinkTypes::ProviderToCommitment => &commitment.provider,
+    _ => &commitment.receiver,
+  };
+  if base_address != AnyLinkableHash::from(party.clone()) {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A commitment party link must be based on the party it names".to_string(),
+    ));
+  }
+  if *author != commitment.provider && *author != commitment.receiver {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only a commitment's parties can index it".to_string(),
+    ));
+  }
+  let expected_tag = CommitmentLinkTag {
+    action: commitment.action,
+    due_date: commitment.due_date,
+  }
+  .to_link_tag()?;
+  if tag != expected_tag {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A commitment party link must be tagged with the commitment's action and due date"
+        .to_string(),
+    ));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// Name of zome_resource's integrity zome in the DNA manifest
+const RESOURCE_INTEGRITY_ZOME_NAME: &str = "zome_resource_integrity";
+
+// Subsets of zome_resource's EconomicResource and ResourceSpecification read when a
+// ResourceValidation is opened
+#[derive(Serialize, Deserialize, SerializedBytes, Debug)]
+struct CitedEconomicResource {
+  pub custodian: AgentPubKey,
+}
+
+#[derive(Serialize, Deserialize, SerializedBytes, Debug)]
+struct CitedResourceSpecification {
+  pub name: String,
+  #[serde(default)]
+  pub validation: ResourceValidationConfig,
+}
+
+// A resource's ResourceValidation is opened by its creator when they create it (REQ-GOV-02):
+// it is the first ResourceValidation of the resource on their chain since its create action,
+// and copies the scheme of the ResourceSpecification the resource was linked to there. The
+// resource therefore has exactly one validation, and its creator cannot pick a laxer scheme.
+fn validate_create_resource_validation(
+  validation: ResourceValidation,
+  action: Create,
+) -> ExternResult<ValidateCallbackResult> {
+  if let Err(e) = validation.scheme() {
+    return Ok(ValidateCallbackResult::Invalid(e));
+  }
+  if validation.status != ResourceValidationStatus::Pending || !validation.receipts.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "ResourceValidation must start Pending, without receipts".to_string(),
+    ));
+  }
+
+  let resource_zome = resource_integrity_zome_index()?;
+  let resource_record = must_get_valid_record(validation.resource.clone())?;
+  let is_resource = matches!(resource_record.action(), Action::Create(create)
+    if create.author == action.author && from_zome(&create.entry_type, resource_zome))
+    && matches!(
+      resource_record.entry().to_app_option::<CitedEconomicResource>(),
+      Ok(Some(_))
+    );
+  if !is_resource {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A ResourceValidation is opened by the creator of the EconomicResource it validates"
+        .to_string(),
+    ));
+  }
+
+  let spec_record = must_get_valid_record(validation.resource_spec.clone())?;
+  let spec = match spec_record.entry().to_app_option::<CitedResourceSpecification>() {
+    Ok(Some(spec))
+      if spec_record
+        .action()
+        .entry_type()
+        .is_some_and(|entry_type| from_zome(entry_type, resource_zome)) =>
+    {
+      spec
+    }
+    _ => {
+      return Ok(ValidateCallbackResult::Invalid(
+        "resource_spec does not reference a ResourceSpecification".to_string(),
+      ));
+    }
+  };
+  if spec.validation.validation_scheme != validation.validation_scheme
+    || spec.validation.required_validators != validation.required_validators
+  {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A ResourceValidation must use the validation scheme of its ResourceSpecification"
+        .to_string(),
+    ));
+  }
+  let spec_versions = update_chain_back_from(validation.resource_spec.clone())?;
+
+  let activity = must_get_agent_activity(
+    action.author.clone(),
+    ChainFilter::new(action.prev_action.clone()).until_hash(validation.resource.clone()),
+  )?;
+  let own_zome = zome_info()?.id;
+  let mut linked_to_spec = false;
+  for item in activity {
+    match item.action.action() {
+      Action::CreateLink(link)
+        if link.zome_index == resource_zome
+          && link.base_address == AnyLinkableHash::from(validation.resource.clone()) =>
+      {
+        linked_to_spec |= link
+          .target_address
+          .clone()
+          .into_action_hash()
+          .is_some_and(|target| spec_versions.contains(&target));
+      }
+      Action::Create(create) if from_zome(&create.entry_type, own_zome) => {
+        let EntryType::App(def) = &create.entry_type else {
+          continue;
+        };
+        let entry = must_get_entry(create.entry_hash.clone())?;
+        if let Some(EntryTypes::ResourceValidation(earlier)) =
+          EntryTypes::deserialize_from_type(def.zome_index, def.entry_index, entry.as_content())?
+        {
+          if earlier.resource == validation.resource {
+            return Ok(ValidateCallbackResult::Invalid(
+              "The EconomicResource already has a ResourceValidation".to_string(),
+            ));
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+  if !linked_to_spec {
+    return Ok(ValidateCallbackResult::Invalid(
+      "resource_spec is not the ResourceSpecification the EconomicResource was created with"
+        .to_string(),
+    ));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// ResourceToValidation links are made by the resource's creator, from the resource to the
+// validation they opened for it, so that readers can trust them.
+fn validate_create_resource_to_validation_link(
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  let Some(validation_hash) = target_address.into_action_hash() else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A ResourceToValidation link must target a ResourceValidation".to_string(),
+    ));
+  };
+  let record = must_get_valid_record(validation_hash)?;
+  let Ok(Some(validation)) = record.entry().to_app_option::<ResourceValidation>() else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A ResourceToValidation link must target a ResourceValidation".to_string(),
+    ));
+  };
+  if base_address != AnyLinkableHash::from(validation.resource)
+    || record.action().author() != author
+    || !matches!(record.action(), Action::Create(_))
+  {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only a ResourceValidation's creator may link it, from its resource".to_string(),
+    ));
+  }
   Ok(ValidateCallbackResult::Valid)
 }
 
+fn resource_integrity_zome_index() -> ExternResult<ZomeIndex> {
+  let position = dna_info()?
+    .zome_names
+    .iter()
+    .position(|name| name.0 == RESOURCE_INTEGRITY_ZOME_NAME)
+    .ok_or(wasm_error!(WasmErrorInner::Guest(format!(
+      "{} is not part of this DNA",
+      RESOURCE_INTEGRITY_ZOME_NAME
+    ))))?;
+  Ok(ZomeIndex(position as u8))
+}
+
+fn from_zome(entry_type: &EntryType, zome: ZomeIndex) -> bool {
+  matches!(entry_type, EntryType::App(def) if def.zome_index == zome)
+}
+
+// `action_hash` and the versions it updates, back to the create action
+fn update_chain_back_from(action_hash: ActionHash) -> ExternResult<Vec<ActionHash>> {
+  let mut versions = vec![action_hash.clone()];
+  let mut current = action_hash;
+  while let Action::Update(update) = must_get_action(current)?.action() {
+    current = update.original_action_address.clone();
+    versions.push(current.clone());
+  }
+  Ok(versions)
+}
+
+// A ResourceValidation only changes to record its outcome, once: Pending -> Approved |
+// Rejected, as the first update its author makes of the original, with everything but
+// `status`, `receipts` and `updated_at` unchanged. The outcome must be the one the scheme
+// gives for the cited receipts: `resource_approval` votes on this resource, one per
+// validator, none by the resource's creator, no more than the panel has seats. Integrity
+// cannot list the receipts linked to the resource, so it checks the cited set rather than
+// all votes.
+fn validate_resource_validation_update(
+  original: &ResourceValidation,
+  updated: &ResourceValidation,
+  action: &Update,
+) -> ExternResult<ValidateCallbackResult> {
+  let created = must_get_action(action.original_action_address.clone())?;
+  if !matches!(created.action(), Action::Create(_)) {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A ResourceValidation outcome must update the original ResourceValidation".to_string(),
+    ));
+  }
+  let activity = must_get_agent_activity(
+    action.author.clone(),
+    ChainFilter::new(action.prev_action.clone()).until_timestamp(created.action().timestamp()),
+  )?;
+  let already_updated = activity.iter().any(|item| {
+    matches!(item.action.action(), Action::Update(update)
+      if update.original_action_address == action.original_action_address)
+  });
+  if already_updated {
+    return Ok(ValidateCallbackResult::Invalid(
+      "The ResourceValidation outcome has already been recorded".to_string(),
+    ));
+  }
+
+  if original.status != ResourceValidationStatus::Pending {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "ResourceValidation is already {:?}",
+      original.status
+    )));
+  }
+  if updated.status == ResourceValidationStatus::Pending {
+    return Ok(ValidateCallbackResult::Invalid(
+      "ResourceValidation updates must record an outcome".to_string(),
+    ));
+  }
+  let unchanged = ResourceValidation {
+    status: updated.status.clone(),
+    receipts: updated.receipts.clone(),
+    updated_at: updated.updated_at,
+    ..original.clone()
+  };
+  if *updated != unchanged {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only status, receipts and updated_at of a ResourceValidation may change".to_string(),
+    ));
+  }
+
+  let scheme = match original.scheme() {
+    Ok(scheme) => scheme,
+    Err(e) => return Ok(ValidateCallbackResult::Invalid(e)),
+  };
+  if updated.receipts.len() > scheme.panel_size() as usize {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "A ResourceValidation counts at most {} votes",
+      scheme.panel_size()
+    )));
+  }
+
+  let creator = must_get_action(original.resource.clone())?
+    .action()
+    .author()
+    .clone();
+  let mut validators = HashSet::new();
+  let mut tally = ValidationTally::default();
+  for receipt_hash in &updated.receipts {
+    let receipt: ValidationReceipt = must_get_valid_record(receipt_hash.clone())?
+      .entry()
+      .to_app_option()
+      .map_err(|e| wasm_error!(WasmErrorInner::Guest(format!("{:?}", e))))?
+      .ok_or(wasm_error!(WasmErrorInner::Guest(
+        "Cited ValidationReceipt entry not found".to_string()
+      )))?;
+    if receipt.validated_item != original.resource || receipt.validation_type != RESOURCE_APPROVAL {
+      return Ok(ValidateCallbackResult::Invalid(
+        "Cited receipts must be resource_approval votes on this resource".to_string(),
+      ));
+    }
+    if receipt.validator == creator || !validators.insert(receipt.validator) {
+      return Ok(ValidateCallbackResult::Invalid(
+        "Cited receipts must come from distinct validators other than the creator".to_string(),
+      ));
+    }
+    if receipt.approved {
+      tally.approvals += 1;
+    } else {
+      tally.rejections += 1;
+    }
+  }
+
+  if scheme.outcome(&tally) != updated.status {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "The cited votes ({} approvals, {} rejections) do not decide {:?}",
+      tally.approvals, tally.rejections, updated.status
+    )));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+fn validate_create_commitment(
+  commitment: Commitment,
+  action: Create,
+) -> ExternResult<ValidateCallbackResult> {
+  if commitment.receiver != action.author {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Commitments are proposed by their receiver".to_string(),
+    ));
+  }
+  if commitment.status != CommitmentStatus::Proposed {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Commitment must start Proposed".to_string(),
+    ));
+  }
+  if let Some(quantity) = commitment.resource_quantity {
+    if !(quantity.is_finite() && quantity > 0.0) {
+      return Ok(ValidateCallbackResult::Invalid(
+        "Commitment resource_quantity must be a positive number".to_string(),
+      ));
+    }
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// A Claim is made by the Commitment's provider, for an EconomicEvent of the committed action
+// between the same two parties. Whether the Commitment was Accepted at the time lives in
+// its update chain, which integrity cannot follow; `fulfillment_progress` checks it.
+fn validate_create_claim(claim: Claim, action: Create) -> ExternResult<ValidateCallbackResult> {
+  let Ok(Some(commitment)) = must_get_valid_record(claim.fulfills)?
+    .entry()
+    .to_app_option::<Commitment>()
+  else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Claim must fulfill a Commitment".to_string(),
+    ));
+  };
+  if commitment.provider != action.author {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only the Commitment's provider may claim it".to_string(),
+    ));
+  }
+  let Ok(Some(event)) = must_get_valid_record(claim.fulfilled_by)?
+    .entry()
+    .to_app_option::<EconomicEvent>()
+  else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Claim must be fulfilled by an EconomicEvent".to_string(),
+    ));
+  };
+  if event.action != commitment.action
+    || event.provider != commitment.provider
+    || event.receiver != commitment.receiver
+  {
+    return Ok(ValidateCallbackResult::Invalid(
+      "EconomicEvent does not match the Commitment's action, provider and receiver".to_string(),
+    ));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// A Commitment update changes only its status, along an allowed transition, authored by
+// the party entitled to make it: the provider accepts or declines, the receiver cancels.
+fn validate_commitment_update(
+  previous: &Commitment,
+  updated: &Commitment,
+  author: &AgentPubKey,
+) -> ValidateCallbackResult {
+  if !previous.status.can_transition_to(updated.status) {
+    return ValidateCallbackResult::Invalid(format!(
+      "Commitment cannot move from {:?} to {:?}",
+      previous.status, updated.status
+    ));
+  }
+  let (entitled, party) = match updated.status {
+    CommitmentStatus::Accepted | CommitmentStatus::Declined => (&previous.provider, "provider"),
+    _ => (&previous.receiver, "receiver"),
+  };
+  if author != entitled {
+    return ValidateCallbackResult::Invalid(format!(
+      "Only the {} may mark a Commitment {:?}",
+      party, updated.status
+    ));
+  }
+  let unchanged = Commitment {
+    status: updated.status,
+    ..previous.clone()
+  };
+  if *updated != unchanged {
+    return ValidateCallbackResult::Invalid(
+      "Only the status of a Commitment may change".to_string(),
+    );
+  }
+  ValidateCallbackResult::Valid
+}
+
+fn validate_create_process(
+  process: Process,
+  action: Create,
+) -> ExternResult<ValidateCallbackResult> {
+  if process.created_by != action.author {
+    return Ok(ValidateCallbackResult::Invalid(
+      "created_by must equal action.author".to_string(),
+    ));
+  }
+  if process.name.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Process name cannot be empty".to_string(),
+    ));
+  }
+  if process.finished {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A Process cannot be created finished".to_string(),
+    ));
+  }
+  if let (Some(start), Some(end)) = (process.planned_start, process.planned_end) {
+    if end < start {
+      return Ok(ValidateCallbackResult::Invalid(
+        "planned_end must not precede planned_start".to_string(),
+      ));
+    }
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// Finishing is the only update: authored by the creator, flipping `finished` to true.
+fn validate_process_update(
+  original: &Process,
+  updated: &Process,
+  original_author: &AgentPubKey,
+  author: &AgentPubKey,
+) -> ValidateCallbackResult {
+  if author != original_author {
+    return ValidateCallbackResult::Invalid("Only the Process creator can finish it".to_string());
+  }
+  if original.finished {
+    return ValidateCallbackResult::Invalid("Process is already finished".to_string());
+  }
+  let finished = Process {
+    finished: true,
+    ..original.clone()
+  };
+  if *updated != finished {
+    return ValidateCallbackResult::Invalid(
+      "A Process update may only mark it finished".to_string(),
+    );
+  }
+  ValidateCallbackResult::Valid
+}
+
 fn validate_create_ndo_hard_link(
   link: NdoHardLink,
   action: Create,
@@ -364,23 +1077,139 @@ fn validate_create_agreement(a: Agreement, action: Create) -> ExternResult<Valid
   validate_agreement_content(&a)
 }
 
-/// Validate a Private Participation Claim entry
+/// Validate a Private Participation Claim entry: committed by one of its two distinct parties,
+/// carrying both parties' signatures over the signed data its own terms rebuild
 pub fn validate_private_participation_claim(
   claim: PrivateParticipationClaim,
+  action: Create,
 ) -> ExternResult<ValidateCallbackResult> {
   // Validate performance metrics
-  if let Err(e) = claim.performance_metrics.validate() {
-    return Ok(ValidateCallbackResult::Invalid(format!(
-      "Invalid performance metrics: {}",
-      e
-    )));
+  for metrics in [&claim.performance_metrics, &claim.counterparty_metrics] {
+    if let Err(e) = metrics.validate() {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Invalid performance metrics: {}",
+        e
+      )));
+    }
+  }
+
+  if claim.provider == claim.receiver {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A participation claim needs distinct provider and receiver".to_string(),
+    ));
+  }
+  if claim.counterparty != claim.provider && claim.counterparty != claim.receiver {
+    return Ok(ValidateCallbackResult::Invalid(
+      "counterparty must be the provider or the receiver".to_string(),
+    ));
+  }
+  if action.author != claim.provider && action.author != claim.receiver {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only the provider or the receiver can commit a participation claim".to_string(),
+    ));
+  }
+
+  let signature = &claim.bilateral_signature;
+  let signed_data_hash =
+    ppr_signed_data_hash(&ppr_signing_data(&claim.signed_terms(), claim.requested_at));
+  if signature.signed_data_hash != signed_data_hash {
+    return Ok(ValidateCallbackResult::Invalid(
+      "signed_data_hash does not match the claim's terms and requested_at".to_string(),
+    ));
+  }
+  if !verify_signature(
+    claim.owner().clone(),
+    signature.recipient_signature.clone(),
+    signed_data_hash.to_vec(),
+  )? {
+    return Ok(ValidateCallbackResult::Invalid(
+      "recipient_signature does not verify against signed_data_hash".to_string(),
+    ));
+  }
+  if !verify_signature(
+    claim.counterparty.clone(),
+    signature.counterparty_signature.clone(),
+    signed_data_hash.to_vec(),
+  )? {
+    return Ok(ValidateCallbackResult::Invalid(
+      "counterparty_signature does not verify against signed_data_hash".to_string(),
+    ));
   }
 
-  // For now, skip timestamp validation in integrity zome since sys_time() is not available
-  // This validation would be done in the coordinator zome
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// The request only publishes signed_data_hash; the counterparty checks it against the full
+// terms before countersigning, and each claim is checked against them when committed.
+fn validate_create_ppr_signature_request(
+  request: PprSignatureRequest,
+  action: Create,
+) -> ExternResult<ValidateCallbackResult> {
+  if request.claim_types.len() != 2 {
+    return Ok(ValidateCallbackResult::Invalid(
+      "PPR signature request must carry exactly 2 claim types".to_string(),
+    ));
+  }
+  if request.provider == request.receiver {
+    return Ok(ValidateCallbackResult::Invalid(
+      "PPR signature request needs distinct provider and receiver".to_string(),
+    ));
+  }
+  if request.counterparty_of(&action.author).is_none() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "PPR signature request author must be the provider or the receiver".to_string(),
+    ));
+  }
+  if !verify_signature(
+    action.author,
+    request.initiator_signature,
+    request.signed_data_hash.to_vec(),
+  )? {
+    return Ok(ValidateCallbackResult::Invalid(
+      "initiator_signature does not verify against signed_data_hash".to_string(),
+    ));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
 
-  // Validate claim type description exists (ensures enum is valid)
-  let _description = claim.claim_type.description();
+fn validate_ppr_private_terms(terms: PprPrivateTerms) -> ExternResult<ValidateCallbackResult> {
+  for metrics in [&terms.provider_metrics, &terms.receiver_metrics] {
+    if let Err(e) = metrics.validate() {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Invalid performance metrics: {}",
+        e
+      )));
+    }
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
 
+fn validate_create_ppr_signature_response(
+  response: PprSignatureResponse,
+  action: Create,
+) -> ExternResult<ValidateCallbackResult> {
+  let request_record = must_get_valid_record(response.request_hash)?;
+  let request = match request_record.entry().to_app_option::<PprSignatureRequest>() {
+    Ok(Some(request)) => request,
+    _ => {
+      return Ok(ValidateCallbackResult::Invalid(
+        "request_hash must reference a PprSignatureRequest".to_string(),
+      ))
+    }
+  };
+  if request.counterparty_of(request_record.action().author()) != Some(action.author.clone()) {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only the requested counterparty can respond to a PPR signature request".to_string(),
+    ));
+  }
+  if !verify_signature(
+    action.author,
+    response.counterparty_signature,
+    request.signed_data_hash.to_vec(),
+  )? {
+    return Ok(ValidateCallbackResult::Invalid(
+      "counterparty_signature does not verify against the request's signed_data_hash".to_string(),
+    ));
+  }
   Ok(ValidateCallbackResult::Valid)
 }
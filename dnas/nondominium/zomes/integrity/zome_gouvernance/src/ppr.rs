@@ -265,37 +265,88 @@ pub struct PrivateParticipationClaim {
   pub counterparty: AgentPubKey, // The other agent involved in the interaction
   pub resource_hash: Option<ActionHash>, // Optional link to the resource involved
   pub notes: Option<String>,     // Optional contextual notes
+
+  // The rest of the signed terms, so the bilateral signature can be checked
+  pub provider: AgentPubKey,
+  pub receiver: AgentPubKey,
+  pub counterparty_claim_type: ParticipationClaimType,
+  pub counterparty_metrics: PerformanceMetrics,
+  pub requested_at: Timestamp,
 }
 
 impl PrivateParticipationClaim {
-  /// Create a new PPR claim with validation
+  /// Create the provider's (`for_provider`) or the receiver's claim of a signed PPR pair
   pub fn new(
-    fulfills: ActionHash,
-    fulfilled_by: ActionHash,
-    claim_type: ParticipationClaimType,
-    performance_metrics: PerformanceMetrics,
+    terms: &PprTerms,
+    for_provider: bool,
     bilateral_signature: CryptographicSignature,
-    counterparty: AgentPubKey,
-    resource_hash: Option<ActionHash>,
-    notes: Option<String>,
+    requested_at: Timestamp,
     claimed_at: Timestamp,
   ) -> Result<Self, String> {
+    if terms.claim_types.len() != 2 {
+      return Err("A PPR pair carries exactly 2 claim types".to_string());
+    }
+    let (own, other) = if for_provider { (0, 1) } else { (1, 0) };
+    let (performance_metrics, counterparty_metrics, counterparty) = if for_provider {
+      (&terms.provider_metrics, &terms.receiver_metrics, &terms.receiver)
+    } else {
+      (&terms.receiver_metrics, &terms.provider_metrics, &terms.provider)
+    };
     // Validate performance metrics
     performance_metrics.validate()?;
 
     Ok(Self {
-      fulfills,
-      fulfilled_by,
+      fulfills: terms.fulfills.clone(),
+      fulfilled_by: terms.fulfilled_by.clone(),
       claimed_at,
-      claim_type,
-      performance_metrics,
+      claim_type: terms.claim_types[own].clone(),
+      performance_metrics: performance_metrics.clone(),
       bilateral_signature,
-      counterparty,
-      resource_hash,
-      notes,
+      counterparty: counterparty.clone(),
+      resource_hash: terms.resource_hash.clone(),
+      notes: terms.notes.clone(),
+      provider: terms.provider.clone(),
+      receiver: terms.receiver.clone(),
+      counterparty_claim_type: terms.claim_types[other].clone(),
+      counterparty_metrics: counterparty_metrics.clone(),
+      requested_at,
     })
   }
 
+  /// The agent this claim belongs to: whichever of provider/receiver is not the counterparty
+  pub fn owner(&self) -> &AgentPubKey {
+    if self.counterparty == self.receiver {
+      &self.provider
+    } else {
+      &self.receiver
+    }
+  }
+
+  /// The PPR pair's terms as both parties signed them
+  pub fn signed_terms(&self) -> PprTerms {
+    let own = (self.claim_type.clone(), self.performance_metrics.clone());
+    let other = (
+      self.counterparty_claim_type.clone(),
+      self.counterparty_metrics.clone(),
+    );
+    let (provider_side, receiver_side) = if self.owner() == &self.provider {
+      (own, other)
+    } else {
+      (other, own)
+    };
+    PprTerms {
+      fulfills: self.fulfills.clone(),
+      fulfilled_by: self.fulfilled_by.clone(),
+      provider: self.provider.clone(),
+      receiver: self.receiver.clone(),
+      claim_types: vec![provider_side.0, receiver_side.0],
+      provider_metrics: provider_side.1,
+      receiver_metrics: receiver_side.1,
+      resource_hash: self.resource_hash.clone(),
+      notes: self.notes.clone(),
+    }
+  }
+
   /// Get verification context for the cryptographic signatures on this claim
   /// The actual verification must be done in the coordinator zome with HDK functions
   pub fn get_signature_verification_contexts(
@@ -320,6 +371,147 @@ impl PrivateParticipationClaim {
   }
 }
 
+/// The terms of a PPR pair. Both parties sign the hash of `ppr_signing_data` over these and
+/// a `requested_at`, so neither claim can be committed with terms the other did not see.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PprTerms {
+  pub fulfills: ActionHash,     // Commitment that was fulfilled
+  pub fulfilled_by: ActionHash, // Economic event that fulfilled it
+  pub provider: AgentPubKey,    // Agent who provided/performed the action
+  pub receiver: AgentPubKey,    // Agent who received/benefited from the action
+  pub claim_types: Vec<ParticipationClaimType>, // [provider claim, receiver claim]
+  pub provider_metrics: PerformanceMetrics, // Performance metrics for provider
+  pub receiver_metrics: PerformanceMetrics, // Performance metrics for receiver
+  pub resource_hash: Option<ActionHash>, // Optional resource involved
+  pub notes: Option<String>,
+}
+
+/// Counterparty signature request for a PPR pair whose counterparty could not be reached
+/// when the receipts were issued. Public so the counterparty can find it in their inbox;
+/// the initiator (action author) has already signed `signed_data_hash`. The metrics and notes
+/// stay off the DHT in the initiator's PprPrivateTerms, shared only with the counterparty.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct PprSignatureRequest {
+  pub fulfills: ActionHash,
+  pub fulfilled_by: ActionHash,
+  pub provider: AgentPubKey,
+  pub receiver: AgentPubKey,
+  pub claim_types: Vec<ParticipationClaimType>, // [provider claim, receiver claim]
+  pub resource_hash: Option<ActionHash>,
+  pub signed_data_hash: [u8; 32],
+  pub initiator_signature: Signature,
+  pub requested_at: Timestamp,
+}
+
+impl PprSignatureRequest {
+  /// The full terms, completed with the metrics and notes the initiator kept private
+  pub fn terms(&self, private: PprPrivateTerms) -> PprTerms {
+    PprTerms {
+      fulfills: self.fulfills.clone(),
+      fulfilled_by: self.fulfilled_by.clone(),
+      provider: self.provider.clone(),
+      receiver: self.receiver.clone(),
+      claim_types: self.claim_types.clone(),
+      provider_metrics: private.provider_metrics,
+      receiver_metrics: private.receiver_metrics,
+      resource_hash: self.resource_hash.clone(),
+      notes: private.notes,
+    }
+  }
+
+  /// The party expected to countersign, i.e. whichever of provider/receiver is not `initiator`
+  pub fn counterparty_of(&self, initiator: &AgentPubKey) -> Option<AgentPubKey> {
+    if *initiator == self.provider {
+      Some(self.receiver.clone())
+    } else if *initiator == self.receiver {
+      Some(self.provider.clone())
+    } else {
+      None
+    }
+  }
+}
+
+/// The part of a PprSignatureRequest's terms that is not published: kept as a private entry
+/// on the initiator's chain until the receipts are finalized
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct PprPrivateTerms {
+  pub signed_data_hash: [u8; 32], // Of the request these terms complete
+  pub provider_metrics: PerformanceMetrics,
+  pub receiver_metrics: PerformanceMetrics,
+  pub notes: Option<String>,
+}
+
+/// Data both parties of a PPR pair sign the hash of. Deterministic in the receipt terms and
+/// `requested_at`, so the counterparty and validators can rebuild it.
+pub fn ppr_signing_data(terms: &PprTerms, requested_at: Timestamp) -> Vec<u8> {
+  // Create a consistent data structure for signing by concatenating serialized components
+  let mut signing_data = Vec::new();
+
+  // Serialize each component individually since complex tuple serialization isn't supported
+  signing_data.extend_from_slice(terms.fulfills.get_raw_39());
+  signing_data.extend_from_slice(terms.fulfilled_by.get_raw_39());
+  signing_data.extend_from_slice(terms.provider.get_raw_39());
+  signing_data.extend_from_slice(terms.receiver.get_raw_39());
+
+  // Serialize claim types as strings
+  for claim_type in &terms.claim_types {
+    signing_data.extend_from_slice(format!("{:?}", claim_type).as_bytes());
+  }
+
+  // Add resource hash if present
+  if let Some(resource_hash) = &terms.resource_hash {
+    signing_data.extend_from_slice(resource_hash.get_raw_39());
+  }
+
+  // Add both parties' metrics and the notes
+  for metrics in [&terms.provider_metrics, &terms.receiver_metrics] {
+    for score in [
+      metrics.timeliness,
+      metrics.quality,
+      metrics.reliability,
+      metrics.communication,
+      metrics.overall_satisfaction,
+    ] {
+      signing_data.extend_from_slice(&score.to_le_bytes());
+    }
+    extend_with_optional_text(&mut signing_data, metrics.notes.as_deref());
+  }
+  extend_with_optional_text(&mut signing_data, terms.notes.as_deref());
+
+  // Add timestamp for uniqueness
+  signing_data.extend_from_slice(&requested_at.as_micros().to_le_bytes());
+
+  signing_data
+}
+
+/// BLAKE3 hash of `ppr_signing_data`, the `signed_data_hash` both parties sign
+pub fn ppr_signed_data_hash(signing_data: &[u8]) -> [u8; 32] {
+  *blake3::hash(signing_data).as_bytes()
+}
+
+/// Presence marker and length prefix, so adjacent text fields cannot be shifted into each other
+fn extend_with_optional_text(data: &mut Vec<u8>, text: Option<&str>) {
+  match text {
+    Some(text) => {
+      data.push(1);
+      data.extend_from_slice(&(text.len() as u64).to_le_bytes());
+      data.extend_from_slice(text.as_bytes());
+    }
+    None => data.push(0),
+  }
+}
+
+/// The counterparty's signature over a PprSignatureRequest's `signed_data_hash`
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct PprSignatureResponse {
+  pub request_hash: ActionHash,
+  pub counterparty_signature: Signature,
+  pub signed_at: Timestamp,
+}
+
 /// Aggregated reputation summary derived from PPRs
 /// Used for privacy-preserving reputation sharing
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
@@ -1,30 +1,8 @@
 use hdi::prelude::*;
-use std::{fmt::Display, str::FromStr};
-
-// TODO (G1 — AgentEntityType): Add an `agent_entity_type` field (or a companion `AgentContext`
-// entry) to distinguish Individual, Collective, Project, Network, Bot, and ExternalOrganisation
-// agents. Currently all agents are implicitly modelled as individual humans. The post-MVP
-// design proposes:
-//
-//   pub enum AgentEntityType {
-//       Individual,
-//       Collective(String),
-//       Project(ActionHash),    // references an organisational NondominiumIdentity hash
-//       Network(ActionHash),    // references a network NondominiumIdentity hash
-//       Bot { capabilities: Vec<String>, operator: AgentPubKey },
-//       ExternalOrganisation(String),
-//   }
-//
-//   pub struct AgentContext {
-//       pub agent_type: AgentEntityType,
-//       pub person_hash: Option<ActionHash>, // None for bots / external orgs
-//       pub created_at: Timestamp,
-//       pub network_seed: String,
-//   }
-//
-// See `documentation/archives/agent.md` §6.1, `REQ-AGENT-01` in requirements.md, and
-// `documentation/requirements/ndo_prima_materia.md` §8.7.
-//
+pub use nondominium_shared::roles::{
+  capability_rank, default_role, DefaultRole, CAPABILITY_LEVELS, DEFAULT_ROLES,
+};
+
 // TODO (G15 — CapabilitySlot on Agent): The `Person` entry hash should serve as a stigmergic
 // attachment surface for external capabilities — analogous to the NondominiumIdentity
 // CapabilitySlot surface in `documentation/requirements/ndo_prima_materia.md` §6. Add a
@@ -53,6 +31,60 @@ pub struct Person {
   pub hrea_agent_hash: Option<ActionHash>,
 }
 
+/// What kind of agent a Person stands for (REQ-AGENT-01)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum AgentEntityType {
+  /// An individual human
+  #[default]
+  Individual,
+  /// A group of people acting together, by name
+  Collective(String),
+  /// A project, by the hash of its organisational NondominiumIdentity
+  Project(ActionHash),
+  /// A network, by the hash of its NondominiumIdentity
+  Network(ActionHash),
+  /// Software acting on behalf of an accountable operator
+  Bot {
+    /// What the bot does (e.g., "inventory_scan")
+    capabilities: Vec<String>,
+    /// Agent answerable for the bot
+    operator: AgentPubKey,
+    /// The operator's own PersonRole at the Accountable Agent level or above
+    #[serde(default)]
+    operator_role: Option<ActionHash>,
+    /// The operator's BotOperatorConsent to answer for this bot
+    #[serde(default)]
+    operator_consent: Option<ActionHash>,
+  },
+  /// An organisation outside the network, by name
+  ExternalOrganisation(String),
+}
+
+/// The kind of agent behind an agent key, linked from it when its Person is created. Agents
+/// without one are individuals. An agent's context is fixed: it cannot be updated or deleted.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct AgentContext {
+  pub agent_type: AgentEntityType,
+  /// The Person the agent acts as, created by the same agent
+  pub person_hash: Option<ActionHash>,
+  pub created_at: Timestamp,
+  /// Network seed of the network the context was created in
+  pub network_seed: String,
+}
+
+/// An operator's agreement to answer for a bot, which the bot's AgentContext must cite. It is
+/// linked from the bot's agent key (BotToOperatorConsents) and cannot be updated or deleted.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct BotOperatorConsent {
+  /// Agent key of the bot
+  pub bot: AgentPubKey,
+  /// Agent answerable for the bot; the author of the consent
+  pub operator: AgentPubKey,
+  pub consented_at: Timestamp,
+}
+
 /// Private data for a person, only accessible by the owner
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
@@ -87,72 +119,143 @@ pub struct PersonRole {
   pub assigned_by: AgentPubKey,
   /// Timestamp when the role was assigned
   pub assigned_at: Timestamp,
+  /// Ratified RoleDefinition version of a community-defined role; None for default roles.
+  /// `#[serde(default)]` keeps entries serialized before the role registry readable.
+  #[serde(default)]
+  pub role_definition: Option<ActionHash>,
+  /// The assigner's own PersonRole that lets it assign this role; None for roles any member
+  /// may assign, and for assignments by the progenitor
+  #[serde(default)]
+  pub assigner_role: Option<ActionHash>,
+  /// End of the term of a time-boxed role; None for roles held until revoked
+  #[serde(default)]
+  pub expires_at: Option<Timestamp>,
 }
 
-// TODO (G14 — Configurable Role Taxonomy): This enum is closed — communities cannot define
-// their own role types. The OVN model requires roles to emerge from community needs, not
-// from infrastructure decisions. Post-MVP, this should become a configurable role registry:
-//
-//   // In integrity: remove `RoleType` enum entirely, or keep as default-role constants.
-//   // Add a `RoleDefinition` entry type:
-//   pub struct RoleDefinition {
-//       pub role_name: String,
-//       pub capability_level: String, // "member" | "stewardship" | "coordination" | "governance"
-//       pub description: Option<String>,
-//       pub validation_requirements: Option<String>,
-//       pub network_id: String,       // Which network defined this role
-//       pub created_at: Timestamp,
-//   }
-//
-//   // In coordinator: `assign_person_role` accepts any role name present in the network's
-//   // RoleDefinition registry. The six predefined roles below become genesis entries.
-//   // The validate_person_role function must stop hard-rejecting unknown role names and
-//   // instead look up the RoleDefinition registry.
-//
-// See `documentation/archives/agent.md` §5.3 (G14), `REQ-AGENT-06` in requirements.md, and
-// `documentation/zomes/person_zome.md` Future Enhancements.
-
-/// Allowed role types in the system
-// NOTE: These are the MVP defaults. Post-MVP they become configurable registry entries.
-#[derive(Debug, Clone, PartialEq)]
-pub enum RoleType {
-  SimpleAgent,             // Simple Agent capabilities
-  AccountableAgent,        // Accountable Agent level
-  PrimaryAccountableAgent, // Primary Accountable Agent level
-  Transport,               // Transport process access
-  Repair,                  // Repair process access
-  Storage,                 // Storage process access
-}
-
-impl Display for RoleType {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    match self {
-      Self::SimpleAgent => write!(f, "Simple Agent"),
-      Self::AccountableAgent => write!(f, "Accountable Agent"),
-      Self::PrimaryAccountableAgent => write!(f, "Primary Accountable Agent"),
-      Self::Transport => write!(f, "Transport Agent"),
-      Self::Repair => write!(f, "Repair Agent"),
-      Self::Storage => write!(f, "Storage Agent"),
-    }
+/// What a RoleRevocation does to a PersonRole
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RoleRevocationKind {
+  Revoke,    // Ends the role for good
+  Suspend,   // Pauses the role until it is reinstated
+  Reinstate, // Lifts a suspension
+}
+
+/// A revocation, suspension or reinstatement of a PersonRole. A role's revocations, in time
+/// order, give its status; they cannot be deleted, so they also keep its history.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct RoleRevocation {
+  /// Original action hash of the PersonRole
+  pub person_role: ActionHash,
+  pub kind: RoleRevocationKind,
+  /// Why the role is revoked, suspended or reinstated
+  pub reason: String,
+  /// Agent who revoked, suspended or reinstated the role
+  pub revoked_by: AgentPubKey,
+  /// The revoker's own PersonRole at the `assignable_by` level of the role, as for assignments
+  pub revoker_role: Option<ActionHash>,
+  pub revoked_at: Timestamp,
+}
+
+/// DNA properties of a nondominium network
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DnaProperties {
+  /// Root of role authority: the only agent who may assign roles without holding one.
+  /// Networks without a progenitor fail closed: agents may only take member level roles
+  /// themselves, and only step down from their own roles.
+  #[serde(default)]
+  pub progenitor: Option<AgentPubKey>,
+}
+
+impl DnaProperties {
+  pub fn get() -> ExternResult<Self> {
+    let properties = dna_info()?.modifiers.properties;
+    let properties: Option<DnaProperties> =
+      decode(properties.bytes()).map_err(|e| wasm_error!(e))?;
+    Ok(properties.unwrap_or_default())
   }
 }
 
-impl FromStr for RoleType {
-  type Err = ();
+/// Status of a RoleDefinition in the role registry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RoleDefinitionStatus {
+  Proposed, // Awaiting ratification; cannot be assigned yet
+  Ratified, // Part of the registry
+}
 
-  fn from_str(s: &str) -> Result<Self, Self::Err> {
-    match s {
-      "Simple Agent" => Ok(Self::SimpleAgent),
-      "Accountable Agent" => Ok(Self::AccountableAgent),
-      "Primary Accountable Agent" => Ok(Self::PrimaryAccountableAgent),
-      "Transport Agent" => Ok(Self::Transport),
-      "Repair Agent" => Ok(Self::Repair),
-      "Storage Agent" => Ok(Self::Storage),
-      _ => Err(()),
+/// A role communities can assign to agents (REQ-AGENT-06). The default roles are seeded
+/// at init from `DEFAULT_ROLES` and cannot be redefined; others are proposed by any agent
+/// and become assignable once a governance-level agent ratifies them.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct RoleDefinition {
+  /// Name roles are assigned under (e.g., "Laser Operator"); unique in the registry
+  pub role_name: String,
+  /// Capability level the role grants: "member", "stewardship", "coordination" or "governance"
+  pub capability_level: String,
+  /// Optional description of the role's responsibilities
+  pub description: Option<String>,
+  /// What an agent must go through to be assigned the role, if anything
+  pub validation_requirements: Option<String>,
+  /// Lowest capability level of the agents who may assign the role
+  pub assignable_by: String,
+  /// Whether the role has been ratified
+  pub status: RoleDefinitionStatus,
+  /// The ratifying agent's own governance level PersonRole; set by the ratification
+  #[serde(default)]
+  pub ratifier_role: Option<ActionHash>,
+}
+
+impl From<&DefaultRole> for RoleDefinition {
+  fn from(role: &DefaultRole) -> Self {
+    RoleDefinition {
+      role_name: role.role_name.to_string(),
+      capability_level: role.capability_level.to_string(),
+      description: Some(role.description.to_string()),
+      validation_requirements: role.validation_requirements.map(str::to_string),
+      assignable_by: role.assignable_by.to_string(),
+      status: RoleDefinitionStatus::Ratified,
+      ratifier_role: None,
     }
   }
 }
 
+/// Status of a RolePromotionRequest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PromotionRequestStatus {
+  Pending,   // Awaiting review
+  Approved,  // The role was assigned
+  Rejected,  // Declined by a reviewer
+  Withdrawn, // Cancelled by the requesting agent
+}
+
+/// An agent's request to be promoted to a role, reviewed by agents who may assign that
+/// role (REQ-AGENT-16)
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct RolePromotionRequest {
+  /// Agent asking for the role
+  pub requesting_agent: AgentPubKey,
+  /// Name of the requested role
+  pub target_role: String,
+  /// Why the agent should hold the role
+  pub justification: String,
+  /// When the request was made
+  pub created_at: Timestamp,
+  /// Where the request stands
+  pub status: PromotionRequestStatus,
+  /// Agent who approved or rejected the request
+  pub reviewed_by: Option<AgentPubKey>,
+  /// Notes of the approval, or reason of the rejection
+  pub review_notes: Option<String>,
+  /// Reviewer's PersonRole at the `assignable_by` level of the requested role
+  #[serde(default)]
+  pub reviewer_role: Option<ActionHash>,
+  /// Ratified RoleDefinition of the requested role, when it is not a default role
+  #[serde(default)]
+  pub role_definition: Option<ActionHash>,
+}
+
 /// Metadata for private data capability grants (for tracking our own grants)
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
@@ -266,6 +369,11 @@ pub enum EntryTypes {
   #[entry_type(visibility = "private")]
   PrivatePersonData(PrivatePersonData),
   PersonRole(PersonRole),
+  RoleDefinition(RoleDefinition),
+  RolePromotionRequest(RolePromotionRequest),
+  RoleRevocation(RoleRevocation),
+  AgentContext(AgentContext),
+  BotOperatorConsent(BotOperatorConsent),
   #[entry_type(visibility = "private")]
   PrivateDataCapabilityMetadata(PrivateDataCapabilityMetadata),
   #[entry_type(visibility = "private")]
@@ -285,16 +393,26 @@ pub enum LinkTypes {
   PersonUpdates, // Person -> Person (versioning)
 
   // Agent-Person relationships (supports multi-device)
-  AgentToPerson,  // Agent -> Person (primary relationship)
-  PersonToAgents, // Person -> Agent (reverse lookup for multi-device)
+  AgentToPerson,         // Agent -> Person (primary relationship)
+  PersonToAgents,        // Person -> Agent (reverse lookup for multi-device)
+  AgentToContext,        // Agent -> AgentContext
+  BotToOperatorConsents, // Bot agent -> BotOperatorConsent
 
   // Person data relationships
   PersonToPrivateData, // Person -> PrivateData (person-centric access)
   PersonToRoles,       // Person -> Role (person-centric roles)
 
   // Versioning and updates
-  RoleUpdates,   // Role -> Role (versioning)
-  DeviceUpdates, // Device -> Device (versioning)
+  RoleUpdates,     // Role -> Role (versioning)
+  RoleRevocations, // Role (original) -> RoleRevocation
+  DeviceUpdates,   // Device -> Device (versioning)
+
+  // Role registry
+  AllRoleDefinitions, // "role_definitions" anchor -> RoleDefinition, tagged with the role name
+
+  // Role promotion requests
+  AllPendingPromotions, // "pending_promotions" anchor -> RolePromotionRequest (while pending)
+  AgentToPromotionRequests, // Requesting agent -> RolePromotionRequest
 
   // Capability-based access management
   AgentToCapabilityMetadata, // Agent -> CapabilityMetadata (tracking grants)
@@ -323,34 +441,89 @@ pub fn validate_agent_joining(
 pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
   if let FlatOp::StoreEntry(store_entry) = op.flattened::<EntryTypes, LinkTypes>()? {
     match store_entry {
-      OpEntry::CreateEntry { app_entry, .. } | OpEntry::UpdateEntry { app_entry, .. } => {
-        match app_entry {
-          EntryTypes::Person(person) => {
-            return validate_person(person);
-          }
-          EntryTypes::PrivatePersonData(private_data) => {
-            return validate_private_person_data(private_data);
-          }
-          EntryTypes::PersonRole(role) => {
-            return validate_person_role(role);
-          }
-          EntryTypes::PrivateDataCapabilityMetadata(metadata) => {
-            return validate_private_data_capability_metadata(metadata);
-          }
-          EntryTypes::FilteredPrivateData(filtered_data) => {
-            return validate_filtered_private_data(filtered_data);
-          }
-          EntryTypes::RevokedGrantMarker(_revoked_marker) => {
-            return validate_revoked_grant_marker();
-          }
-          EntryTypes::Device(device) => {
-            return validate_device(device);
-          }
-          EntryTypes::AgentPersonRelationship(relationship) => {
-            return validate_agent_person_relationship(relationship);
-          }
-        }
+      OpEntry::UpdateEntry {
+        app_entry: EntryTypes::AgentContext(_),
+        ..
+      } => {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "An agent context cannot be updated",
+        )));
+      }
+      OpEntry::UpdateEntry {
+        app_entry: EntryTypes::BotOperatorConsent(_),
+        ..
+      } => {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "A bot operator consent cannot be updated",
+        )));
+      }
+      OpEntry::UpdateEntry {
+        app_entry: EntryTypes::RoleDefinition(definition),
+        action,
+        ..
+      } => {
+        return validate_update_role_definition(definition, action);
       }
+      OpEntry::UpdateEntry {
+        app_entry: EntryTypes::RolePromotionRequest(request),
+        action,
+        ..
+      } => {
+        return validate_update_role_promotion_request(request, action);
+      }
+      OpEntry::CreateEntry {
+        app_entry,
+        action: Create {
+          author, timestamp, ..
+        },
+      }
+      | OpEntry::UpdateEntry {
+        app_entry,
+        action: Update {
+          author, timestamp, ..
+        },
+        ..
+      } => match app_entry {
+        EntryTypes::Person(person) => {
+          return validate_person(person);
+        }
+        EntryTypes::PrivatePersonData(private_data) => {
+          return validate_private_person_data(private_data);
+        }
+        EntryTypes::PersonRole(role) => {
+          return validate_person_role(role, &author, &timestamp);
+        }
+        EntryTypes::RoleDefinition(definition) => {
+          return validate_role_definition(definition);
+        }
+        EntryTypes::RolePromotionRequest(request) => {
+          return validate_role_promotion_request(request, &author);
+        }
+        EntryTypes::RoleRevocation(revocation) => {
+          return validate_role_revocation(revocation, &author, &timestamp);
+        }
+        EntryTypes::AgentContext(context) => {
+          return validate_agent_context(context, &author, &timestamp);
+        }
+        EntryTypes::BotOperatorConsent(consent) => {
+          return validate_bot_operator_consent(consent, &author);
+        }
+        EntryTypes::PrivateDataCapabilityMetadata(metadata) => {
+          return validate_private_data_capability_metadata(metadata);
+        }
+        EntryTypes::FilteredPrivateData(filtered_data) => {
+          return validate_filtered_private_data(filtered_data);
+        }
+        EntryTypes::RevokedGrantMarker(_revoked_marker) => {
+          return validate_revoked_grant_marker();
+        }
+        EntryTypes::Device(device) => {
+          return validate_device(device);
+        }
+        EntryTypes::AgentPersonRelationship(relationship) => {
+          return validate_agent_person_relationship(relationship);
+        }
+      },
       _ => (),
     }
   }
@@ -358,6 +531,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
     match store_record {
       OpRecord::DeleteEntry {
         original_action_hash,
+        action,
         ..
       } => {
         let original_record = must_get_valid_record(original_action_hash)?;
@@ -408,8 +582,23 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
           EntryTypes::PrivatePersonData(_) => {
             return validate_delete_private_person_data();
           }
-          EntryTypes::PersonRole(_) => {
-            return validate_delete_person_role();
+          EntryTypes::PersonRole(role) => {
+            return validate_delete_person_role(role, &action.author);
+          }
+          EntryTypes::RoleDefinition(_) => {
+            return validate_delete_role_definition();
+          }
+          EntryTypes::RolePromotionRequest(_) => {
+            return validate_delete_role_promotion_request();
+          }
+          EntryTypes::RoleRevocation(_) => {
+            return validate_delete_role_revocation();
+          }
+          EntryTypes::AgentContext(_) => {
+            return validate_delete_agent_context();
+          }
+          EntryTypes::BotOperatorConsent(_) => {
+            return validate_delete_bot_operator_consent();
           }
           EntryTypes::PrivateDataCapabilityMetadata(_) => {
             return validate_delete_private_data_capability_metadata();
@@ -431,7 +620,42 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
       _ => (),
     }
   }
-  Ok(ValidateCallbackResult::Valid)
+  match op.flattened::<EntryTypes, LinkTypes>()? {
+    FlatOp::RegisterCreateLink {
+      link_type: LinkTypes::AgentToContext,
+      base_address,
+      target_address,
+      action,
+      ..
+    } => validate_create_agent_context_link(base_address, target_address, &action.author),
+    FlatOp::RegisterCreateLink {
+      link_type: LinkTypes::BotToOperatorConsents,
+      base_address,
+      target_address,
+      action,
+      ..
+    } => validate_create_bot_operator_consent_link(base_address, target_address, &action.author),
+    FlatOp::RegisterCreateLink {
+      link_type: LinkTypes::RoleRevocations,
+      base_address,
+      target_address,
+      action,
+      ..
+    } => validate_create_role_revocation_link(base_address, target_address, &action.author),
+    FlatOp::RegisterDeleteLink {
+      link_type: LinkTypes::RoleRevocations,
+      ..
+    } => Ok(ValidateCallbackResult::Invalid(String::from(
+      "Links to role revocations cannot be deleted",
+    ))),
+    FlatOp::RegisterDeleteLink {
+      link_type: LinkTypes::AgentToContext | LinkTypes::BotToOperatorConsents,
+      ..
+    } => Ok(ValidateCallbackResult::Invalid(String::from(
+      "Agent context and bot operator consent links cannot be deleted",
+    ))),
+    _ => Ok(ValidateCallbackResult::Valid),
+  }
 }
 
 pub fn validate_person(person: Person) -> ExternResult<ValidateCallbackResult> {
@@ -462,6 +686,227 @@ pub fn validate_person(person: Person) -> ExternResult<ValidateCallbackResult> {
   Ok(ValidateCallbackResult::Valid)
 }
 
+pub fn validate_agent_context(
+  context: AgentContext,
+  author: &AgentPubKey,
+  timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+  if context.network_seed != dna_info()?.modifiers.network_seed {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An agent context must be created for this network",
+    )));
+  }
+
+  if let Some(person_hash) = context.person_hash {
+    let record = must_get_valid_record(person_hash)?;
+    let person = record
+      .entry()
+      .to_app_option::<Person>()
+      .map_err(|e| wasm_error!(e))?;
+    if person.is_none() || record.action().author() != author {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "An agent context must refer to a Person created by the same agent",
+      )));
+    }
+  }
+
+  match context.agent_type {
+    AgentEntityType::Collective(name) | AgentEntityType::ExternalOrganisation(name)
+      if name.trim().is_empty() =>
+    {
+      Ok(ValidateCallbackResult::Invalid(String::from(
+        "Collectives and external organisations need a name",
+      )))
+    }
+    AgentEntityType::Bot {
+      operator,
+      operator_role,
+      operator_consent,
+      ..
+    } => validate_bot_operator(
+      &operator,
+      operator_role,
+      operator_consent,
+      author,
+      timestamp,
+    ),
+    _ => Ok(ValidateCallbackResult::Valid),
+  }
+}
+
+// A bot is operated by another agent, who consented to it and holds a role at the Accountable
+// Agent level or above
+fn validate_bot_operator(
+  operator: &AgentPubKey,
+  operator_role: Option<ActionHash>,
+  operator_consent: Option<ActionHash>,
+  author: &AgentPubKey,
+  timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+  if operator == author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A bot cannot operate itself",
+    )));
+  }
+
+  let consent = match operator_consent {
+    Some(consent_hash) => must_get_valid_record(consent_hash)?
+      .entry()
+      .to_app_option::<BotOperatorConsent>()
+      .map_err(|e| wasm_error!(e))?,
+    None => None,
+  };
+  if !consent.is_some_and(|consent| consent.bot == *author && consent.operator == *operator) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A bot must cite its operator's consent to operate it",
+    )));
+  }
+
+  let Some(operator_role_hash) = operator_role else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A bot's operator must be an Accountable Agent",
+    )));
+  };
+  let operator_role = must_get_valid_record(operator_role_hash)?
+    .entry()
+    .to_app_option::<PersonRole>()
+    .map_err(|e| wasm_error!(e))?;
+  let Some(operator_role) = operator_role.filter(|role| {
+    role.assigned_to == *operator
+      && role
+        .expires_at
+        .is_none_or(|expires_at| expires_at > *timestamp)
+  }) else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The operator role must be a current role of the bot's operator",
+    )));
+  };
+
+  let required_rank = default_role("Accountable Agent")
+    .and_then(|accountable| capability_rank(accountable.capability_level));
+  let operator_rank = person_role_definition(&operator_role)?
+    .and_then(|definition| capability_rank(&definition.capability_level));
+  if operator_rank.is_none() || operator_rank < required_rank {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "The role '{}' does not make the operator an Accountable Agent",
+      operator_role.role_name
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_agent_context() -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "Agent contexts cannot be deleted",
+  )))
+}
+
+// Agents may only link their own agent key to a context of their own
+fn validate_create_agent_context_link(
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  if base_address != AnyLinkableHash::from(author.clone()) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Agents can only link an agent context from their own agent key",
+    )));
+  }
+  let Some(context_hash) = target_address.into_action_hash() else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An agent context link must target an AgentContext",
+    )));
+  };
+  let record = must_get_valid_record(context_hash)?;
+  let context = record
+    .entry()
+    .to_app_option::<AgentContext>()
+    .map_err(|e| wasm_error!(e))?;
+  if context.is_none() || record.action().author() != author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An agent context link must target an AgentContext created by the same agent",
+    )));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_bot_operator_consent(
+  consent: BotOperatorConsent,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  if consent.operator != *author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the operator can consent to operate a bot",
+    )));
+  }
+  if consent.bot == consent.operator {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A bot cannot operate itself",
+    )));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// Operators link their consents from the bot's agent key
+fn validate_create_bot_operator_consent_link(
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  let Some(consent_hash) = target_address.into_action_hash() else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A bot operator consent link must target a BotOperatorConsent",
+    )));
+  };
+  let consent = must_get_valid_record(consent_hash)?
+    .entry()
+    .to_app_option::<BotOperatorConsent>()
+    .map_err(|e| wasm_error!(e))?;
+  let linked_from_bot = consent.is_some_and(|consent| {
+    consent.operator == *author && base_address == AnyLinkableHash::from(consent.bot)
+  });
+  if !linked_from_bot {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Operators can only link their own consents from the bot's agent key",
+    )));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// Revokers link their own revocations from the role they revoke
+fn validate_create_role_revocation_link(
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  let Some(revocation_hash) = target_address.into_action_hash() else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A role revocation link must target a RoleRevocation",
+    )));
+  };
+  let revocation = must_get_valid_record(revocation_hash)?
+    .entry()
+    .to_app_option::<RoleRevocation>()
+    .map_err(|e| wasm_error!(e))?;
+  let linked_from_role = revocation.is_some_and(|revocation| {
+    revocation.revoked_by == *author
+      && base_address == AnyLinkableHash::from(revocation.person_role)
+  });
+  if !linked_from_role {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Revokers can only link their own revocations from the revoked role",
+    )));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_bot_operator_consent() -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "Bot operator consents cannot be deleted",
+  )))
+}
+
 pub fn validate_private_person_data(
   private_data: PrivatePersonData,
 ) -> ExternResult<ValidateCallbackResult> {
@@ -487,7 +932,11 @@ pub fn validate_private_person_data(
   Ok(ValidateCallbackResult::Valid)
 }
 
-pub fn validate_person_role(role: PersonRole) -> ExternResult<ValidateCallbackResult> {
+pub fn validate_person_role(
+  role: PersonRole,
+  author: &AgentPubKey,
+  timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
   if role.role_name.trim().is_empty() {
     return Ok(ValidateCallbackResult::Invalid(String::from(
       "Role name cannot be empty",
@@ -500,17 +949,324 @@ pub fn validate_person_role(role: PersonRole) -> ExternResult<ValidateCallbackRe
     )));
   }
 
-  // Validate that the role type is allowed
-  if RoleType::from_str(&role.role_name).is_err() {
+  let Some(definition) = person_role_definition(&role)? else {
+    return Ok(ValidateCallbackResult::Invalid(
+      match role.role_definition {
+        None => format!("Role '{}' is not in the role registry", role.role_name),
+        Some(_) => format!(
+          "Role '{}' must refer to its ratified RoleDefinition",
+          role.role_name
+        ),
+      },
+    ));
+  };
+
+  if role
+    .expires_at
+    .is_some_and(|expires_at| expires_at <= role.assigned_at)
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A role must expire after it is assigned",
+    )));
+  }
+
+  validate_role_assignment(&role, &definition, author, timestamp)
+}
+
+/// Definition of the role a PersonRole grants: the default role of that name, or the ratified
+/// RoleDefinition it refers to. None when the role is not in the registry.
+pub fn person_role_definition(role: &PersonRole) -> ExternResult<Option<RoleDefinition>> {
+  role_definition(&role.role_name, role.role_definition.clone())
+}
+
+/// Definition of the role named `role_name`: the default role of that name, or the cited
+/// RoleDefinition if it is ratified under that name. None when the role is not in the registry.
+pub fn role_definition(
+  role_name: &str,
+  cited_definition: Option<ActionHash>,
+) -> ExternResult<Option<RoleDefinition>> {
+  if let Some(default) = default_role(role_name) {
+    return Ok(Some(RoleDefinition::from(default)));
+  }
+  let Some(definition_hash) = cited_definition else {
+    return Ok(None);
+  };
+  let definition = must_get_valid_record(definition_hash)?
+    .entry()
+    .to_app_option::<RoleDefinition>()
+    .map_err(|e| wasm_error!(e))?;
+  Ok(definition.filter(|definition| {
+    definition.role_name == role_name && definition.status == RoleDefinitionStatus::Ratified
+  }))
+}
+
+// Who may assign a role. The progenitor may assign any role; anyone else needs a role of their
+// own at the `assignable_by` level of the assigned role, named by `assigner_role`, unless any
+// member may assign it. Agents cannot raise their own capability above member level. Each
+// assigner role was itself validated this way, so authority always traces back to the
+// progenitor. Without a progenitor there is no such root, so only member level self-assignment
+// is allowed.
+fn validate_role_assignment(
+  role: &PersonRole,
+  definition: &RoleDefinition,
+  author: &AgentPubKey,
+  timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+  if role.assigned_by != *author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A role must be assigned by the author of the PersonRole",
+    )));
+  }
+
+  let member_level = capability_rank(&definition.capability_level) == Some(0);
+  match DnaProperties::get()?.progenitor {
+    Some(progenitor) if progenitor == *author => return Ok(ValidateCallbackResult::Valid),
+    Some(_) => (),
+    None if role.assigned_to == *author && member_level => {
+      return Ok(ValidateCallbackResult::Valid)
+    }
+    None => {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "Without a progenitor, agents may only assign themselves member level roles",
+      )))
+    }
+  }
+
+  if role.assigned_to == *author && !member_level {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Agents cannot assign themselves the {} level role '{}'",
+      definition.capability_level, role.role_name
+    )));
+  }
+
+  validate_assigner_authority(
+    &role.role_name,
+    definition,
+    author,
+    role.assigner_role.clone(),
+    timestamp,
+  )
+}
+
+// Whether `assigner_role` lets `author` assign or revoke a role of this definition at `timestamp`.
+// Integrity sees the assigner role's expiry but not its revocations or suspensions: those are
+// links, which validation cannot read deterministically. A revoked or suspended assigner role
+// is refused by the coordinator (`find_assigner_role` only cites Active roles), not here, so
+// communities wanting a hard bound on delegated authority should assign it with `expires_at`.
+fn validate_assigner_authority(
+  role_name: &str,
+  definition: &RoleDefinition,
+  author: &AgentPubKey,
+  assigner_role: Option<ActionHash>,
+  timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+  if capability_rank(&definition.assignable_by) == Some(0) {
+    return Ok(ValidateCallbackResult::Valid);
+  }
+  validate_held_role_level(
+    &format!("Assigning '{}'", role_name),
+    &definition.assignable_by,
+    author,
+    assigner_role,
+    timestamp,
+  )
+}
+
+// Whether `held_role` is a role of `author`, unexpired at `timestamp`, granting `required_level`
+// or above, as `action` requires
+fn validate_held_role_level(
+  action: &str,
+  required_level: &str,
+  author: &AgentPubKey,
+  held_role: Option<ActionHash>,
+  timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+  let Some(held_role_hash) = held_role else {
     return Ok(ValidateCallbackResult::Invalid(format!(
-      "Invalid role type: {}. Must be one of the predefined role types.",
+      "{} requires a {} level role",
+      action, required_level
+    )));
+  };
+  let held_role = must_get_valid_record(held_role_hash)?
+    .entry()
+    .to_app_option::<PersonRole>()
+    .map_err(|e| wasm_error!(e))?;
+  let Some(held_role) = held_role.filter(|held_role| held_role.assigned_to == *author) else {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "{} requires a role held by the acting agent",
+      action
+    )));
+  };
+  if held_role
+    .expires_at
+    .is_some_and(|expires_at| expires_at <= *timestamp)
+  {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "The role '{}' has expired",
+      held_role.role_name
+    )));
+  }
+  let held_rank = person_role_definition(&held_role)?
+    .and_then(|held_definition| capability_rank(&held_definition.capability_level));
+  if held_rank.is_none() || held_rank < capability_rank(required_level) {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "{} requires a {} level role, '{}' is not",
+      action, required_level, held_role.role_name
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_role_revocation(
+  revocation: RoleRevocation,
+  author: &AgentPubKey,
+  timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+  if revocation.reason.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A role revocation needs a reason",
+    )));
+  }
+
+  if revocation.revoked_by != *author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A role must be revoked by the author of the RoleRevocation",
+    )));
+  }
+
+  let role = must_get_valid_record(revocation.person_role.clone())?
+    .entry()
+    .to_app_option::<PersonRole>()
+    .map_err(|e| wasm_error!(e))?;
+  let Some(role) = role else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A role revocation must refer to a PersonRole",
+    )));
+  };
+
+  // Agents may step down from their own roles, but not lift their own suspension
+  if role.assigned_to == *author {
+    return Ok(match revocation.kind {
+      RoleRevocationKind::Revoke => ValidateCallbackResult::Valid,
+      _ => ValidateCallbackResult::Invalid(String::from(
+        "Agents cannot suspend or reinstate their own roles",
+      )),
+    });
+  }
+
+  match DnaProperties::get()?.progenitor {
+    Some(progenitor) if progenitor == *author => return Ok(ValidateCallbackResult::Valid),
+    Some(_) => (),
+    None => {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "Without a progenitor, only holders may revoke their roles",
+      )))
+    }
+  }
+
+  let Some(definition) = person_role_definition(&role)? else {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Role '{}' is not in the role registry",
       role.role_name
     )));
+  };
+  validate_assigner_authority(
+    &role.role_name,
+    &definition,
+    author,
+    revocation.revoker_role,
+    timestamp,
+  )
+}
+
+pub fn validate_delete_role_revocation() -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "Role revocations cannot be deleted",
+  )))
+}
+
+pub fn validate_role_definition(
+  definition: RoleDefinition,
+) -> ExternResult<ValidateCallbackResult> {
+  if definition.role_name.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Role name cannot be empty",
+    )));
+  }
+
+  if definition.role_name.len() > 50 {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Role name too long (max 50 characters)",
+    )));
+  }
+
+  for level in [&definition.capability_level, &definition.assignable_by] {
+    if capability_rank(level).is_none() {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Invalid capability level: {}. Must be one of: {:?}",
+        level, CAPABILITY_LEVELS
+      )));
+    }
+  }
+
+  // Default role names can only ever mean the default role; other roles start as proposals
+  if let Some(default) = default_role(&definition.role_name) {
+    if definition != RoleDefinition::from(default) {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Role '{}' is a default role and cannot be redefined",
+        definition.role_name
+      )));
+    }
+  } else if definition.status != RoleDefinitionStatus::Proposed
+    || definition.ratifier_role.is_some()
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Community roles must be proposed before they are ratified",
+    )));
   }
 
   Ok(ValidateCallbackResult::Valid)
 }
 
+// The only update of a role definition is its ratification: Proposed to Ratified, by an agent
+// citing a governance level role of their own, with the proposal otherwise unchanged
+pub fn validate_update_role_definition(
+  definition: RoleDefinition,
+  action: Update,
+) -> ExternResult<ValidateCallbackResult> {
+  let original = must_get_valid_record(action.original_action_address)?
+    .entry()
+    .to_app_option::<RoleDefinition>()
+    .map_err(|e| wasm_error!(e))?;
+  let Some(original) =
+    original.filter(|original| original.status == RoleDefinitionStatus::Proposed)
+  else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only proposed role definitions can be updated",
+    )));
+  };
+
+  let ratified = RoleDefinition {
+    status: RoleDefinitionStatus::Ratified,
+    ratifier_role: definition.ratifier_role.clone(),
+    ..original
+  };
+  if definition != ratified {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A role definition update can only ratify the proposal",
+    )));
+  }
+
+  validate_held_role_level(
+    "Ratifying roles",
+    "governance",
+    &action.author,
+    definition.ratifier_role,
+    &action.timestamp,
+  )
+}
+
 pub fn validate_delete_person() -> ExternResult<ValidateCallbackResult> {
   Ok(ValidateCallbackResult::Invalid(String::from(
     "Person profiles cannot be deleted",
@@ -521,8 +1277,175 @@ pub fn validate_delete_private_person_data() -> ExternResult<ValidateCallbackRes
   Ok(ValidateCallbackResult::Valid) // Allow deletion of private data
 }
 
-pub fn validate_delete_person_role() -> ExternResult<ValidateCallbackResult> {
-  Ok(ValidateCallbackResult::Valid) // Allow role deletion for role transfers
+// Holders may delete their own roles, and the progenitor any role; other agents end roles
+// through RoleRevocations, which keep the role's history
+pub fn validate_delete_person_role(
+  role: PersonRole,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  if role.assigned_to == *author || DnaProperties::get()?.progenitor.as_ref() == Some(author) {
+    return Ok(ValidateCallbackResult::Valid);
+  }
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "Only the holder or the progenitor can delete a role; others revoke it",
+  )))
+}
+
+pub fn validate_delete_role_definition() -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "Role definitions cannot be deleted",
+  )))
+}
+
+pub fn validate_role_promotion_request(
+  request: RolePromotionRequest,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  if request.target_role.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Target role cannot be empty",
+    )));
+  }
+
+  if request.justification.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Promotion justification cannot be empty",
+    )));
+  }
+
+  if request.requesting_agent != *author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Agents can only request promotions for themselves",
+    )));
+  }
+
+  if request.status != PromotionRequestStatus::Pending
+    || request.reviewed_by.is_some()
+    || request.review_notes.is_some()
+    || request.reviewer_role.is_some()
+    || request.role_definition.is_some()
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Promotion requests start pending and unreviewed",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// A pending request is closed once, by an update of its create: withdrawn by its requester,
+// or approved or rejected by another agent naming themselves as reviewer and citing their own
+// role at the requested role's `assignable_by` level. Nothing else about the request changes.
+// Integrity sees the author's own earlier closes of the request, not other agents': a reviewer
+// and the requester closing it concurrently is refused by the coordinator, not here.
+pub fn validate_update_role_promotion_request(
+  request: RolePromotionRequest,
+  action: Update,
+) -> ExternResult<ValidateCallbackResult> {
+  let original_record = must_get_valid_record(action.original_action_address.clone())?;
+  let Action::Create(create) = original_record.action() else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A promotion request update must update the request's create",
+    )));
+  };
+  let original = original_record
+    .entry()
+    .to_app_option::<RolePromotionRequest>()
+    .map_err(|e| wasm_error!(e))?;
+  let Some(original) =
+    original.filter(|original| original.status == PromotionRequestStatus::Pending)
+  else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only pending promotion requests can be updated",
+    )));
+  };
+
+  let earlier_updates = must_get_agent_activity(
+    action.author.clone(),
+    ChainFilter::new(action.prev_action.clone()).until_timestamp(create.timestamp),
+  )?;
+  let already_closed = earlier_updates.iter().any(|item| {
+    matches!(item.action.action(), Action::Update(update)
+      if update.original_action_address == action.original_action_address)
+  });
+  if already_closed {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A promotion request is closed only once",
+    )));
+  }
+
+  let closed = RolePromotionRequest {
+    status: request.status.clone(),
+    reviewed_by: request.reviewed_by.clone(),
+    review_notes: request.review_notes.clone(),
+    reviewer_role: request.reviewer_role.clone(),
+    role_definition: request.role_definition.clone(),
+    ..original
+  };
+  if request != closed {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Closing a promotion request cannot change the request",
+    )));
+  }
+
+  match request.status {
+    PromotionRequestStatus::Withdrawn
+      if action.author != request.requesting_agent
+        || request.reviewed_by.is_some()
+        || request.review_notes.is_some()
+        || request.reviewer_role.is_some()
+        || request.role_definition.is_some() =>
+    {
+      Ok(ValidateCallbackResult::Invalid(String::from(
+        "Only the requesting agent can withdraw a promotion request",
+      )))
+    }
+    PromotionRequestStatus::Approved | PromotionRequestStatus::Rejected
+      if request.reviewed_by.as_ref() != Some(&action.author)
+        || action.author == request.requesting_agent =>
+    {
+      Ok(ValidateCallbackResult::Invalid(String::from(
+        "Promotion requests are reviewed by another agent, named as their reviewer",
+      )))
+    }
+    PromotionRequestStatus::Pending => Ok(ValidateCallbackResult::Invalid(String::from(
+      "A promotion request update must close the request",
+    ))),
+    PromotionRequestStatus::Withdrawn => Ok(ValidateCallbackResult::Valid),
+    PromotionRequestStatus::Approved | PromotionRequestStatus::Rejected => {
+      validate_reviewer_authority(&request, &action)
+    }
+  }
+}
+
+// Reviewing a promotion needs the authority to assign the requested role, as assigning it does
+fn validate_reviewer_authority(
+  request: &RolePromotionRequest,
+  action: &Update,
+) -> ExternResult<ValidateCallbackResult> {
+  let Some(definition) = role_definition(&request.target_role, request.role_definition.clone())?
+  else {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Role '{}' is not in the role registry",
+      request.target_role
+    )));
+  };
+  if DnaProperties::get()?.progenitor.as_ref() == Some(&action.author) {
+    return Ok(ValidateCallbackResult::Valid);
+  }
+  validate_assigner_authority(
+    &request.target_role,
+    &definition,
+    &action.author,
+    request.reviewer_role.clone(),
+    &action.timestamp,
+  )
+}
+
+pub fn validate_delete_role_promotion_request() -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "Promotion requests cannot be deleted; withdraw them instead",
+  )))
 }
 
 pub fn validate_private_data_capability_metadata(
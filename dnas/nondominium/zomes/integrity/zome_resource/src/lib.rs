@@ -1,19 +1,25 @@
 use hdi::prelude::*;
-pub use nondominium_shared::types::{LifecycleStage, PropertyRegime, ResourceNature};
+pub use nondominium_shared::rules::{GovernanceRuleData, RuleParameters};
+pub use nondominium_shared::types::{
+  LifecycleStage, OperationalState, PropertyRegime, ResourceNature, ResourceValidationConfig,
+  ResourceValidationStatus,
+};
+pub use nondominium_shared::location::{GeoPoint, Location, PostalAddress};
+use nondominium_shared::roles::default_role;
+pub use nondominium_shared::units::{om2_unit, Dimension, Om2Unit, OM2_UNITS};
 
-// TODO (post-MVP): Split ResourceState into two orthogonal enums and migrate EconomicResource:
+// Legacy EconomicResource state (pre REQ-NDO-OS-06). It conflated maturity and the
+// in-flight process, and has been split into:
 //
-// 1. LifecycleStage — now in nondominium_shared::types (imported above).
+// 1. LifecycleStage   — maturity of the resource instance (nondominium_shared::types).
+// 2. OperationalState — the current process acting on this specific resource instance
+//    (nondominium_shared::types). Governance-zome controlled.
 //
-// 2. OperationalState — the current process acting on this specific resource instance (cycles
-//    frequently as processes begin and end). Governance-zome controlled.
-//    Values: Available, Reserved, InTransit, InStorage, InMaintenance, InUse, PendingValidation
-//
-// The current ResourceState enum CONFLATES both dimensions and is kept for EconomicResource
-// backwards-compatibility until the OperationalState refactor (REQ-NDO-OS-06).
+// Kept only so entries written before the split still deserialize: see
+// `EconomicResourceWire` and `ResourceState::split` below.
 //
 // See: documentation/requirements/ndo_prima_materia.md — Section 5 (LifecycleStage + OperationalState)
-// See: documentation/archives/resources.md — Section 2.4 (known gaps)
+// See: documentation/requirements/ndo_prima_materia.md — Section 10.3 (ResourceState migration map)
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum ResourceState {
   #[default]
@@ -24,6 +30,23 @@ pub enum ResourceState {
   Reserved,
 }
 
+impl ResourceState {
+  /// Maps a legacy state onto the (OperationalState, LifecycleStage) pair defined by the
+  /// migration map (ndo_prima_materia.md §10.3). Stages the map leaves "unchanged" default
+  /// to Active; Retired maps to EndOfLife because legacy entries carry no successor NDO.
+  pub fn split(&self) -> (OperationalState, LifecycleStage) {
+    match self {
+      ResourceState::PendingValidation => {
+        (OperationalState::PendingValidation, LifecycleStage::Prototype)
+      }
+      ResourceState::Active => (OperationalState::Available, LifecycleStage::Active),
+      ResourceState::Maintenance => (OperationalState::InMaintenance, LifecycleStage::Active),
+      ResourceState::Retired => (OperationalState::Available, LifecycleStage::EndOfLife),
+      ResourceState::Reserved => (OperationalState::Reserved, LifecycleStage::Active),
+    }
+  }
+}
+
 impl std::fmt::Display for ResourceState {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
@@ -49,24 +72,132 @@ pub struct ResourceSpecification {
   pub image_url: Option<String>,
   pub tags: Vec<String>, // For flexible discovery and filtering
   pub is_active: bool,   // For filtering active vs inactive specs
+  // How new resources of this spec are validated; specs written before this field
+  // default to a single approval
+  #[serde(default)]
+  pub validation: ResourceValidationConfig,
 }
 
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
+#[serde(from = "GovernanceRuleWire")]
 pub struct GovernanceRule {
   pub rule_type: String, // e.g., "access_requirement", "usage_limit", "transfer_conditions"
-  pub rule_data: String, // JSON-encoded rule parameters
+  pub rule_data: GovernanceRuleData, // Versioned, typed rule parameters
   pub enforced_by: Option<String>, // Role required to enforce this rule
+  // Ratified zome_person RoleDefinitions of the roles the rule names that are not default roles
+  pub role_definitions: Vec<ActionHash>,
+}
+
+// Deserialization shape for GovernanceRule. Rules written before typed rule data carry a
+// JSON string in `rule_data`; it is parsed against `rule_type`, and kept as `Custom` when it
+// does not fit the schema (the evaluator denies on such rules of a known type).
+#[derive(Deserialize)]
+struct GovernanceRuleWire {
+  rule_type: String,
+  rule_data: GovernanceRuleDataWire,
+  enforced_by: Option<String>,
+  #[serde(default)]
+  role_definitions: Vec<ActionHash>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GovernanceRuleDataWire {
+  Typed(GovernanceRuleData),
+  Legacy(String),
+}
+
+impl From<GovernanceRuleWire> for GovernanceRule {
+  fn from(wire: GovernanceRuleWire) -> Self {
+    let rule_data = match wire.rule_data {
+      GovernanceRuleDataWire::Typed(data) => data,
+      GovernanceRuleDataWire::Legacy(json) => GovernanceRuleData::from_json(&wire.rule_type, &json)
+        .unwrap_or(GovernanceRuleData::V1(RuleParameters::Custom(
+          nondominium_shared::rules::CustomRule { data: json },
+        ))),
+    };
+    GovernanceRule {
+      rule_type: wire.rule_type,
+      rule_data,
+      enforced_by: wire.enforced_by,
+      role_definitions: wire.role_definitions,
+    }
+  }
 }
 
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
+#[serde(try_from = "EconomicResourceWire")]
 pub struct EconomicResource {
-  pub quantity: f64,
+  // ValueFlows splits rights from custody: the accounting quantity is what the network
+  // accounts for, the onhand quantity what the custodian physically holds
+  pub accounting_quantity: f64,
+  pub onhand_quantity: f64,
   pub unit: String,
   pub custodian: AgentPubKey, // The Primary Accountable Agent holding the resource
-  pub current_location: Option<String>, // Physical or virtual location TODO: use an enum
-  pub state: ResourceState,
+  pub current_location: Option<Location>, // Physical or virtual location
+  pub state: OperationalState, // Process currently acting on this instance (REQ-NDO-OS-01)
+  pub lifecycle_stage: LifecycleStage, // Maturity of this instance (REQ-NDO-OS-06 facet)
+  pub validated_by: Option<ActionHash>, // Approved ResourceValidation that ended PendingValidation
+}
+
+// Deserialization shape for EconomicResource. Entries written before REQ-NDO-OS-06 carry a
+// legacy `ResourceState` in `state` and no `lifecycle_stage`; both shapes decode into the
+// current struct. Legacy variant names are tried first so that the shared names
+// (PendingValidation, Reserved) resolve identically either way. Entries written before the
+// accounting/onhand split carry a single `quantity`, which seeds both, and a free-text
+// `current_location`, which becomes a `Location::Named`.
+#[derive(Deserialize)]
+struct EconomicResourceWire {
+  #[serde(default)]
+  quantity: Option<f64>,
+  #[serde(default)]
+  accounting_quantity: Option<f64>,
+  #[serde(default)]
+  onhand_quantity: Option<f64>,
+  unit: String,
+  custodian: AgentPubKey,
+  #[serde(default, deserialize_with = "nondominium_shared::location::deserialize_location")]
+  current_location: Option<Location>,
+  state: EconomicResourceStateWire,
+  #[serde(default)]
+  lifecycle_stage: Option<LifecycleStage>,
+  #[serde(default)]
+  validated_by: Option<ActionHash>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EconomicResourceStateWire {
+  Legacy(ResourceState),
+  Operational(OperationalState),
+}
+
+impl TryFrom<EconomicResourceWire> for EconomicResource {
+  type Error = String;
+
+  fn try_from(wire: EconomicResourceWire) -> Result<Self, Self::Error> {
+    let (state, migrated_stage) = match wire.state {
+      EconomicResourceStateWire::Legacy(legacy) => legacy.split(),
+      EconomicResourceStateWire::Operational(state) => (state, LifecycleStage::Active),
+    };
+    let quantity = |field: Option<f64>| {
+      field
+        .or(wire.quantity)
+        .ok_or_else(|| "EconomicResource has no quantity".to_string())
+    };
+    Ok(EconomicResource {
+      accounting_quantity: quantity(wire.accounting_quantity)?,
+      onhand_quantity: quantity(wire.onhand_quantity)?,
+      unit: wire.unit,
+      custodian: wire.custodian,
+      current_location: wire.current_location,
+      state,
+      lifecycle_stage: wire.lifecycle_stage.unwrap_or(migrated_stage),
+      validated_by: wire.validated_by,
+    })
+  }
 }
 
 // NDO Layer 0 — NondominiumIdentity (REQ-NDO-L0-01, REQ-NDO-L0-07)
@@ -111,6 +242,78 @@ pub struct NondominiumIdentity {
   pub hibernation_origin: Option<LifecycleStage>,
 }
 
+// Two-step custody transfer. The current custodian proposes handing a specific
+// EconomicResource version to another agent; nothing changes until that agent commits a
+// CustodyTransferAcceptance, immediately followed (same zome call) by the EconomicResource
+// update that names them custodian. Integrity ties the update to the acceptance through
+// the update's prev_action — see `validate_custody_transfer`.
+// A pending proposal is withdrawn (by the proposer) or declined (by the recipient) by
+// deleting it.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct CustodyTransferProposal {
+  pub resource_hash: ActionHash, // EconomicResource version being handed over
+  pub current_custodian: AgentPubKey,
+  pub proposed_custodian: AgentPubKey,
+  pub note: Option<String>,
+  pub proposed_at: Timestamp,
+  // Transfer EconomicEvent that requested this handover; its acceptance logs no second event
+  #[serde(default)]
+  pub transfer_event: Option<ActionHash>,
+}
+
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct CustodyTransferAcceptance {
+  pub proposal_hash: ActionHash,
+  pub accepted_at: Timestamp,
+}
+
+// Unit of measure for resource and event quantities, identified by its symbol. Units are
+// immutable; the OM2 common units are built in and cannot be registered.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct UnitOfMeasure {
+  pub symbol: String, // e.g. "kg"; what EconomicResource.unit and events refer to
+  pub label: String,  // e.g. "kilogram"
+  pub dimension: Dimension,
+  pub factor: f64, // Size of one of this unit in the base unit of its dimension
+}
+
+impl From<&Om2Unit> for UnitOfMeasure {
+  fn from(unit: &Om2Unit) -> Self {
+    UnitOfMeasure {
+      symbol: unit.symbol.to_string(),
+      label: unit.label.to_string(),
+      dimension: unit.dimension,
+      factor: unit.factor,
+    }
+  }
+}
+
+impl UnitOfMeasure {
+  /// Express `quantity` of this unit in `target`, which must measure the same dimension
+  pub fn convert(&self, quantity: f64, target: &UnitOfMeasure) -> Result<f64, String> {
+    if self.dimension != target.dimension {
+      return Err(format!(
+        "Cannot convert {} ({:?}) to {} ({:?})",
+        self.symbol, self.dimension, target.symbol, target.dimension
+      ));
+    }
+    Ok(quantity * self.factor / target.factor)
+  }
+}
+
+// A registered place (VF SpatialThing) that resources and Move events can be located at.
+// Places are immutable; a place that moves is registered anew.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct SpatialThing {
+  pub name: String,
+  pub location: Location,
+  pub note: Option<String>,
+}
+
 #[hdk_entry_types]
 #[unit_enum(UnitEntryTypes)]
 #[derive(Serialize, Deserialize, SerializedBytes)]
@@ -119,6 +322,10 @@ pub enum EntryTypes {
   EconomicResource(EconomicResource),
   GovernanceRule(GovernanceRule),
   NondominiumIdentity(NondominiumIdentity),
+  CustodyTransferProposal(CustodyTransferProposal),
+  CustodyTransferAcceptance(CustodyTransferAcceptance),
+  UnitOfMeasure(UnitOfMeasure),
+  SpatialThing(SpatialThing),
 }
 
 #[hdk_link_types]
@@ -157,12 +364,16 @@ pub enum LinkTypes {
 
   // Service-type patterns (inspired by R&O ServiceType queries)
   SpecsByCategory,     // Category -> ResourceSpecs
-  ResourcesByLocation, // Location -> EconomicResources
-  ResourcesByState,    // ResourceState -> EconomicResources
-  // TODO (REQ-NDO-OS-06): Split ResourcesByState into two independent link types:
-  //   ResourcesByLifecycleStage  — NondominiumIdentity lifecycle facet queries
-  //   ResourcesByOperationalState — EconomicResource operational facet queries
-  // See: documentation/requirements/ndo_prima_materia.md — Section 9.4 (REQ-NDO-OS-06)
+  SpecsByNameToken,    // Path("specs_by_name.{word prefix}") -> ResourceSpec (original)
+  // Path("resource.location.{key}") / Path("resource.geo.{geohash prefix}") -> original
+  // EconomicResource; geo points are linked under every prefix up to GEOHASH_PRECISION
+  ResourcesByLocation,
+
+  // Independent faceted queries on the two resource state dimensions (REQ-NDO-OS-06).
+  // Replace the former ResourcesByState link type.
+  // anchor path pattern: "resource.operational.{State:?}" / "resource.lifecycle.{Stage:?}"
+  ResourcesByOperationalState, // Path("resource.operational.{state}") -> EconomicResources
+  ResourcesByLifecycleStage,   // Path("resource.lifecycle.{stage}")   -> EconomicResources
 
   // Governance patterns
   RulesByType,          // RuleType -> GovernanceRules
@@ -170,8 +381,23 @@ pub enum LinkTypes {
 
   // Update patterns (following person zome)
   ResourceSpecificationUpdates, // Original -> Updated ResourceSpec
-  EconomicResourceUpdates,      // Original -> Updated EconomicResource
+  EconomicResourceUpdates,      // No longer written: chains are walked with get_details
   GovernanceRuleUpdates,        // Original -> Updated GovernanceRule
+
+  // Custody transfer patterns
+  ResourceToCustodyProposals,     // EconomicResource -> CustodyTransferProposals
+  AgentToIncomingCustodyProposals, // Proposed custodian -> CustodyTransferProposals
+  AgentToOutgoingCustodyProposals, // Current custodian -> CustodyTransferProposals
+  CustodyProposalToAcceptance,    // CustodyTransferProposal -> CustodyTransferAcceptance
+
+  // Reverse of SpecificationToResource, for looking up a resource's governance rules
+  ResourceToSpecification, // EconomicResource (original) -> ResourceSpec
+
+  // Unit registry
+  AllUnits, // "units" anchor -> UnitOfMeasure, tagged with the unit symbol
+
+  // Location registry
+  AllSpatialThings, // "spatial_things" anchor -> SpatialThing
 }
 
 #[hdk_extern]
@@ -202,6 +428,14 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
         EntryTypes::NondominiumIdentity(ndi) => {
           validate_create_nondominium_identity(&ndi, &action.author)
         }
+        EntryTypes::CustodyTransferProposal(proposal) => {
+          validate_create_custody_transfer_proposal(&proposal, &action)
+        }
+        EntryTypes::CustodyTransferAcceptance(acceptance) => {
+          validate_create_custody_transfer_acceptance(&acceptance, &action)
+        }
+        EntryTypes::UnitOfMeasure(unit) => validate_create_unit(&unit),
+        EntryTypes::SpatialThing(place) => validate_create_spatial_thing(&place),
       },
       OpEntry::UpdateEntry {
         app_entry, action, ..
@@ -210,7 +444,21 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
           validate_update_resource_spec(&spec, &action.author)
         }
         EntryTypes::EconomicResource(resource) => {
-          validate_update_economic_resource(&resource, &action.author)
+          // Fetch the previous version to enforce custody and the state graphs (REQ-NDO-OS-06)
+          let original_record = must_get_valid_record(action.original_action_address.clone())?;
+          let original: EconomicResource = original_record
+            .entry()
+            .to_app_option()
+            .map_err(|e| {
+              wasm_error!(WasmErrorInner::Guest(format!(
+                "Failed to deserialize original EconomicResource: {:?}",
+                e
+              )))
+            })?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+              "Original EconomicResource entry not found in record".to_string()
+            )))?;
+          validate_update_economic_resource(&action, &original, &resource)
         }
         EntryTypes::GovernanceRule(rule) => validate_update_governance_rule(&rule, &action.author),
         EntryTypes::NondominiumIdentity(new_ndi) => {
@@ -231,12 +479,24 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             )))?;
           validate_update_nondominium_identity(&action, &original, &new_ndi)
         }
+        EntryTypes::CustodyTransferProposal(_) | EntryTypes::CustodyTransferAcceptance(_) => {
+          Ok(ValidateCallbackResult::Invalid(
+            "Custody transfer entries cannot be updated".to_string(),
+          ))
+        }
+        EntryTypes::UnitOfMeasure(_) => Ok(ValidateCallbackResult::Invalid(
+          "Units cannot be updated".to_string(),
+        )),
+        EntryTypes::SpatialThing(_) => Ok(ValidateCallbackResult::Invalid(
+          "Registered places cannot be updated".to_string(),
+        )),
       },
       _ => Ok(ValidateCallbackResult::Valid),
     },
     FlatOp::StoreRecord(store_record) => match store_record {
       OpRecord::DeleteEntry {
         original_action_hash,
+        action,
         ..
       } => {
         // Identify whether the deleted entry is a NondominiumIdentity (REQ-NDO-L0-03)
@@ -269,6 +529,18 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
         };
         match original_app_entry {
           EntryTypes::NondominiumIdentity(_) => validate_delete_nondominium_identity(),
+          EntryTypes::CustodyTransferProposal(proposal) => {
+            validate_delete_custody_transfer_proposal(&proposal, &action.author)
+          }
+          EntryTypes::CustodyTransferAcceptance(_) => Ok(ValidateCallbackResult::Invalid(
+            "CustodyTransferAcceptance entries cannot be deleted".to_string(),
+          )),
+          EntryTypes::UnitOfMeasure(_) => Ok(ValidateCallbackResult::Invalid(
+            "Units cannot be deleted".to_string(),
+          )),
+          EntryTypes::SpatialThing(_) => Ok(ValidateCallbackResult::Invalid(
+            "Registered places cannot be deleted".to_string(),
+          )),
           _ => Ok(ValidateCallbackResult::Valid),
         }
       }
@@ -286,6 +558,50 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
   }
 }
 
+fn validate_create_unit(unit: &UnitOfMeasure) -> ExternResult<ValidateCallbackResult> {
+  if unit.symbol.is_empty() || unit.symbol.contains(char::is_whitespace) {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Unit symbol must be non-empty and contain no whitespace".to_string(),
+    ));
+  }
+
+  if unit.label.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Unit label cannot be empty".to_string(),
+    ));
+  }
+
+  if !(unit.factor.is_finite() && unit.factor > 0.0) {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Unit factor must be a positive number".to_string(),
+    ));
+  }
+
+  // OM2 symbols resolve from the built-in table and can only ever mean that unit
+  if om2_unit(&unit.symbol).is_some() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Unit '{}' is a built-in OM2 unit and cannot be registered",
+      unit.symbol
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+fn validate_create_spatial_thing(place: &SpatialThing) -> ExternResult<ValidateCallbackResult> {
+  if place.name.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Place name cannot be empty".to_string(),
+    ));
+  }
+
+  if let Err(e) = place.location.validate() {
+    return Ok(ValidateCallbackResult::Invalid(e));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
 fn validate_create_resource_spec(
   spec: &ResourceSpecification,
   _author: &AgentPubKey,
@@ -308,16 +624,36 @@ fn validate_create_resource_spec(
     ));
   }
 
+  if let Err(e) = spec.validation.validate() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Invalid resource validation config: {}",
+      e
+    )));
+  }
+
   Ok(ValidateCallbackResult::Valid)
 }
 
 fn validate_create_economic_resource(
   resource: &EconomicResource,
-  _author: &AgentPubKey,
+  author: &AgentPubKey,
 ) -> ExternResult<ValidateCallbackResult> {
-  if resource.quantity <= 0.0 {
+  // Custody can only be claimed by the creator; handing it on goes through a transfer
+  if resource.custodian != *author {
+    return Ok(ValidateCallbackResult::Invalid(
+      "EconomicResource creator must be its initial custodian".to_string(),
+    ));
+  }
+
+  if !(resource.accounting_quantity.is_finite() && resource.accounting_quantity > 0.0) {
     return Ok(ValidateCallbackResult::Invalid(
-      "Resource quantity must be positive".to_string(),
+      "Resource accounting quantity must be positive".to_string(),
+    ));
+  }
+
+  if !(resource.onhand_quantity.is_finite() && resource.onhand_quantity >= 0.0) {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Resource onhand quantity cannot be negative".to_string(),
     ));
   }
 
@@ -327,6 +663,34 @@ fn validate_create_economic_resource(
     ));
   }
 
+  if let Some(Err(e)) = resource.current_location.as_ref().map(Location::validate) {
+    return Ok(ValidateCallbackResult::Invalid(e));
+  }
+
+  // New instances enter the operational graph at its root (REQ-NDO-OS-06)
+  if resource.state != OperationalState::PendingValidation {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "EconomicResource must be created in PendingValidation state, got {:?}",
+      resource.state
+    )));
+  }
+
+  if resource.validated_by.is_some() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A new EconomicResource cannot cite a ResourceValidation".to_string(),
+    ));
+  }
+
+  if matches!(
+    resource.lifecycle_stage,
+    LifecycleStage::Hibernating | LifecycleStage::Deprecated | LifecycleStage::EndOfLife
+  ) {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "EconomicResource cannot be created in lifecycle stage {:?}",
+      resource.lifecycle_stage
+    )));
+  }
+
   Ok(ValidateCallbackResult::Valid)
 }
 
@@ -334,21 +698,74 @@ fn validate_create_governance_rule(
   rule: &GovernanceRule,
   _author: &AgentPubKey,
 ) -> ExternResult<ValidateCallbackResult> {
-  if rule.rule_type.trim().is_empty() {
-    return Ok(ValidateCallbackResult::Invalid(
-      "Governance rule type cannot be empty".to_string(),
-    ));
+  // Typed rule data is checked here so a malformed rule never reaches the evaluator
+  if let Err(e) = rule
+    .rule_data
+    .validate(&rule.rule_type, rule.enforced_by.as_deref())
+  {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Invalid governance rule: {}",
+      e
+    )));
   }
 
-  if rule.rule_data.trim().is_empty() {
-    return Ok(ValidateCallbackResult::Invalid(
-      "Governance rule data cannot be empty".to_string(),
-    ));
+  let role_names = rule.rule_data.parameters().role_names();
+  for role_name in role_names.into_iter().chain(rule.enforced_by.as_deref()) {
+    if default_role(role_name).is_none() && !cites_ratified_role(rule, role_name)? {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Invalid governance rule: '{}' is not a default role or a cited ratified role",
+        role_name
+      )));
+    }
   }
 
   Ok(ValidateCallbackResult::Valid)
 }
 
+// Name of zome_person's integrity zome in the DNA manifest
+const PERSON_INTEGRITY_ZOME_NAME: &str = "zome_person_integrity";
+
+// Subset of zome_person's RoleDefinition read when a rule names a role
+#[derive(Serialize, Deserialize, SerializedBytes, Debug)]
+struct CitedRoleDefinition {
+  pub role_name: String,
+  pub status: CitedRoleDefinitionStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum CitedRoleDefinitionStatus {
+  Proposed,
+  Ratified,
+}
+
+// Whether the rule cites a ratified zome_person RoleDefinition of `role_name`. As for
+// `validated_by`, the entry is read through a subset of its fields once its action is known
+// to belong to zome_person_integrity.
+fn cites_ratified_role(rule: &GovernanceRule, role_name: &str) -> ExternResult<bool> {
+  let person_index = dna_info()?
+    .zome_names
+    .iter()
+    .position(|name| name.0 == PERSON_INTEGRITY_ZOME_NAME);
+  for definition_hash in &rule.role_definitions {
+    let record = must_get_valid_record(definition_hash.clone())?;
+    let from_person = matches!(
+      record.action().entry_type(),
+      Some(EntryType::App(def)) if Some(def.zome_index.0 as usize) == person_index
+    );
+    if !from_person {
+      continue;
+    }
+    if let Ok(Some(definition)) = record.entry().to_app_option::<CitedRoleDefinition>() {
+      if definition.role_name == role_name
+        && definition.status == CitedRoleDefinitionStatus::Ratified
+      {
+        return Ok(true);
+      }
+    }
+  }
+  Ok(false)
+}
+
 // REQ-NDO-L0-01: NondominiumIdentity name must not be empty.
 // REQ-NDO-LC-01: Only emergence/maturity/operation stages are valid at creation time.
 fn validate_create_nondominium_identity(
@@ -592,28 +1009,435 @@ fn validate_delete_nondominium_identity() -> ExternResult<ValidateCallbackResult
 }
 
 fn validate_update_resource_spec(
-  _spec: &ResourceSpecification,
+  spec: &ResourceSpecification,
   _author: &AgentPubKey,
 ) -> ExternResult<ValidateCallbackResult> {
-  // For Phase 1, allow updates
   // Phase 2 will add governance-based update validation
+  if let Err(e) = spec.validation.validate() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Invalid resource validation config: {}",
+      e
+    )));
+  }
   Ok(ValidateCallbackResult::Valid)
 }
 
+// EconomicResource update rules (REQ-NDO-OS-06):
+// - a custodian change goes through the transfer path (see `validate_custody_transfer`);
+// - any other update may only be authored by the current custodian (per the previous version);
+// - OperationalState changes follow the transition graph (ndo_prima_materia.md §5.4), and
+//   leaving PendingValidation cites the resource's approved ResourceValidation;
+// - LifecycleStage only advances, and EndOfLife is terminal.
 fn validate_update_economic_resource(
-  _resource: &EconomicResource,
-  _author: &AgentPubKey,
+  action: &Update,
+  original: &EconomicResource,
+  new_resource: &EconomicResource,
+) -> ExternResult<ValidateCallbackResult> {
+  if original.lifecycle_stage == LifecycleStage::EndOfLife {
+    return Ok(ValidateCallbackResult::Invalid(
+      "EconomicResource is at EndOfLife; no further updates are permitted".to_string(),
+    ));
+  }
+
+  if new_resource.custodian != original.custodian {
+    return validate_custody_transfer(action, original, new_resource);
+  }
+
+  if action.author != original.custodian {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only the current custodian may update an EconomicResource".to_string(),
+    ));
+  }
+
+  // Events may consume a resource entirely, but never below zero
+  let quantities = [new_resource.accounting_quantity, new_resource.onhand_quantity];
+  if !quantities.iter().all(|quantity| quantity.is_finite() && *quantity >= 0.0) {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Resource quantities must be finite and not negative".to_string(),
+    ));
+  }
+
+  if new_resource.unit.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Resource unit cannot be empty".to_string(),
+    ));
+  }
+
+  if let Some(Err(e)) = new_resource.current_location.as_ref().map(Location::validate) {
+    return Ok(ValidateCallbackResult::Invalid(e));
+  }
+
+  if !original.state.can_transition_to(&new_resource.state) {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Invalid OperationalState transition: {:?} -> {:?}",
+      original.state, new_resource.state
+    )));
+  }
+
+  if original.state == OperationalState::PendingValidation
+    && new_resource.state != OperationalState::PendingValidation
+  {
+    let promotion = validate_resource_promotion(action, new_resource)?;
+    if promotion != ValidateCallbackResult::Valid {
+      return Ok(promotion);
+    }
+  } else if new_resource.validated_by != original.validated_by {
+    return Ok(ValidateCallbackResult::Invalid(
+      "validated_by is only set when a resource leaves PendingValidation".to_string(),
+    ));
+  }
+
+  if !resource_lifecycle_transition_allowed(&original.lifecycle_stage, &new_resource.lifecycle_stage)
+  {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Invalid EconomicResource lifecycle transition: {:?} -> {:?}",
+      original.lifecycle_stage, new_resource.lifecycle_stage
+    )));
+  }
+
+  validate_version_not_offered(action)
+}
+
+// Name of zome_gouvernance's integrity zome in the DNA manifest
+const GOUVERNANCE_INTEGRITY_ZOME_NAME: &str = "zome_gouvernance_integrity";
+
+// Subset of zome_gouvernance's ResourceValidation read when a resource is promoted
+#[derive(Serialize, Deserialize, SerializedBytes, Debug)]
+struct CitedResourceValidation {
+  pub resource: ActionHash,
+  pub status: ResourceValidationStatus,
+}
+
+// A resource leaves PendingValidation on the strength of its ResourceValidation (REQ-GOV-02):
+// `validated_by` must cite an Approved version of the zome_gouvernance ResourceValidation
+// opened by the resource's creator, which zome_gouvernance_integrity only lets them open
+// once, with the scheme of the resource's specification. Integrity zomes cannot share entry
+// types, so the entry is read through a subset of its fields once its action is known to
+// belong to zome_gouvernance_integrity.
+fn validate_resource_promotion(
+  action: &Update,
+  new_resource: &EconomicResource,
 ) -> ExternResult<ValidateCallbackResult> {
-  // For Phase 1, allow updates
-  // Phase 2 will add custody transfer validation
+  let Some(validation_hash) = new_resource.validated_by.clone() else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Leaving PendingValidation requires an approved ResourceValidation".to_string(),
+    ));
+  };
+
+  let record = must_get_valid_record(validation_hash)?;
+  let gouvernance_index = dna_info()?
+    .zome_names
+    .iter()
+    .position(|name| name.0 == GOUVERNANCE_INTEGRITY_ZOME_NAME);
+  let from_gouvernance = matches!(
+    record.action().entry_type(),
+    Some(EntryType::App(def)) if Some(def.zome_index.0 as usize) == gouvernance_index
+  );
+  let validation = match record.entry().to_app_option::<CitedResourceValidation>() {
+    Ok(Some(validation)) if from_gouvernance => validation,
+    _ => {
+      return Ok(ValidateCallbackResult::Invalid(
+        "validated_by does not reference a ResourceValidation".to_string(),
+      ));
+    }
+  };
+
+  let resource_hash = create_action_hash(action.original_action_address.clone())?;
+  if validation.resource != resource_hash {
+    return Ok(ValidateCallbackResult::Invalid(
+      "validated_by references the validation of another resource".to_string(),
+    ));
+  }
+  let creator = must_get_action(resource_hash)?.action().author().clone();
+  let opened = must_get_action(create_action_hash(record.action_address().clone())?)?;
+  if *opened.action().author() != creator {
+    return Ok(ValidateCallbackResult::Invalid(
+      "validated_by references a ResourceValidation not opened by the resource's creator"
+        .to_string(),
+    ));
+  }
+  if validation.status != ResourceValidationStatus::Approved {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "The cited ResourceValidation is {:?}, not Approved",
+      validation.status
+    )));
+  }
+
   Ok(ValidateCallbackResult::Valid)
 }
 
-fn validate_update_governance_rule(
-  _rule: &GovernanceRule,
-  _author: &AgentPubKey,
+// Create action of the update chain `action_hash` belongs to
+fn create_action_hash(action_hash: ActionHash) -> ExternResult<ActionHash> {
+  let mut hash = action_hash;
+  loop {
+    match must_get_action(hash.clone())?.action() {
+      Action::Update(update) => hash = update.original_action_address.clone(),
+      _ => return Ok(hash),
+    }
+  }
+}
+
+// Transfer path: the incoming custodian authors the update, and the action immediately
+// preceding it on their chain must be a CustodyTransferAcceptance of a proposal made by the
+// current custodian for exactly this resource version. The update may change nothing but
+// the custodian, so a custody change can never smuggle in a state or quantity edit.
+fn validate_custody_transfer(
+  action: &Update,
+  original: &EconomicResource,
+  new_resource: &EconomicResource,
 ) -> ExternResult<ValidateCallbackResult> {
-  // For Phase 1, allow updates
-  // Phase 2 will add proper governance rule update validation
+  if action.author != new_resource.custodian {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A custody transfer must be authored by the incoming custodian".to_string(),
+    ));
+  }
+
+  let unchanged = EconomicResource {
+    custodian: new_resource.custodian.clone(),
+    ..original.clone()
+  };
+  if *new_resource != unchanged {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A custody transfer may only change the custodian field".to_string(),
+    ));
+  }
+
+  let Some(EntryTypes::CustodyTransferAcceptance(acceptance)) =
+    must_get_app_entry(action.prev_action.clone())?
+  else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "A custody transfer must directly follow a CustodyTransferAcceptance".to_string(),
+    ));
+  };
+  let Some(EntryTypes::CustodyTransferProposal(proposal)) =
+    must_get_app_entry(acceptance.proposal_hash)?
+  else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "CustodyTransferAcceptance does not reference a CustodyTransferProposal".to_string(),
+    ));
+  };
+
+  if proposal.resource_hash != action.original_action_address
+    || proposal.current_custodian != original.custodian
+    || proposal.proposed_custodian != new_resource.custodian
+  {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Accepted custody proposal does not match this resource update".to_string(),
+    ));
+  }
+
   Ok(ValidateCallbackResult::Valid)
 }
+
+// Integrity cannot see whether a version has already been superseded, but it can see what
+// its custodian offered: a custodian may only update a version after deleting every custody
+// transfer proposal they made for it. Should a custodian withdraw a proposal that was
+// already accepted and update the version anyway, the version forks; reads resolve a
+// handed-over version to its first handover (see `get_latest_economic_resource_record`), so
+// the previous custodian's branch is ignored.
+fn validate_version_not_offered(action: &Update) -> ExternResult<ValidateCallbackResult> {
+  let open = open_custody_proposals(
+    &action.author,
+    &action.prev_action,
+    &action.original_action_address,
+  )?;
+  if !open.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "EconomicResource version is offered in a custody transfer proposal; withdraw it before updating"
+        .to_string(),
+    ));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// Custody transfer proposals for `version` on the custodian's chain, from `top` back, that
+// they have not deleted. The custodian authored the version, so the scan stops there.
+fn open_custody_proposals(
+  custodian: &AgentPubKey,
+  top: &ActionHash,
+  version: &ActionHash,
+) -> ExternResult<Vec<ActionHash>> {
+  let activity = must_get_agent_activity(
+    custodian.clone(),
+    ChainFilter::new(top.clone()).until_hash(version.clone()),
+  )?;
+
+  let mut offered = Vec::new();
+  let mut withdrawn = Vec::new();
+  for item in activity {
+    match item.action.action() {
+      Action::Create(create) => {
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+          continue;
+        };
+        let entry = must_get_entry(create.entry_hash.clone())?;
+        if let Some(EntryTypes::CustodyTransferProposal(proposal)) =
+          EntryTypes::deserialize_from_type(
+            app_entry_def.zome_index,
+            app_entry_def.entry_index,
+            entry.as_content(),
+          )?
+        {
+          if proposal.resource_hash == *version {
+            offered.push(item.action.as_hash().clone());
+          }
+        }
+      }
+      Action::Delete(delete) => withdrawn.push(delete.deletes_address.clone()),
+      _ => {}
+    }
+  }
+
+  offered.retain(|proposal| !withdrawn.contains(proposal));
+  Ok(offered)
+}
+
+// Resource instances only move forward along the maturity chain, or straight to EndOfLife.
+// Hibernating and Deprecated are NondominiumIdentity-level stages and do not apply to an
+// individual instance.
+fn resource_lifecycle_transition_allowed(from: &LifecycleStage, to: &LifecycleStage) -> bool {
+  fn maturity_rank(stage: &LifecycleStage) -> Option<u8> {
+    match stage {
+      LifecycleStage::Ideation => Some(0),
+      LifecycleStage::Specification => Some(1),
+      LifecycleStage::Development => Some(2),
+      LifecycleStage::Prototype => Some(3),
+      LifecycleStage::Stable => Some(4),
+      LifecycleStage::Distributed => Some(5),
+      LifecycleStage::Active => Some(6),
+      LifecycleStage::EndOfLife => Some(7),
+      LifecycleStage::Hibernating | LifecycleStage::Deprecated => None,
+    }
+  }
+
+  if from == to {
+    return true;
+  }
+  match (maturity_rank(from), maturity_rank(to)) {
+    (Some(from_rank), Some(to_rank)) => to_rank > from_rank,
+    _ => false,
+  }
+}
+
+// The proposer must be the custodian of the exact resource version being handed over, and
+// resources still pending validation cannot change hands. A version is offered to one agent
+// at a time, so only one acceptance can exist for it (see `validate_version_not_offered`).
+fn validate_create_custody_transfer_proposal(
+  proposal: &CustodyTransferProposal,
+  action: &Create,
+) -> ExternResult<ValidateCallbackResult> {
+  let author = &action.author;
+  if proposal.current_custodian != *author {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only the current custodian may propose a custody transfer".to_string(),
+    ));
+  }
+
+  if proposal.proposed_custodian == proposal.current_custodian {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Cannot propose a custody transfer to the current custodian".to_string(),
+    ));
+  }
+
+  let Some(EntryTypes::EconomicResource(resource)) =
+    must_get_app_entry(proposal.resource_hash.clone())?
+  else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Custody transfer proposal must reference an EconomicResource".to_string(),
+    ));
+  };
+
+  if resource.custodian != proposal.current_custodian {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Proposer is not the custodian of the referenced EconomicResource".to_string(),
+    ));
+  }
+
+  if resource.state == OperationalState::PendingValidation {
+    return Ok(ValidateCallbackResult::Invalid(
+      "EconomicResource pending validation cannot be transferred".to_string(),
+    ));
+  }
+
+  let open = open_custody_proposals(author, &action.prev_action, &proposal.resource_hash)?;
+  if !open.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "EconomicResource version already has an open custody transfer proposal".to_string(),
+    ));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// A declined proposal stays declined: the recipient may not accept a proposal they deleted.
+fn validate_create_custody_transfer_acceptance(
+  acceptance: &CustodyTransferAcceptance,
+  action: &Create,
+) -> ExternResult<ValidateCallbackResult> {
+  let Some(EntryTypes::CustodyTransferProposal(proposal)) =
+    must_get_app_entry(acceptance.proposal_hash.clone())?
+  else {
+    return Ok(ValidateCallbackResult::Invalid(
+      "CustodyTransferAcceptance must reference a CustodyTransferProposal".to_string(),
+    ));
+  };
+
+  if proposal.proposed_custodian != action.author {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only the proposed custodian may accept a custody transfer".to_string(),
+    ));
+  }
+
+  // Only deletes made since the proposal can concern it
+  let proposed_at = must_get_action(acceptance.proposal_hash.clone())?
+    .action()
+    .timestamp();
+  let activity = must_get_agent_activity(
+    action.author.clone(),
+    ChainFilter::new(action.prev_action.clone()).until_timestamp(proposed_at),
+  )?;
+  let declined = activity.iter().any(|item| {
+    matches!(item.action.action(), Action::Delete(delete)
+      if delete.deletes_address == acceptance.proposal_hash)
+  });
+  if declined {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Cannot accept a custody transfer proposal that was declined".to_string(),
+    ));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// Withdrawal by the proposer or decline by the recipient.
+fn validate_delete_custody_transfer_proposal(
+  proposal: &CustodyTransferProposal,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  if *author != proposal.current_custodian && *author != proposal.proposed_custodian {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only the parties to a custody transfer proposal may delete it".to_string(),
+    ));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+// Fetches a valid record and decodes its app entry with the entry type recorded on the action.
+fn must_get_app_entry(action_hash: ActionHash) -> ExternResult<Option<EntryTypes>> {
+  let record = must_get_valid_record(action_hash)?;
+  let Some(EntryType::App(app_entry_def)) = record.action().entry_type() else {
+    return Ok(None);
+  };
+  let Some(entry) = record.entry().as_option() else {
+    return Ok(None);
+  };
+  EntryTypes::deserialize_from_type(app_entry_def.zome_index, app_entry_def.entry_index, entry)
+}
+
+fn validate_update_governance_rule(
+  rule: &GovernanceRule,
+  author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  // Phase 2 will add authorization checks on who may update a rule
+  validate_create_governance_rule(rule, author)
+}
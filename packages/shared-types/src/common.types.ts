@@ -8,6 +8,22 @@ import type {
 // Core Holochain types
 export type HolochainHash = ActionHash | EntryHash;
 
+// Pagination: `*_paginated` listing externs order links by timestamp, then link hash
+export interface PageCursor {
+  timestamp: Timestamp;
+  link_hash: ActionHash;
+}
+
+export interface PageRequest {
+  cursor?: PageCursor;
+  limit: number; // 0 means the default page size (50); capped at 200
+}
+
+export interface Page<T> {
+  items: T[];
+  next_cursor?: PageCursor; // Absent on the last page
+}
+
 // API Response Types
 export interface ApiResponse<T> {
   success: boolean;
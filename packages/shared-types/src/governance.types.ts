@@ -1,4 +1,6 @@
 import type { ActionHash, AgentPubKey, EntryHash, Timestamp } from '@holochain/client';
+import type { Page, PageRequest } from './common.types.js';
+import type { Location } from './resource.types.js';
 
 // Core Governance Types
 export interface Commitment {
@@ -45,10 +47,103 @@ export interface VfEconomicEvent {
   provider: AgentPubKey;
   receiver: AgentPubKey;
   resource_inventoried_as: ActionHash;
-  affects: ActionHash;
+  affects: ActionHash; // resource version the event was applied to
   resource_quantity: number;
   event_time: Timestamp;
   note?: string | null;
+  to_location?: Location | null; // destination of a Move
+  resource_unit?: string | null; // unit symbol of resource_quantity
+}
+
+/** What `log_economic_event` did to the event's resource (`resource_effect` in its output). */
+export interface AppliedEventEffect {
+  updated_resource_hash?: ActionHash | null;
+  custody_proposal_hash?: ActionHash | null; // Transfer awaiting the receiver's acceptance
+}
+
+/** Stored negotiation status of a `zome_gouvernance` Commitment. */
+export type CommitmentStatus = 'Proposed' | 'Accepted' | 'Declined' | 'Cancelled';
+
+/** Effective Commitment state: status combined with fulfillment and due date (computed, never stored). */
+export type CommitmentState = CommitmentStatus | 'PartiallyFulfilled' | 'Fulfilled' | 'Overdue';
+
+/** Commitment entry shape in `zome_gouvernance` (distinct from legacy `Commitment` above). */
+export interface VfCommitment {
+  action: VfAction;
+  provider: AgentPubKey;
+  receiver: AgentPubKey;
+  resource_inventoried_as?: ActionHash | null;
+  resource_conforms_to?: ActionHash | null;
+  input_of?: ActionHash | null;
+  due_date: Timestamp;
+  note?: string | null;
+  committed_at: Timestamp;
+  resource_quantity?: number | null;
+  status: CommitmentStatus;
+}
+
+export interface FulfillmentProgress {
+  committed_quantity?: number | null;
+  fulfilled_quantity: number;
+  fulfilling_events: number;
+}
+
+export interface CommitmentStateOutput {
+  commitment: VfCommitment;
+  state: CommitmentState;
+  progress: FulfillmentProgress;
+}
+
+export type CommitmentSide = 'Provider' | 'Receiver';
+
+export interface GetAgentCommitmentsInput {
+  agent: AgentPubKey;
+  side: CommitmentSide;
+  due_from?: Timestamp | null;
+  due_until?: Timestamp | null;
+  statuses?: CommitmentStatus[] | null;
+}
+
+export interface CommitmentListing {
+  commitment_hash: ActionHash;
+  commitment: VfCommitment;
+}
+
+export interface UpdateCommitmentStatusOutput {
+  commitment_hash: ActionHash;
+  commitment: VfCommitment;
+  ppr_claims?: unknown | null;
+}
+
+/** ValueFlows Process that Commitments, EconomicEvents and Contributions are `input_of`. */
+export interface Process {
+  name: string;
+  based_on?: ActionHash | null;
+  note?: string | null;
+  planned_start?: Timestamp | null;
+  planned_end?: Timestamp | null;
+  finished: boolean;
+  created_by: AgentPubKey;
+  created_at: Timestamp;
+}
+
+export interface OpenProcessInput {
+  name: string;
+  based_on?: ActionHash | null;
+  note?: string | null;
+  planned_start?: Timestamp | null;
+  planned_end?: Timestamp | null;
+}
+
+export interface ProcessRecord {
+  action_hash: ActionHash;
+  entry: Process;
+}
+
+export interface ProcessInputs {
+  commitments: ActionHash[];
+  economic_events: ActionHash[];
+  contributions: ActionHash[];
 }
 
 // Zome Function Types
@@ -61,4 +156,17 @@ export interface GovernanceZomeFunctions {
   get_events_by_agent: (agent: AgentPubKey) => Promise<EconomicEvent[]>;
   get_events_for_resource: (resourceHash: ActionHash) => Promise<VfEconomicEvent[]>;
   get_all_economic_events: () => Promise<VfEconomicEvent[]>;
+  get_all_economic_events_paginated: (page: PageRequest) => Promise<Page<VfEconomicEvent>>;
+  accept_commitment: (hash: ActionHash) => Promise<UpdateCommitmentStatusOutput>;
+  decline_commitment: (hash: ActionHash) => Promise<UpdateCommitmentStatusOutput>;
+  cancel_commitment: (hash: ActionHash) => Promise<UpdateCommitmentStatusOutput>;
+  get_commitment_state: (hash: ActionHash) => Promise<CommitmentStateOutput>;
+  get_agent_commitments: (input: GetAgentCommitmentsInput) => Promise<CommitmentListing[]>;
+  open_process: (input: OpenProcessInput) => Promise<ProcessRecord>;
+  get_process: (hash: ActionHash) => Promise<ProcessRecord | null>;
+  get_all_processes: () => Promise<ProcessRecord[]>;
+  get_all_processes_paginated: (page: PageRequest) => Promise<Page<ProcessRecord>>;
+  get_agent_processes: (agent: AgentPubKey) => Promise<ProcessRecord[]>;
+  get_process_inputs: (hash: ActionHash) => Promise<ProcessInputs>;
+  finish_process: (hash: ActionHash) => Promise<ProcessRecord>;
 }
\ No newline at end of file
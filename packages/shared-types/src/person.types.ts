@@ -1,4 +1,5 @@
-import type { ActionHash, AgentPubKey, Timestamp, CapSecret } from "@holochain/client";
+import type { ActionHash, AgentPubKey, Timestamp, CapSecret, Record } from "@holochain/client";
+import type { Page, PageRequest } from "./common.types.js";
 
 // Core Person Types
 export interface Person {
@@ -12,6 +13,36 @@ export interface Person {
   hrea_agent_hash?: ActionHash;
 }
 
+/** What kind of agent a Person stands for; agents without an AgentContext are individuals. */
+export type AgentEntityType =
+  | "Individual"
+  | { Collective: string }
+  | { Project: ActionHash } // Organisational NondominiumIdentity
+  | { Network: ActionHash } // Network NondominiumIdentity
+  | {
+      Bot: {
+        capabilities: string[];
+        operator: AgentPubKey; // Must hold an Accountable Agent level role
+        operator_role?: ActionHash; // Filled in by create_person
+        operator_consent?: ActionHash; // Filled in by create_person
+      };
+    }
+  | { ExternalOrganisation: string };
+
+/** An operator's agreement to answer for a bot. */
+export interface BotOperatorConsent {
+  bot: AgentPubKey;
+  operator: AgentPubKey;
+  consented_at: Timestamp;
+}
+
+export interface AgentContext {
+  agent_type: AgentEntityType;
+  person_hash?: ActionHash;
+  created_at: Timestamp;
+  network_seed: string;
+}
+
 export interface EncryptedProfile {
   email?: string;
   phone?: string;
@@ -40,8 +71,38 @@ export interface PersonRole {
   assigned_to?: AgentPubKey;
   assigned_by: AgentPubKey;
   assigned_at: Timestamp;
+  role_definition?: ActionHash; // Ratified RoleDefinition of a community-defined role
+  assigner_role?: ActionHash; // Assigner's own PersonRole authorizing the assignment
+  expires_at?: Timestamp; // End of the term of a time-boxed role
+}
+
+export type RoleRevocationKind = "Revoke" | "Suspend" | "Reinstate";
+
+/** A revocation, suspension or reinstatement of a PersonRole. */
+export interface RoleRevocation {
+  person_role: ActionHash; // Original action hash of the role
+  kind: RoleRevocationKind;
+  reason: string;
+  revoked_by: AgentPubKey;
+  revoker_role?: ActionHash; // Revoker's own PersonRole authorizing the revocation
+  revoked_at: Timestamp;
+}
+
+/** Status of a role; only Active roles grant capabilities. */
+export type RoleStatus = "Active" | "Expired" | "Suspended" | "Revoked";
+
+export interface RoleRevocationInput {
+  person_role: ActionHash; // Original action hash of the role
+  reason: string;
 }
 
+export interface PersonRoleRecord {
+  action_hash: ActionHash; // Original creation hash
+  role: PersonRole;
+  status: RoleStatus;
+}
+
+/** Default roles; communities add their own through the role registry. */
 export type RoleType =
   | "Simple Agent"
   | "Accountable Agent"
@@ -56,11 +117,83 @@ export type CapabilityLevel =
   | "stewardship"
   | "member";
 
+/** DNA properties; the progenitor is the root of role authority (none: member level self-assignment only). */
+export interface DnaProperties {
+  progenitor?: AgentPubKey;
+}
+
+export type RoleDefinitionStatus = "Proposed" | "Ratified";
+
+/** A role of the role registry. */
+export interface RoleDefinition {
+  role_name: string;
+  capability_level: CapabilityLevel;
+  description?: string;
+  validation_requirements?: string;
+  assignable_by: CapabilityLevel; // Lowest capability level that may assign the role
+  status: RoleDefinitionStatus;
+  ratifier_role?: ActionHash; // Ratifier's own governance level PersonRole
+}
+
+export type RoleDefinitionInput = Omit<RoleDefinition, "status" | "ratifier_role">;
+
+export interface RoleDefinitionRecord {
+  action_hash: ActionHash; // Original creation hash
+  definition: RoleDefinition;
+}
+
+export type PromotionRequestStatus =
+  | "Pending"
+  | "Approved"
+  | "Rejected"
+  | "Withdrawn";
+
+/** An agent's request to be promoted to a role. */
+export interface RolePromotionRequest {
+  requesting_agent: AgentPubKey;
+  target_role: string;
+  justification: string;
+  created_at: Timestamp;
+  status: PromotionRequestStatus;
+  reviewed_by?: AgentPubKey;
+  review_notes?: string; // Approval notes, or reason of the rejection
+  reviewer_role?: ActionHash; // Reviewer's PersonRole at the role's assignable_by level
+  role_definition?: ActionHash; // Ratified RoleDefinition of a non-default role
+}
+
+export interface RolePromotionRequestInput {
+  target_role: string;
+  justification: string;
+}
+
+export interface PromotionRequestRecord {
+  action_hash: ActionHash; // Original creation hash
+  request: RolePromotionRequest;
+}
+
+export interface ApprovePromotionInput {
+  request_hash: ActionHash;
+  approval_notes?: string;
+}
+
+export interface RejectPromotionInput {
+  request_hash: ActionHash;
+  reason: string;
+}
+
+/** Signals of the person zome; reviews also reach the requesting agent. */
+export type PersonSignal = {
+  type: "PromotionRequestReviewed";
+  request_hash: ActionHash;
+  request: RolePromotionRequest;
+};
+
 // Input/Output types for zome functions
 export interface PersonInput {
   name: string;
   avatar_url?: string;
   bio?: string;
+  agent_type?: AgentEntityType; // Defaults to "Individual"
 }
 
 /** Matches `zome_person::update_person` input. */
@@ -84,6 +217,7 @@ export interface PersonRoleInput {
   agent_pubkey: AgentPubKey;
   role_name: string;
   description?: string;
+  expires_at?: Timestamp;
 }
 
 export interface PersonProfileOutput {
@@ -254,11 +388,33 @@ export interface PersonZomeFunctions {
     person: Omit<Person, "agent_pub_key" | "created_at">,
   ) => Promise<ActionHash>;
   get_person: (hash: ActionHash) => Promise<Person>;
+  get_agent_context: (agent: AgentPubKey) => Promise<AgentContext | null>;
+  get_agent_entity_type: (agent: AgentPubKey) => Promise<AgentEntityType>;
+  consent_to_operate_bot: (bot: AgentPubKey) => Promise<ActionHash>;
   get_all_persons: () => Promise<Person[]>;
+  get_all_persons_paginated: (page: PageRequest) => Promise<Page<Person>>;
   create_encrypted_profile: (
     profile: Omit<EncryptedProfile, "agent_pub_key" | "created_at">,
   ) => Promise<ActionHash>;
   get_encrypted_profile: (hash: ActionHash) => Promise<EncryptedProfile>;
   assign_role: (agent: AgentPubKey, role: string) => Promise<ActionHash>;
   get_roles: (agent: AgentPubKey) => Promise<PersonRole[]>;
+  propose_role_definition: (input: RoleDefinitionInput) => Promise<RoleDefinitionRecord>;
+  ratify_role_definition: (actionHash: ActionHash) => Promise<RoleDefinitionRecord>;
+  get_role_definition: (roleName: string) => Promise<RoleDefinitionRecord | null>;
+  get_all_role_definitions: () => Promise<RoleDefinitionRecord[]>;
+  get_proposed_role_definitions: () => Promise<RoleDefinitionRecord[]>;
+  request_role_promotion: (input: RolePromotionRequestInput) => Promise<ActionHash>;
+  get_pending_promotion_requests: () => Promise<PromotionRequestRecord[]>;
+  get_my_promotion_requests: () => Promise<PromotionRequestRecord[]>;
+  approve_role_promotion: (input: ApprovePromotionInput) => Promise<Record>;
+  reject_role_promotion: (input: RejectPromotionInput) => Promise<PromotionRequestRecord>;
+  withdraw_role_promotion: (requestHash: ActionHash) => Promise<PromotionRequestRecord>;
+  revoke_person_role: (input: RoleRevocationInput) => Promise<Record>;
+  suspend_person_role: (input: RoleRevocationInput) => Promise<Record>;
+  reinstate_person_role: (input: RoleRevocationInput) => Promise<Record>;
+  get_role_revocations: (personRole: ActionHash) => Promise<RoleRevocation[]>;
+  get_person_role_status: (personRole: ActionHash) => Promise<RoleStatus>;
+  get_person_role_records: (agent: AgentPubKey) => Promise<PersonRoleRecord[]>;
+  get_active_person_roles: (agent: AgentPubKey) => Promise<GetPersonRolesOutput>;
 }
@@ -143,6 +143,13 @@ export interface PrivateParticipationClaim {
   counterparty: AgentPubKey;             // The other agent involved in the interaction
   resource_hash?: ActionHash;            // Optional link to the resource involved
   notes?: string;                        // Optional contextual notes
+
+  // The rest of the signed terms, so the bilateral signature can be checked
+  provider: AgentPubKey;
+  receiver: AgentPubKey;
+  counterparty_claim_type: ParticipationClaimType;
+  counterparty_metrics: PerformanceMetrics;
+  requested_at: Timestamp;
 }
 
 /**
@@ -1,12 +1,16 @@
 import type { ActionHash, AgentPubKey, EntryHash, Record, Timestamp } from '@holochain/client';
+import type { Page, PageRequest } from './common.types.js';
 
 // Resource State Types
-export type ResourceState =
-  | "PendingValidation"
-  | "Active"
-  | "Maintenance"
-  | "Retired"
-  | "Reserved";
+// Process currently acting on an EconomicResource instance (REQ-NDO-OS-06).
+export type OperationalState =
+  | "Available"
+  | "Reserved"
+  | "InTransit"
+  | "InStorage"
+  | "InMaintenance"
+  | "InUse"
+  | "PendingValidation";
 
 // Core Resource Types
 export interface ResourceSpecification {
@@ -18,21 +22,88 @@ export interface ResourceSpecification {
   image_url?: string;
   tags?: string[];
   is_active?: boolean;
+  validation?: ResourceValidationConfig;
+}
+
+// How new resources of a specification are validated before becoming Active
+export interface ResourceValidationConfig {
+  validation_scheme: string; // "k-of-n" (e.g. "2-of-3"), "simple_majority", "unanimous" or "simple_approval"
+  required_validators: number; // Validator panel size (n); must equal n for "k-of-n"
+}
+
+export interface GeoPoint {
+  lat: number;
+  long: number;
+}
+
+export interface PostalAddress {
+  street?: string | null;
+  locality: string;
+  region?: string | null;
+  postal_code?: string | null;
+  country: string;
+}
+
+/** Where a resource is; Rust's externally tagged `Location` enum. */
+export type Location =
+  | { Named: string }
+  | { Address: PostalAddress }
+  | { Geo: GeoPoint }
+  | { Virtual: string }; // URI
+
+/** Registered place (VF SpatialThing) in `zome_resource`. */
+export interface SpatialThing {
+  name: string;
+  location: Location;
+  note?: string | null;
+}
+
+export interface NearbyResource {
+  record: Record; // latest version of the resource
+  distance_km: number;
 }
 
 export interface EconomicResource {
-  quantity: number;
+  accounting_quantity: number; // quantity the network accounts for (rights)
+  onhand_quantity: number; // quantity the custodian physically holds
   unit: string;
   custodian: AgentPubKey;
-  current_location?: string;
-  state: ResourceState;
+  current_location?: Location | null;
+  state: OperationalState;
+  lifecycle_stage: LifecycleStage;
+  validated_by?: ActionHash | null; // approved ResourceValidation that ended PendingValidation
+}
+
+export type Dimension = 'Count' | 'Mass' | 'Length' | 'Area' | 'Volume' | 'Time' | 'Energy';
+
+/** Registry entry for a unit symbol; `factor` is its size in the dimension's base unit. */
+export interface UnitOfMeasure {
+  symbol: string;
+  label: string;
+  dimension: Dimension;
+  factor: number;
+}
+
+export interface UnitRecord {
+  action_hash?: ActionHash; // Absent for a built-in OM2 unit
+  unit: UnitOfMeasure;
 }
 
 // Governance Types
+export type RuleParameters =
+  | { AccessRequirement: { min_role: string } }
+  | { UsageLimit: { max_duration_hours?: number; max_quantity?: number } }
+  | { TransferConditions: { min_role?: string; required_validators: number } }
+  | { Custom: { data: string } }; // JSON document, not enforced
+
+// Versioned envelope stored on-chain; GovernanceRuleInput.rule_data stays a JSON string
+export type GovernanceRuleData = { version: 'V1'; rule: RuleParameters };
+
 export interface GovernanceRule {
   rule_type: string;
-  rule_data: string;
+  rule_data: GovernanceRuleData;
   enforced_by?: string;
+  role_definitions: ActionHash[]; // Ratified RoleDefinitions of the non-default roles named
 }
 
 export interface GovernanceRules {
@@ -61,13 +132,14 @@ export interface ResourceSpecificationInput {
   image_url?: string;
   tags: string[];
   governance_rules: GovernanceRuleInput[];
+  validation?: ResourceValidationConfig; // Defaults to a single approval
 }
 
 export interface EconomicResourceInput {
   spec_hash: ActionHash;
   quantity: number;
   unit: string;
-  current_location?: string;
+  current_location?: Location | null;
 }
 
 export interface GovernanceRuleInput {
@@ -85,6 +157,7 @@ export interface CreateResourceSpecificationOutput {
 export interface CreateEconomicResourceOutput {
   resource_hash: ActionHash;
   resource: EconomicResource;
+  validation_hash: ActionHash;
 }
 
 export interface GetAllResourceSpecificationsOutput {
@@ -99,6 +172,21 @@ export interface ResourceSpecificationListing {
   specification: ResourceSpecification;
 }
 
+/** Criteria of `search_resource_specifications`; every given criterion must match. */
+export interface SearchResourceSpecificationsInput {
+  text?: string; // Every word must start a word of the name
+  category?: string;
+  tags?: string[]; // The spec must have all of them
+  is_active?: boolean;
+}
+
+/** A search result: the latest version of a spec, with its name relevance. */
+export interface ResourceSpecificationMatch {
+  action_hash: ActionHash;
+  specification: ResourceSpecification;
+  score: number;
+}
+
 /** NDO card / lobby descriptor (UI layer; mirrors Effect schema `NdoDescriptor`). */
 export interface NdoDescriptor {
   hash: string;
@@ -236,14 +324,32 @@ export interface GetResourceSpecWithRulesOutput {
   governance_rules: GovernanceRule[];
 }
 
-export interface TransferCustodyInput {
+export interface ProposeCustodyTransferInput {
   resource_hash: ActionHash;
   new_custodian: AgentPubKey;
+  note?: string;
+  request_contact_info?: boolean;
+}
+
+export interface CustodyTransferProposal {
+  resource_hash: ActionHash;
+  current_custodian: AgentPubKey;
+  proposed_custodian: AgentPubKey;
+  note?: string;
+  proposed_at: Timestamp;
+  transfer_event?: ActionHash | null; // Transfer event that requested the handover
+}
+
+export interface CustodyTransferProposalRecord {
+  proposal_hash: ActionHash;
+  proposal: CustodyTransferProposal;
 }
 
-export interface TransferCustodyOutput {
+export interface AcceptCustodyTransferOutput {
   updated_resource_hash: ActionHash;
   updated_resource: EconomicResource;
+  acceptance_hash: ActionHash;
+  event_hash: ActionHash;
 }
 
 // Zome Function Types
@@ -253,12 +359,26 @@ export interface ResourceZomeFunctions {
   ) => Promise<ActionHash>;
   get_resource_specification: (hash: ActionHash) => Promise<ResourceSpecification>;
   get_all_resource_specifications: () => Promise<GetAllResourceSpecificationsOutput>;
+  get_all_resource_specifications_paginated: (
+    page: PageRequest
+  ) => Promise<Page<ResourceSpecificationListing>>;
+  search_resource_specifications: (
+    input: SearchResourceSpecificationsInput
+  ) => Promise<ResourceSpecificationMatch[]>;
   get_resource_specification_with_rules: (
     specHash: ActionHash
   ) => Promise<GetResourceSpecWithRulesOutput>;
   get_resources_by_specification: (specHash: ActionHash) => Promise<Record[]>;
   get_all_ndos: () => Promise<GetAllNdosOutput>;
+  get_all_ndos_paginated: (page: PageRequest) => Promise<Page<NdoOutput>>;
+  get_all_economic_resources_paginated: (page: PageRequest) => Promise<Page<EconomicResource>>;
   create_economic_resource: (resource: Omit<EconomicResource, 'created_at'>) => Promise<ActionHash>;
   get_economic_resource: (hash: ActionHash) => Promise<EconomicResource>;
   get_resources_by_custodian: (custodian: AgentPubKey) => Promise<EconomicResource[]>;
+  get_resources_at_location: (location: Location) => Promise<Record[]>;
+  get_resources_near: (input: {
+    lat: number;
+    long: number;
+    radius_km: number;
+  }) => Promise<NearbyResource[]>;
 }
\ No newline at end of file
@@ -94,10 +94,10 @@ export class UIResourceValidation extends Schema.Class<UIResourceValidation>(
   'UIResourceValidation'
 )({
   resource: Schema.Any, // ActionHash
+  resource_spec: Schema.Any, // ActionHash of the ResourceSpecification version
   validation_scheme: Schema.String, // e.g. "2-of-3", "simple_majority"
-  required_validators: Schema.Number, // u32
-  current_validators: Schema.Number, // u32
-  status: Schema.String, // "pending" | "approved" | "rejected"
+  required_validators: Schema.Number, // u32, validator panel size (n)
+  status: Schema.Literal('Pending', 'Approved', 'Rejected'), // tally via get_validation_tally
   created_at: Schema.Number,
   updated_at: Schema.Number,
   original_action_hash: Schema.optional(Schema.Any)
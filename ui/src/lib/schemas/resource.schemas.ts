@@ -11,14 +11,16 @@ import { Schema } from 'effect';
  * once the service layer is available for mock injection.
  */
 
-export const ResourceStateSchema = Schema.Literal(
-  'PendingValidation',
-  'Active',
-  'Maintenance',
-  'Retired',
-  'Reserved'
+export const OperationalStateSchema = Schema.Literal(
+  'Available',
+  'Reserved',
+  'InTransit',
+  'InStorage',
+  'InMaintenance',
+  'InUse',
+  'PendingValidation'
 );
-export type ResourceState = Schema.Schema.Type<typeof ResourceStateSchema>;
+export type OperationalState = Schema.Schema.Type<typeof OperationalStateSchema>;
 
 export const PropertyRegimeSchema = Schema.Literal(
   'Private',
@@ -73,21 +75,41 @@ export class UIResourceSpec extends Schema.Class<UIResourceSpec>('UIResourceSpec
   created_at: Schema.optional(Schema.Number)
 }) { }
 
+export const GeoPointSchema = Schema.Struct({ lat: Schema.Number, long: Schema.Number });
+
+export const LocationSchema = Schema.Union(
+  Schema.Struct({ Named: Schema.String }),
+  Schema.Struct({
+    Address: Schema.Struct({
+      street: Schema.optional(Schema.NullOr(Schema.String)),
+      locality: Schema.String,
+      region: Schema.optional(Schema.NullOr(Schema.String)),
+      postal_code: Schema.optional(Schema.NullOr(Schema.String)),
+      country: Schema.String
+    })
+  }),
+  Schema.Struct({ Geo: GeoPointSchema }),
+  Schema.Struct({ Virtual: Schema.String })
+);
+export type Location = Schema.Schema.Type<typeof LocationSchema>;
+
 export class EconomicResourceInput extends Schema.Class<EconomicResourceInput>(
   'EconomicResourceInput'
 )({
   conforms_to: Schema.Any, // ActionHash → ResourceSpecification
   quantity: Schema.Number,
   unit: Schema.String,
-  current_location: Schema.optional(Schema.String)
+  current_location: Schema.optional(LocationSchema)
 }) { }
 
 export class UIEconomicResource extends Schema.Class<UIEconomicResource>('UIEconomicResource')({
-  quantity: Schema.Number,
+  accounting_quantity: Schema.Number,
+  onhand_quantity: Schema.Number,
   unit: Schema.String,
   custodian: Schema.Any, // AgentPubKey
-  current_location: Schema.optional(Schema.String),
-  state: ResourceStateSchema,
+  current_location: Schema.optional(LocationSchema),
+  state: OperationalStateSchema,
+  lifecycle_stage: LifecycleStageSchema,
   conforms_to: Schema.optional(Schema.Any), // ActionHash
   original_action_hash: Schema.optional(Schema.Any),
   created_at: Schema.optional(Schema.Number)
@@ -101,7 +123,7 @@ export class GovernanceRuleInput extends Schema.Class<GovernanceRuleInput>('Gove
 
 export class UIGovernanceRule extends Schema.Class<UIGovernanceRule>('UIGovernanceRule')({
   rule_type: Schema.String,
-  rule_data: Schema.String,
+  rule_data: Schema.Struct({ version: Schema.String, rule: Schema.Unknown }), // Versioned typed rule
   enforced_by: Schema.optional(Schema.String),
   original_action_hash: Schema.optional(Schema.Any),
   created_at: Schema.optional(Schema.Number)
@@ -178,7 +178,10 @@ const createResourceStore = (): E.Effect<ResourceStore, never, ResourceServiceTa
       const hash = await run(resourceService.updateResourceQuantity(resourceHash, newQuantity));
       if (hash) {
         const updated = await fetchEconomicResource(resourceHash);
-        if (updated) updated.quantity = newQuantity;
+        if (updated) {
+          updated.accounting_quantity = newQuantity;
+          updated.onhand_quantity = newQuantity;
+        }
       }
       return hash;
     }
@@ -5,8 +5,18 @@ import type { EconomicResource } from '@nondominium/shared-types';
 function isEconomicResource(x: unknown): x is EconomicResource {
   if (typeof x !== 'object' || x === null) return false;
   const o = x as Record<string, unknown>;
+  // Entries written before the accounting/onhand split carry a single `quantity`
+  if (typeof o.quantity === 'number' && o.accounting_quantity === undefined) {
+    o.accounting_quantity = o.quantity;
+    o.onhand_quantity = o.quantity;
+  }
+  // ... and a free-text `current_location`
+  if (typeof o.current_location === 'string') {
+    o.current_location = { Named: o.current_location };
+  }
   return (
-    typeof o.quantity === 'number' &&
+    typeof o.accounting_quantity === 'number' &&
+    typeof o.onhand_quantity === 'number' &&
     typeof o.unit === 'string' &&
     o.custodian instanceof Uint8Array &&
     typeof o.state === 'string'